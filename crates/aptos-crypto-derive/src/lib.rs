@@ -5,7 +5,7 @@
 #![forbid(unsafe_code)]
 
 //! # Derive macros for crypto operations
-//! This crate contains four types of derive macros:
+//! This crate contains five types of derive macros:
 //!
 //! - the `SilentDebug` and SilentDisplay macros are meant to be used on private key types, and
 //!   elide their input for confidentiality.
@@ -27,6 +27,9 @@
 //!     )
 //!   }
 //!   ```
+//! - the `MoveStruct` and `MoveType` macros, which implement
+//!   `aptos_rust_sdk_types::api_types::move_type_tag::MoveStructType` and `MoveTypeTag` for Rust
+//!   types with the BCS layout of a Move type, see below.
 //!
 //! # Unions of Signing Traits, in detail
 //!
@@ -101,9 +104,11 @@
 extern crate proc_macro;
 
 mod hasher;
+mod move_types;
 mod unions;
 
 use hasher::camel_to_snake;
+use move_types::{impl_move_struct, impl_move_type};
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
@@ -475,3 +480,33 @@ fn add_trait_bounds(mut generics: syn::Generics) -> syn::Generics {
     }
     generics
 }
+
+/// Implements `MoveStructType` and `MoveTypeTag` for a Rust struct mirroring a Move struct, e.g.
+///
+/// ```ignore
+/// #[derive(Deserialize, MoveStruct, Serialize)]
+/// #[move_struct(address = "0x1", module = "coin", name = "CoinStore")]
+/// pub struct CoinStore<CoinType> {
+///     pub coin: Coin<CoinType>,
+///     pub frozen: bool,
+///     pub deposit_events: EventHandle,
+///     pub withdraw_events: EventHandle,
+/// }
+/// ```
+///
+/// `name` defaults to the Rust struct's name.  Every type parameter is a type argument of the
+/// Move struct, and must implement `MoveTypeTag`; a phantom type parameter can be held in a
+/// `PhantomData`, which BCS encodes as nothing.  The fields must be the Move struct's fields in
+/// the same order, with types of the same BCS layout, for the struct to (de)serialize the same.
+#[proc_macro_derive(MoveStruct, attributes(move_struct))]
+pub fn derive_move_struct(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    impl_move_struct(ast).into()
+}
+
+/// Implements `MoveTypeTag` for a newtype, as the type tag of the type it wraps
+#[proc_macro_derive(MoveType)]
+pub fn derive_move_type(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    impl_move_type(ast).into()
+}
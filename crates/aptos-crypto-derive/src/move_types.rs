@@ -0,0 +1,144 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Lit, Meta, NestedMeta};
+
+/// The `#[move_struct(address = "0x1", module = "coin", name = "CoinStore")]` attribute.  The
+/// name defaults to the Rust struct's name.
+struct MoveStructAttrs {
+    address: [u8; 32],
+    module: String,
+    name: Option<String>,
+}
+
+fn parse_move_struct_attrs(ast: &DeriveInput) -> MoveStructAttrs {
+    let mut address = None;
+    let mut module = None;
+    let mut name = None;
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("move_struct"))
+    {
+        let list = match attr.parse_meta().expect("Incorrect move_struct attribute") {
+            Meta::List(list) => list,
+            _ => panic!("Expected #[move_struct(address = \"...\", module = \"...\")]"),
+        };
+        for nested in list.nested {
+            let name_value = match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value)) => name_value,
+                _ => panic!("Expected key = \"value\" in #[move_struct(...)]"),
+            };
+            let value = match name_value.lit {
+                Lit::Str(value) => value.value(),
+                _ => panic!("Expected a string value in #[move_struct(...)]"),
+            };
+            let key = name_value.path.get_ident().map(|ident| ident.to_string());
+            match key.as_deref() {
+                Some("address") => address = Some(parse_address(&value)),
+                Some("module") => module = Some(value),
+                Some("name") => name = Some(value),
+                _ => panic!("Unknown key in #[move_struct(...)], expected address, module or name"),
+            }
+        }
+    }
+
+    MoveStructAttrs {
+        address: address.expect("#[derive(MoveStruct)] requires #[move_struct(address = \"...\")]"),
+        module: module.expect("#[derive(MoveStruct)] requires #[move_struct(module = \"...\")]"),
+        name,
+    }
+}
+
+/// Parses a hex address, with or without `0x` and leading zeros, at compile time
+fn parse_address(address: &str) -> [u8; 32] {
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+    if hex.is_empty() || hex.len() > 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        panic!("Invalid Move address {:?}", address);
+    }
+    let hex = format!("{:0>64}", hex);
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+pub fn impl_move_struct(mut ast: DeriveInput) -> TokenStream {
+    if !matches!(ast.data, Data::Struct(_)) {
+        panic!("#[derive(MoveStruct)] is only defined for structs");
+    }
+    let attrs = parse_move_struct_attrs(&ast);
+    let name = &ast.ident;
+    let struct_name = attrs.name.unwrap_or_else(|| name.to_string());
+    let module = attrs.module;
+    let address = attrs.address;
+
+    // Every type parameter is a type argument of the Move struct, including phantom ones
+    let type_params: Vec<_> = ast
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = ast.generics.make_where_clause();
+    for param in &type_params {
+        where_clause.predicates.push(
+            parse_quote!(#param: ::aptos_rust_sdk_types::api_types::move_type_tag::MoveTypeTag),
+        );
+    }
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::aptos_rust_sdk_types::api_types::move_type_tag::MoveStructType
+            for #name #ty_generics #where_clause
+        {
+            const ADDRESS: ::aptos_rust_sdk_types::api_types::address::AccountAddress =
+                ::aptos_rust_sdk_types::api_types::address::AccountAddress::new([#(#address),*]);
+            const MODULE_NAME: &'static str = #module;
+            const STRUCT_NAME: &'static str = #struct_name;
+
+            fn type_args() -> ::std::vec::Vec<::aptos_rust_sdk_types::api_types::type_tag::TypeTag> {
+                ::std::vec![
+                    #(<#type_params as ::aptos_rust_sdk_types::api_types::move_type_tag::MoveTypeTag>::type_tag()),*
+                ]
+            }
+        }
+
+        impl #impl_generics ::aptos_rust_sdk_types::api_types::move_type_tag::MoveTypeTag
+            for #name #ty_generics #where_clause
+        {
+            fn type_tag() -> ::aptos_rust_sdk_types::api_types::type_tag::TypeTag {
+                ::aptos_rust_sdk_types::api_types::type_tag::TypeTag::Struct(::std::boxed::Box::new(
+                    <Self as ::aptos_rust_sdk_types::api_types::move_type_tag::MoveStructType>::struct_tag(),
+                ))
+            }
+        }
+    }
+}
+
+pub fn impl_move_type(mut ast: DeriveInput) -> TokenStream {
+    let field_ty = match ast.data {
+        Data::Struct(ref body) if body.fields.len() == 1 => {
+            body.fields.iter().next().unwrap().ty.clone()
+        }
+        _ => panic!("#[derive(MoveType)] can only be used on structs with one field"),
+    };
+    let name = &ast.ident;
+    ast.generics.make_where_clause().predicates.push(
+        parse_quote!(#field_ty: ::aptos_rust_sdk_types::api_types::move_type_tag::MoveTypeTag),
+    );
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        // A newtype has the BCS layout of the type it wraps
+        impl #impl_generics ::aptos_rust_sdk_types::api_types::move_type_tag::MoveTypeTag
+            for #name #ty_generics #where_clause
+        {
+            fn type_tag() -> ::aptos_rust_sdk_types::api_types::type_tag::TypeTag {
+                <#field_ty as ::aptos_rust_sdk_types::api_types::move_type_tag::MoveTypeTag>::type_tag()
+            }
+        }
+    }
+}
@@ -0,0 +1,844 @@
+//! Generates Rust bindings for Move modules from their ABIs.
+//!
+//! Each module becomes a Rust module with:
+//! * a function building the `TransactionPayload` of each entry function
+//! * an async function calling each view function, decoding the returned values with
+//!   `AptosFullnodeClient::view_bcs`
+//! * a `Serialize` and `Deserialize` struct for each Move struct, e.g. resources and events,
+//!   with the same BCS layout as the Move struct
+//!
+//! ABIs can be the node's JSON, either the `abi` alone or a whole module response, or compiled
+//! module bytecode.  The generator can run from a build script:
+//!
+//! ```no_run
+//! # fn main() -> anyhow::Result<()> {
+//! let modules = aptos_rust_sdk_codegen::load_abi_file("abi/my_module.json")?;
+//! let out_dir = std::env::var("OUT_DIR")?;
+//! aptos_rust_sdk_codegen::Generator::new()
+//!     .with_modules(modules)
+//!     .write_to_file(format!("{}/bindings.rs", out_dir))?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! or as the `aptos-rust-sdk-codegen` binary.  The generated code depends on `aptos-rust-sdk`,
+//! `aptos-rust-sdk-types` and `serde`.
+
+use anyhow::{anyhow, bail, Context};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::move_module::{
+    MoveFunction, MoveModule, MoveStruct, MoveStructTag, MoveType,
+};
+use aptos_rust_sdk_types::api_types::type_tag::StructTag;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+const BYTECODE_MAGIC: [u8; 4] = [0xA1, 0x1C, 0xEB, 0x0B];
+
+/// Items every generated module defines, which Move functions are renamed around
+const RESERVED_NAMES: &[&str] = &["ADDRESS", "MODULE_NAME", "module_id"];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Keywords that can't be raw identifiers
+const RESERVED_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Read the modules in an ABI file, see [`parse_abi`]
+pub fn load_abi_file(path: impl AsRef<Path>) -> anyhow::Result<Vec<MoveModule>> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path).with_context(|| format!("Failed to read {:?}", path))?;
+    parse_abi(&bytes).with_context(|| format!("Failed to parse {:?}", path))
+}
+
+/// Read the modules in an ABI, which may be:
+/// * compiled module bytecode, or its hex
+/// * the JSON `abi` of a module
+/// * a JSON module as returned by `/accounts/{address}/module/{name}`
+/// * a JSON list of modules, as returned by `/accounts/{address}/modules`
+pub fn parse_abi(bytes: &[u8]) -> anyhow::Result<Vec<MoveModule>> {
+    if bytes.starts_with(&BYTECODE_MAGIC) {
+        return Ok(vec![MoveModule::from_bytecode(bytes)?]);
+    }
+    let text = std::str::from_utf8(bytes)?.trim();
+    if text.starts_with("0xa11ceb0b") {
+        let bytecode = hex::decode(&text[2..])?;
+        return Ok(vec![MoveModule::from_bytecode(&bytecode)?]);
+    }
+    match serde_json::from_str(text)? {
+        serde_json::Value::Array(modules) => modules.iter().map(module_from_json).collect(),
+        module => Ok(vec![module_from_json(&module)?]),
+    }
+}
+
+fn module_from_json(module: &serde_json::Value) -> anyhow::Result<MoveModule> {
+    let abi = module.get("abi").unwrap_or(module);
+    Ok(serde_json::from_value(abi.clone())?)
+}
+
+/// Generates the bindings of a set of modules, which can refer to each other's structs
+#[derive(Clone, Debug, Default)]
+pub struct Generator {
+    modules: Vec<MoveModule>,
+    /// Rust types for structs of modules that aren't generated, by struct name
+    type_mappings: BTreeMap<String, String>,
+}
+
+impl Generator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_module(mut self, module: MoveModule) -> Self {
+        self.modules.push(module);
+        self
+    }
+
+    pub fn with_modules(mut self, modules: impl IntoIterator<Item = MoveModule>) -> Self {
+        self.modules.extend(modules);
+        self
+    }
+
+    /// Use a Rust type for a struct of a module that isn't generated, e.g.
+    /// `0x1::fungible_asset::Metadata`.  The Rust type must have the same BCS layout, and is
+    /// used whatever the struct's type arguments are.
+    pub fn with_type_mapping(mut self, move_struct: &str, rust_type: &str) -> Self {
+        // Normalize the address, so that `0x01::...` matches `0x1::...`
+        let name = StructTag::from_str(move_struct)
+            .map(|struct_tag| {
+                struct_name(&struct_tag.address, &struct_tag.module, &struct_tag.name)
+            })
+            .unwrap_or_else(|_| move_struct.to_string());
+        self.type_mappings.insert(name, rust_type.to_string());
+        self
+    }
+
+    /// Generate the bindings, as Rust source
+    pub fn generate(&self) -> anyhow::Result<String> {
+        let mut module_names = BTreeSet::new();
+        for module in &self.modules {
+            if !module_names.insert(module.name.as_str()) {
+                bail!("Two modules are named {}", module.name);
+            }
+        }
+
+        let structs = self.generated_structs();
+        let mut out = String::from("// Generated by aptos-rust-sdk-codegen.  Do not edit.\n");
+        for module in &self.modules {
+            ModuleWriter {
+                generator: self,
+                structs: &structs,
+                module,
+                out: &mut out,
+            }
+            .write()?;
+        }
+        Ok(out)
+    }
+
+    /// Generate the bindings into a file, e.g. in a build script's `OUT_DIR`
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.generate()?)
+            .with_context(|| format!("Failed to write {:?}", path))
+    }
+
+    /// The structs that can be generated, with the generic type params their Rust structs keep.
+    /// Structs are dropped until all the field types of the remaining ones can be mapped, and
+    /// type params are only kept if a field uses them, as Rust doesn't allow unused generics.
+    fn generated_structs(&self) -> BTreeMap<String, GeneratedStruct> {
+        let mut structs: BTreeMap<_, _> = self
+            .modules
+            .iter()
+            .flat_map(|module| {
+                module
+                    .structs
+                    .iter()
+                    .filter(|move_struct| !move_struct.is_native && !move_struct.fields.is_empty())
+                    .map(move |move_struct| {
+                        (
+                            struct_name(&module.address, &module.name, &move_struct.name),
+                            GeneratedStruct {
+                                module: rust_identifier(&module.name),
+                                move_struct,
+                                used_params: BTreeSet::new(),
+                            },
+                        )
+                    })
+            })
+            .collect();
+
+        loop {
+            // Find the used type params, which depend on the type params used by other structs
+            loop {
+                let mut changed = false;
+                let names: Vec<_> = structs.keys().cloned().collect();
+                for name in names {
+                    let move_struct = structs[&name].move_struct;
+                    let used_params: BTreeSet<_> = (0..move_struct.generic_type_params.len())
+                        .filter(|index| {
+                            move_struct
+                                .fields
+                                .iter()
+                                .any(|field| self.uses_param(&structs, &field.type_, *index as u16))
+                        })
+                        .collect();
+                    let generated = structs.get_mut(&name).unwrap();
+                    if generated.used_params != used_params {
+                        generated.used_params = used_params;
+                        changed = true;
+                    }
+                }
+                if !changed {
+                    break;
+                }
+            }
+
+            let unmappable: Vec<_> =
+                structs
+                    .iter()
+                    .filter(|(_, generated)| {
+                        let generics = generated.generics();
+                        generated.move_struct.fields.iter().any(|field| {
+                            self.rust_type(&structs, &field.type_, &generics).is_none()
+                        })
+                    })
+                    .map(|(name, _)| name.clone())
+                    .collect();
+            if unmappable.is_empty() {
+                return structs;
+            }
+            for name in unmappable {
+                structs.remove(&name);
+            }
+        }
+    }
+
+    /// Whether a field type uses a type param in a way that the Rust type keeps
+    fn uses_param(
+        &self,
+        structs: &BTreeMap<String, GeneratedStruct>,
+        move_type: &MoveType,
+        index: u16,
+    ) -> bool {
+        match move_type {
+            MoveType::GenericTypeParam(param) => *param == index,
+            MoveType::Vector(inner) => self.uses_param(structs, inner, index),
+            MoveType::Struct(struct_tag) => match builtin_struct(struct_tag) {
+                Some(Builtin::Option) => struct_tag
+                    .generic_type_params
+                    .first()
+                    .is_some_and(|inner| self.uses_param(structs, inner, index)),
+                Some(_) => false,
+                // Mapped types are used whatever the type arguments are
+                None if self
+                    .type_mappings
+                    .contains_key(&struct_tag_name(struct_tag)) =>
+                {
+                    false
+                }
+                None => structs
+                    .get(&struct_tag_name(struct_tag))
+                    .is_some_and(|generated| {
+                        generated.used_params.iter().any(|used| {
+                            struct_tag
+                                .generic_type_params
+                                .get(*used)
+                                .is_some_and(|arg| self.uses_param(structs, arg, index))
+                        })
+                    }),
+            },
+            _ => false,
+        }
+    }
+
+    /// The Rust type of a struct field or return value, if it has one.  `generics` are the Rust
+    /// names of the type params in scope, by index.
+    fn rust_type(
+        &self,
+        structs: &BTreeMap<String, GeneratedStruct>,
+        move_type: &MoveType,
+        generics: &[Option<String>],
+    ) -> Option<String> {
+        if let Some(rust_type) = primitive_rust_type(move_type) {
+            return Some(rust_type.to_string());
+        }
+        match move_type {
+            MoveType::Vector(inner) => Some(format!(
+                "std::vec::Vec<{}>",
+                self.rust_type(structs, inner, generics)?
+            )),
+            MoveType::GenericTypeParam(index) => generics.get(*index as usize).cloned().flatten(),
+            MoveType::Struct(struct_tag) => {
+                let type_args = &struct_tag.generic_type_params;
+                match builtin_struct(struct_tag) {
+                    Some(Builtin::String) => return Some("std::string::String".to_string()),
+                    Some(Builtin::Object) => return Some("AccountAddress".to_string()),
+                    Some(Builtin::Option) => {
+                        return Some(format!(
+                            "std::option::Option<{}>",
+                            self.rust_type(structs, type_args.first()?, generics)?
+                        ))
+                    }
+                    None => {}
+                }
+                let name = struct_tag_name(struct_tag);
+                if let Some(rust_type) = self.type_mappings.get(&name) {
+                    return Some(rust_type.clone());
+                }
+                let generated = structs.get(&name)?;
+                let path = format!(
+                    "super::{}::{}",
+                    generated.module, generated.move_struct.name
+                );
+                if generated.used_params.is_empty() {
+                    return Some(path);
+                }
+                let args = generated
+                    .used_params
+                    .iter()
+                    .map(|index| self.rust_type(structs, type_args.get(*index)?, generics))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("{}<{}>", path, args.join(", ")))
+            }
+            _ => None,
+        }
+    }
+}
+
+struct GeneratedStruct<'a> {
+    /// The Rust module the struct is in
+    module: String,
+    move_struct: &'a MoveStruct,
+    used_params: BTreeSet<usize>,
+}
+
+impl GeneratedStruct<'_> {
+    /// The Rust names of the type params, by index, for the ones the Rust struct keeps
+    fn generics(&self) -> Vec<Option<String>> {
+        (0..self.move_struct.generic_type_params.len())
+            .map(|index| {
+                self.used_params
+                    .contains(&index)
+                    .then(|| format!("T{}", index))
+            })
+            .collect()
+    }
+}
+
+/// Framework structs with their own Rust types
+enum Builtin {
+    String,
+    Object,
+    Option,
+}
+
+fn builtin_struct(struct_tag: &MoveStructTag) -> Option<Builtin> {
+    if struct_tag.address != AccountAddress::ONE {
+        return None;
+    }
+    match (struct_tag.module.as_str(), struct_tag.name.as_str()) {
+        ("string", "String") => Some(Builtin::String),
+        ("object", "Object") => Some(Builtin::Object),
+        ("option", "Option") => Some(Builtin::Option),
+        _ => None,
+    }
+}
+
+fn primitive_rust_type(move_type: &MoveType) -> Option<&'static str> {
+    Some(match move_type {
+        MoveType::Bool => "bool",
+        MoveType::U8 => "u8",
+        MoveType::U16 => "u16",
+        MoveType::U32 => "u32",
+        MoveType::U64 => "u64",
+        MoveType::U128 => "u128",
+        MoveType::U256 => "U256",
+        MoveType::Address => "AccountAddress",
+        _ => return None,
+    })
+}
+
+/// The Rust type of an entry or view function argument, which must convert into a `MoveValue`.
+/// Arguments without one are passed as `MoveValue`s.
+fn argument_rust_type(move_type: &MoveType) -> Option<String> {
+    if let Some(rust_type) = primitive_rust_type(move_type) {
+        return Some(rust_type.to_string());
+    }
+    match move_type {
+        MoveType::Vector(inner) => Some(format!("std::vec::Vec<{}>", argument_rust_type(inner)?)),
+        MoveType::Struct(struct_tag) => match builtin_struct(struct_tag)? {
+            Builtin::String => Some("std::string::String".to_string()),
+            Builtin::Object => Some("AccountAddress".to_string()),
+            Builtin::Option => Some(format!(
+                "std::option::Option<{}>",
+                argument_rust_type(struct_tag.generic_type_params.first()?)?
+            )),
+        },
+        _ => None,
+    }
+}
+
+fn struct_name(address: &AccountAddress, module: &str, name: &str) -> String {
+    format!("0x{}::{}::{}", address.short_str_lossless(), module, name)
+}
+
+fn struct_tag_name(struct_tag: &MoveStructTag) -> String {
+    struct_name(&struct_tag.address, &struct_tag.module, &struct_tag.name)
+}
+
+/// A Move identifier as a Rust identifier, escaping Rust keywords
+fn rust_identifier(name: &str) -> String {
+    if RESERVED_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// The Rust name of a Move function, which mustn't clash with the generated module's own items
+fn function_identifier(name: &str) -> String {
+    if RESERVED_NAMES.contains(&name) {
+        format!("{}_", name)
+    } else {
+        rust_identifier(name)
+    }
+}
+
+struct ModuleWriter<'a> {
+    generator: &'a Generator,
+    structs: &'a BTreeMap<String, GeneratedStruct<'a>>,
+    module: &'a MoveModule,
+    out: &'a mut String,
+}
+
+impl ModuleWriter<'_> {
+    fn write(&mut self) -> anyhow::Result<()> {
+        let module = self.module;
+        let address = module
+            .address
+            .to_bytes()
+            .iter()
+            .map(|byte| format!("{:#04x}", byte))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(self.out)?;
+        writeln!(
+            self.out,
+            "/// Bindings for `0x{}::{}`",
+            module.address.short_str_lossless(),
+            module.name
+        )?;
+        writeln!(
+            self.out,
+            "#[allow(dead_code, unused_imports, clippy::too_many_arguments, clippy::useless_conversion)]"
+        )?;
+        writeln!(self.out, "pub mod {} {{", rust_identifier(&module.name))?;
+        for import in [
+            "aptos_rust_sdk::client::rest_api::AptosFullnodeClient",
+            "aptos_rust_sdk_types::api_types::address::AccountAddress",
+            "aptos_rust_sdk_types::api_types::module_id::ModuleId",
+            "aptos_rust_sdk_types::api_types::move_value::MoveValue",
+            "aptos_rust_sdk_types::api_types::transaction::{EntryFunction, TransactionPayload, ViewFunction}",
+            "aptos_rust_sdk_types::api_types::type_tag::{StructTag, TypeTag}",
+            "aptos_rust_sdk_types::api_types::u256::U256",
+            "aptos_rust_sdk_types::AptosResult",
+            "serde::{Deserialize, Serialize}",
+        ] {
+            writeln!(self.out, "    use {};", import)?;
+        }
+        writeln!(self.out)?;
+        writeln!(
+            self.out,
+            "    pub const ADDRESS: AccountAddress = AccountAddress::new([{}]);",
+            address
+        )?;
+        writeln!(
+            self.out,
+            "    pub const MODULE_NAME: &str = {:?};",
+            module.name
+        )?;
+        writeln!(self.out)?;
+        writeln!(self.out, "    pub fn module_id() -> ModuleId {{")?;
+        writeln!(
+            self.out,
+            "        ModuleId::new(ADDRESS, MODULE_NAME.to_string())"
+        )?;
+        writeln!(self.out, "    }}")?;
+
+        for move_struct in &module.structs {
+            self.write_struct(move_struct)?;
+        }
+        for function in &module.exposed_functions {
+            if function.is_entry {
+                self.write_entry_function(function)?;
+            }
+            if function.is_view {
+                self.write_view_function(function)?;
+            }
+        }
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn full_name(&self, name: &str) -> String {
+        struct_name(&self.module.address, &self.module.name, name)
+    }
+
+    fn write_struct(&mut self, move_struct: &MoveStruct) -> anyhow::Result<()> {
+        let name = self.full_name(&move_struct.name);
+        writeln!(self.out)?;
+        let Some(generated) = self.structs.get(&name) else {
+            let reason = if move_struct.is_native {
+                "it's native".to_string()
+            } else if move_struct.fields.is_empty() {
+                "it has no fields".to_string()
+            } else {
+                "a field's type has no Rust type".to_string()
+            };
+            writeln!(self.out, "    // Skipped struct `{}`, as {}", name, reason)?;
+            return Ok(());
+        };
+
+        let generics = generated.generics();
+        let rust_generics: Vec<_> = generics.iter().flatten().cloned().collect();
+        let rust_generics = if rust_generics.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", rust_generics.join(", "))
+        };
+        writeln!(self.out, "    /// The Move struct `{}`", name)?;
+        writeln!(
+            self.out,
+            "    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]"
+        )?;
+        writeln!(
+            self.out,
+            "    pub struct {}{} {{",
+            move_struct.name, rust_generics
+        )?;
+        for field in &move_struct.fields {
+            let rust_type = self
+                .generator
+                .rust_type(self.structs, &field.type_, &generics)
+                .ok_or_else(|| anyhow!("Field {} of {} has no Rust type", field.name, name))?;
+            writeln!(
+                self.out,
+                "        pub {}: {},",
+                rust_identifier(&field.name),
+                rust_type
+            )?;
+        }
+        writeln!(self.out, "    }}")?;
+
+        let type_params: Vec<_> = (0..move_struct.generic_type_params.len())
+            .map(|index| format!("t{}", index))
+            .collect();
+        writeln!(self.out)?;
+        writeln!(
+            self.out,
+            "    impl{} {}{} {{",
+            rust_generics, move_struct.name, rust_generics
+        )?;
+        writeln!(
+            self.out,
+            "        /// The Move struct's tag, given its type arguments"
+        )?;
+        writeln!(
+            self.out,
+            "        pub fn struct_tag({}) -> StructTag {{",
+            type_params
+                .iter()
+                .map(|param| format!("{}: TypeTag", param))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        writeln!(self.out, "            StructTag {{")?;
+        writeln!(self.out, "                address: ADDRESS,")?;
+        writeln!(self.out, "                module: MODULE_NAME.to_string(),")?;
+        writeln!(
+            self.out,
+            "                name: {:?}.to_string(),",
+            move_struct.name
+        )?;
+        writeln!(
+            self.out,
+            "                type_args: vec![{}],",
+            type_params.join(", ")
+        )?;
+        writeln!(self.out, "            }}")?;
+        writeln!(self.out, "        }}")?;
+        writeln!(self.out, "    }}")?;
+        Ok(())
+    }
+
+    /// The Rust parameters of a function's type arguments and arguments, and the expressions of
+    /// the arguments as `MoveValue`s
+    fn parameters(&self, function: &MoveFunction) -> (Vec<String>, Vec<String>, Vec<String>) {
+        let type_params = (0..function.generic_type_params.len())
+            .map(|index| format!("t{}", index))
+            .collect();
+        let (params, args) = function
+            .non_signer_params()
+            .enumerate()
+            .map(|(index, param)| {
+                let rust_type =
+                    argument_rust_type(param).unwrap_or_else(|| "MoveValue".to_string());
+                (
+                    format!("arg{}: {}", index, rust_type),
+                    format!("arg{}.into()", index),
+                )
+            })
+            .unzip();
+        (type_params, params, args)
+    }
+
+    fn write_entry_function(&mut self, function: &MoveFunction) -> anyhow::Result<()> {
+        let (type_params, params, args) = self.parameters(function);
+        let signature: Vec<_> = type_params
+            .iter()
+            .map(|param| format!("{}: TypeTag", param))
+            .chain(params)
+            .collect();
+        writeln!(self.out)?;
+        writeln!(
+            self.out,
+            "    /// The payload calling the entry function `{}`",
+            self.full_name(&function.name)
+        )?;
+        writeln!(
+            self.out,
+            "    pub fn {}({}) -> TransactionPayload {{",
+            function_identifier(&function.name),
+            signature.join(", ")
+        )?;
+        writeln!(
+            self.out,
+            "        TransactionPayload::EntryFunction(EntryFunction::from_values("
+        )?;
+        self.write_call_arguments(function, &type_params, &args, "            ")?;
+        writeln!(self.out, "        ))")?;
+        writeln!(self.out, "    }}")?;
+        Ok(())
+    }
+
+    fn write_view_function(&mut self, function: &MoveFunction) -> anyhow::Result<()> {
+        let name = self.full_name(&function.name);
+        writeln!(self.out)?;
+        let Some(return_types) = function
+            .return_
+            .iter()
+            .map(|return_type| self.generator.rust_type(self.structs, return_type, &[]))
+            .collect::<Option<Vec<_>>>()
+        else {
+            writeln!(
+                self.out,
+                "    // Skipped view function `{}`, as a return type has no Rust type",
+                name
+            )?;
+            return Ok(());
+        };
+        let return_type = match return_types.len() {
+            1 => return_types[0].clone(),
+            _ => format!("({})", return_types.join(", ")),
+        };
+
+        let (type_params, params, args) = self.parameters(function);
+        let signature: Vec<_> = std::iter::once("client: &AptosFullnodeClient".to_string())
+            .chain(
+                type_params
+                    .iter()
+                    .map(|param| format!("{}: TypeTag", param)),
+            )
+            .chain(params)
+            .collect();
+        // An entry function that's also a view function already has a payload builder
+        let rust_name = if function.is_entry {
+            format!("{}_view", function.name)
+        } else {
+            function_identifier(&function.name)
+        };
+        writeln!(self.out, "    /// Call the view function `{}`", name)?;
+        writeln!(
+            self.out,
+            "    pub async fn {}({}) -> AptosResult<{}> {{",
+            rust_name,
+            signature.join(", "),
+            return_type
+        )?;
+        writeln!(self.out, "        let view_function = ViewFunction::new(")?;
+        self.write_call_arguments(function, &type_params, &args, "            ")?;
+        writeln!(self.out, "        );")?;
+        writeln!(
+            self.out,
+            "        Ok(client.view_bcs(&view_function).await?.into_inner())"
+        )?;
+        writeln!(self.out, "    }}")?;
+        Ok(())
+    }
+
+    fn write_call_arguments(
+        &mut self,
+        function: &MoveFunction,
+        type_params: &[String],
+        args: &[String],
+        indent: &str,
+    ) -> anyhow::Result<()> {
+        writeln!(self.out, "{}module_id(),", indent)?;
+        writeln!(self.out, "{}{:?}.to_string(),", indent, function.name)?;
+        writeln!(self.out, "{}vec![{}],", indent, type_params.join(", "))?;
+        writeln!(self.out, "{}vec![{}],", indent, args.join(", "))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_rust_sdk_types::api_types::move_value::MoveValue;
+    use aptos_rust_sdk_types::api_types::transaction::{EntryFunction, TransactionPayload};
+    use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+
+    mod bindings {
+        include!("../testdata/example_bindings.rs");
+    }
+
+    const EXAMPLE_ABI: &str = include_str!("../testdata/example_abi.json");
+    const EXAMPLE_BINDINGS: &str = "testdata/example_bindings.rs";
+
+    fn example_generator() -> Generator {
+        Generator::new().with_modules(parse_abi(EXAMPLE_ABI.as_bytes()).unwrap())
+    }
+
+    /// Set `UPDATE_BINDINGS=1` to regenerate the expected bindings
+    #[test]
+    fn test_generate_example() {
+        let bindings = example_generator().generate().unwrap();
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLE_BINDINGS);
+        if std::env::var("UPDATE_BINDINGS").is_ok() {
+            std::fs::write(&path, &bindings).unwrap();
+        }
+        assert_eq!(bindings, std::fs::read_to_string(path).unwrap());
+    }
+
+    #[test]
+    fn test_parse_abi() {
+        let module = parse_abi(EXAMPLE_ABI.as_bytes()).unwrap().remove(0);
+        assert_eq!(module.address, AccountAddress::from_str("0xcafe").unwrap());
+        assert_eq!(module.name, "vault");
+
+        let json: serde_json::Value = serde_json::from_str(EXAMPLE_ABI).unwrap();
+        let abi = json["abi"].to_string();
+        assert_eq!(parse_abi(abi.as_bytes()).unwrap(), vec![module.clone()]);
+        let modules = serde_json::Value::Array(vec![json.clone(), json["abi"].clone()]).to_string();
+        assert_eq!(
+            parse_abi(modules.as_bytes()).unwrap(),
+            vec![module.clone(), module]
+        );
+
+        assert!(parse_abi(b"not an abi").is_err());
+        assert!(parse_abi(&[0xA1, 0x1C, 0xEB, 0x0B, 0xFF]).is_err());
+    }
+
+    #[test]
+    fn test_generated_entry_functions() {
+        let metadata = AccountAddress::from_str("0xa").unwrap();
+        assert_eq!(
+            bindings::vault::deposit(TypeTag::U64, 100, "memo".to_string(), Some(metadata)),
+            TransactionPayload::EntryFunction(EntryFunction::from_values(
+                bindings::vault::module_id(),
+                "deposit".to_string(),
+                vec![TypeTag::U64],
+                vec![
+                    MoveValue::U64(100),
+                    MoveValue::String("memo".to_string()),
+                    MoveValue::Option(Some(Box::new(MoveValue::Address(metadata)))),
+                ],
+            ))
+        );
+
+        let receipt = MoveValue::Struct(vec![MoveValue::U8(1), MoveValue::Address(metadata)]);
+        assert_eq!(
+            bindings::vault::r#move(receipt.clone()),
+            TransactionPayload::EntryFunction(EntryFunction::from_values(
+                bindings::vault::module_id(),
+                "move".to_string(),
+                vec![],
+                vec![receipt],
+            ))
+        );
+    }
+
+    #[test]
+    fn test_generated_structs() {
+        let metadata = AccountAddress::from_str("0xa").unwrap();
+        let event = bindings::vault::DepositEvent {
+            vault: AccountAddress::ONE,
+            amount: 7,
+            receipt: bindings::vault::Receipt {
+                r#type: 2,
+                metadata,
+            },
+        };
+        let expected = MoveValue::Struct(vec![
+            MoveValue::Address(AccountAddress::ONE),
+            MoveValue::U64(7),
+            MoveValue::Struct(vec![MoveValue::U8(2), MoveValue::Address(metadata)]),
+        ])
+        .to_bcs();
+        assert_eq!(aptos_bcs::to_bytes(&event).unwrap(), expected);
+        assert_eq!(
+            aptos_bcs::from_bytes::<bindings::vault::DepositEvent>(&expected).unwrap(),
+            event
+        );
+
+        let vault = bindings::vault::Vault {
+            owner: AccountAddress::ONE,
+            amount: 5u64,
+            receipts: vec![],
+            label: Some("savings".to_string()),
+        };
+        let bytes = aptos_bcs::to_bytes(&vault).unwrap();
+        assert_eq!(
+            aptos_bcs::from_bytes::<bindings::vault::Vault<u64>>(&bytes).unwrap(),
+            vault
+        );
+
+        assert_eq!(
+            bindings::vault::Vault::<u64>::struct_tag(TypeTag::U64),
+            StructTag::from_str("0xcafe::vault::Vault<u64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_type_mapping() {
+        let bindings = example_generator().generate().unwrap();
+        assert!(bindings.contains("// Skipped struct `0xcafe::vault::Registry`"));
+        assert!(bindings.contains("// Skipped view function `0xcafe::vault::registry`"));
+
+        let bindings = example_generator()
+            .with_type_mapping("0x01::table::Table", "my_crate::Table")
+            .generate()
+            .unwrap();
+        assert!(bindings.contains("pub vaults: my_crate::Table,"));
+        assert!(bindings.contains("-> AptosResult<super::vault::Registry>"));
+    }
+
+    #[test]
+    fn test_duplicate_module_names() {
+        let module = parse_abi(EXAMPLE_ABI.as_bytes()).unwrap().remove(0);
+        let generator = Generator::new()
+            .with_module(module.clone())
+            .with_module(module);
+        assert!(generator.generate().is_err());
+    }
+}
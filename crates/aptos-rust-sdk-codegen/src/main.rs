@@ -0,0 +1,58 @@
+use anyhow::{anyhow, bail, Context};
+use aptos_rust_sdk_codegen::{load_abi_file, Generator};
+
+const USAGE: &str = "Usage: aptos-rust-sdk-codegen [--output FILE] [--map MOVE_STRUCT=RUST_TYPE]... ABI...
+
+Generates Rust bindings for the Move modules in the ABI files, which may be JSON ABIs, JSON
+module responses or compiled module bytecode.  The bindings are printed unless --output is given.
+
+Options:
+  -o, --output FILE                 Write the bindings to FILE
+  -m, --map MOVE_STRUCT=RUST_TYPE   Use RUST_TYPE for MOVE_STRUCT, e.g. 0x1::fungible_asset::Metadata=my_crate::Metadata
+  -h, --help                        Print this help";
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let mut output = None;
+    let mut generator = Generator::new();
+    let mut inputs = vec![];
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            "-o" | "--output" => {
+                output = Some(args.next().ok_or_else(|| anyhow!("{} needs a file", arg))?);
+            }
+            "-m" | "--map" => {
+                let mapping = args
+                    .next()
+                    .ok_or_else(|| anyhow!("{} needs a mapping", arg))?;
+                let (move_struct, rust_type) = mapping
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("Expected MOVE_STRUCT=RUST_TYPE, got {}", mapping))?;
+                generator = generator.with_type_mapping(move_struct, rust_type);
+            }
+            _ if arg.starts_with('-') => bail!("Unknown option {}\n\n{}", arg, USAGE),
+            _ => inputs.push(arg),
+        }
+    }
+    if inputs.is_empty() {
+        bail!("No ABI files given\n\n{}", USAGE);
+    }
+
+    for input in inputs {
+        generator = generator.with_modules(load_abi_file(input)?);
+    }
+    match output {
+        Some(output) => generator.write_to_file(output),
+        None => {
+            let bindings = generator
+                .generate()
+                .context("Failed to generate bindings")?;
+            print!("{}", bindings);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,161 @@
+// Generated by aptos-rust-sdk-codegen.  Do not edit.
+
+/// Bindings for `0xcafe::vault`
+#[allow(dead_code, unused_imports, clippy::too_many_arguments, clippy::useless_conversion)]
+pub mod vault {
+    use aptos_rust_sdk::client::rest_api::AptosFullnodeClient;
+    use aptos_rust_sdk_types::api_types::address::AccountAddress;
+    use aptos_rust_sdk_types::api_types::module_id::ModuleId;
+    use aptos_rust_sdk_types::api_types::move_value::MoveValue;
+    use aptos_rust_sdk_types::api_types::transaction::{EntryFunction, TransactionPayload, ViewFunction};
+    use aptos_rust_sdk_types::api_types::type_tag::{StructTag, TypeTag};
+    use aptos_rust_sdk_types::api_types::u256::U256;
+    use aptos_rust_sdk_types::AptosResult;
+    use serde::{Deserialize, Serialize};
+
+    pub const ADDRESS: AccountAddress = AccountAddress::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xca, 0xfe]);
+    pub const MODULE_NAME: &str = "vault";
+
+    pub fn module_id() -> ModuleId {
+        ModuleId::new(ADDRESS, MODULE_NAME.to_string())
+    }
+
+    /// The Move struct `0xcafe::vault::Vault`
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Vault<T0> {
+        pub owner: AccountAddress,
+        pub amount: T0,
+        pub receipts: std::vec::Vec<super::vault::Receipt>,
+        pub label: std::option::Option<std::string::String>,
+    }
+
+    impl<T0> Vault<T0> {
+        /// The Move struct's tag, given its type arguments
+        pub fn struct_tag(t0: TypeTag) -> StructTag {
+            StructTag {
+                address: ADDRESS,
+                module: MODULE_NAME.to_string(),
+                name: "Vault".to_string(),
+                type_args: vec![t0],
+            }
+        }
+    }
+
+    /// The Move struct `0xcafe::vault::Receipt`
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Receipt {
+        pub r#type: u8,
+        pub metadata: AccountAddress,
+    }
+
+    impl Receipt {
+        /// The Move struct's tag, given its type arguments
+        pub fn struct_tag(t0: TypeTag) -> StructTag {
+            StructTag {
+                address: ADDRESS,
+                module: MODULE_NAME.to_string(),
+                name: "Receipt".to_string(),
+                type_args: vec![t0],
+            }
+        }
+    }
+
+    /// The Move struct `0xcafe::vault::DepositEvent`
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct DepositEvent {
+        pub vault: AccountAddress,
+        pub amount: u64,
+        pub receipt: super::vault::Receipt,
+    }
+
+    impl DepositEvent {
+        /// The Move struct's tag, given its type arguments
+        pub fn struct_tag() -> StructTag {
+            StructTag {
+                address: ADDRESS,
+                module: MODULE_NAME.to_string(),
+                name: "DepositEvent".to_string(),
+                type_args: vec![],
+            }
+        }
+    }
+
+    // Skipped struct `0xcafe::vault::Registry`, as a field's type has no Rust type
+
+    // Skipped struct `0xcafe::vault::Marker`, as it has no fields
+
+    /// The payload calling the entry function `0xcafe::vault::deposit`
+    pub fn deposit(t0: TypeTag, arg0: u64, arg1: std::string::String, arg2: std::option::Option<AccountAddress>) -> TransactionPayload {
+        TransactionPayload::EntryFunction(EntryFunction::from_values(
+            module_id(),
+            "deposit".to_string(),
+            vec![t0],
+            vec![arg0.into(), arg1.into(), arg2.into()],
+        ))
+    }
+
+    /// The payload calling the entry function `0xcafe::vault::set_tags`
+    pub fn set_tags(arg0: std::vec::Vec<std::vec::Vec<u8>>, arg1: AccountAddress) -> TransactionPayload {
+        TransactionPayload::EntryFunction(EntryFunction::from_values(
+            module_id(),
+            "set_tags".to_string(),
+            vec![],
+            vec![arg0.into(), arg1.into()],
+        ))
+    }
+
+    /// The payload calling the entry function `0xcafe::vault::move`
+    pub fn r#move(arg0: MoveValue) -> TransactionPayload {
+        TransactionPayload::EntryFunction(EntryFunction::from_values(
+            module_id(),
+            "move".to_string(),
+            vec![],
+            vec![arg0.into()],
+        ))
+    }
+
+    /// Call the view function `0xcafe::vault::balance`
+    pub async fn balance(client: &AptosFullnodeClient, t0: TypeTag, arg0: AccountAddress) -> AptosResult<(u64, U256)> {
+        let view_function = ViewFunction::new(
+            module_id(),
+            "balance".to_string(),
+            vec![t0],
+            vec![arg0.into()],
+        );
+        Ok(client.view_bcs(&view_function).await?.into_inner())
+    }
+
+    /// Call the view function `0xcafe::vault::vault`
+    pub async fn vault(client: &AptosFullnodeClient, arg0: AccountAddress) -> AptosResult<super::vault::Vault<u64>> {
+        let view_function = ViewFunction::new(
+            module_id(),
+            "vault".to_string(),
+            vec![],
+            vec![arg0.into()],
+        );
+        Ok(client.view_bcs(&view_function).await?.into_inner())
+    }
+
+    /// The payload calling the entry function `0xcafe::vault::total`
+    pub fn total() -> TransactionPayload {
+        TransactionPayload::EntryFunction(EntryFunction::from_values(
+            module_id(),
+            "total".to_string(),
+            vec![],
+            vec![],
+        ))
+    }
+
+    /// Call the view function `0xcafe::vault::total`
+    pub async fn total_view(client: &AptosFullnodeClient) -> AptosResult<u128> {
+        let view_function = ViewFunction::new(
+            module_id(),
+            "total".to_string(),
+            vec![],
+            vec![],
+        );
+        Ok(client.view_bcs(&view_function).await?.into_inner())
+    }
+
+    // Skipped view function `0xcafe::vault::registry`, as a return type has no Rust type
+}
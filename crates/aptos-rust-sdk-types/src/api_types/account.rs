@@ -1,9 +1,201 @@
+use crate::api_types::address::AccountAddress;
+use crate::api_types::event::EventHandle;
+use crate::api_types::move_json::from_move_json;
+use crate::api_types::move_type_tag::{MoveStruct, MoveStructType};
+use crate::api_types::numbers::U64;
+use crate::api_types::type_tag::StructTag;
+use anyhow::anyhow;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use std::str::FromStr;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A resource as returned by `/accounts/{address}/resources`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "RawAccountResource")]
 pub struct AccountResource {
+    /// The resource's type as the API returns it
     #[serde(rename = "type")]
     pub type_: String,
     pub data: serde_json::Value,
+    /// `type_` parsed once, or `None` if it uses type syntax `StructTag` can't parse, so such a
+    /// resource doesn't keep the others from being read
+    #[serde(skip)]
+    struct_tag: Option<StructTag>,
+}
+
+/// The JSON form of an [`AccountResource`], before its type is parsed
+#[derive(Deserialize)]
+struct RawAccountResource {
+    #[serde(rename = "type")]
+    type_: String,
+    data: serde_json::Value,
+}
+
+impl From<RawAccountResource> for AccountResource {
+    fn from(resource: RawAccountResource) -> Self {
+        Self::new(resource.type_, resource.data)
+    }
+}
+
+impl AccountResource {
+    pub fn new(type_: String, data: serde_json::Value) -> Self {
+        Self {
+            struct_tag: StructTag::from_str(&type_).ok(),
+            type_,
+            data,
+        }
+    }
+
+    /// The resource's type, if it could be parsed
+    pub fn struct_tag(&self) -> Option<&StructTag> {
+        self.struct_tag.as_ref()
+    }
+
+    /// Decode the resource's data as a Rust struct mirroring the Move struct, see
+    /// [`from_move_json`]
+    pub fn decode<T: DeserializeOwned>(&self) -> anyhow::Result<T> {
+        from_move_json(self.data.clone())
+            .map_err(|err| anyhow!("Failed to decode {}: {}", self.type_, err))
+    }
+}
+
+/// Lookups on the resources of an account
+pub trait AccountResources {
+    /// The resource of a type, if the account has one
+    fn get_resource(&self, type_: &StructTag) -> Option<&AccountResource>;
+
+    /// The resource with `T`'s struct tag, decoded as `T`, if the account has one, e.g.
+    /// `resources.find::<Account>()`
+    fn find<T: MoveStructType + DeserializeOwned>(&self) -> anyhow::Result<Option<T>> {
+        self.get_resource(&T::struct_tag())
+            .map(AccountResource::decode)
+            .transpose()
+    }
+}
+
+impl AccountResources for [AccountResource] {
+    fn get_resource(&self, type_: &StructTag) -> Option<&AccountResource> {
+        self.iter()
+            .find(|resource| resource.struct_tag() == Some(type_))
+    }
+}
+
+/// The `0x1::account::Account` resource
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(address = "0x1", module = "account")]
+pub struct Account {
+    pub authentication_key: Vec<u8>,
+    pub sequence_number: u64,
+    /// The number of GUIDs the account has created, e.g. for event handles and objects
+    pub guid_creation_num: u64,
+    pub coin_register_events: EventHandle,
+    pub key_rotation_events: EventHandle,
+    pub rotation_capability_offer: CapabilityOffer,
+    pub signer_capability_offer: CapabilityOffer,
+}
+
+/// A `0x1::account::CapabilityOffer`.  Like `EventHandle`, it leaves out the Move struct's
+/// phantom type parameter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CapabilityOffer {
+    /// The account the capability is offered to
+    #[serde(rename = "for")]
+    pub for_: Option<AccountAddress>,
+}
+
+/// The core data of an account, as returned by `/accounts/{address}`
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AccountData {
+    pub sequence_number: U64,
+    pub authentication_key: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::str::FromStr;
+
+    fn account_resources() -> Vec<AccountResource> {
+        serde_json::from_value(json!([
+            {
+                "type": "0x1::account::Account",
+                "data": {
+                    "authentication_key": "0x5e2d9c1f0b2b4a7f8e1d6c3b9a0f7e2d5c8b1a4f7e0d3c6b9a2f5e8d1c4b7a0f",
+                    "coin_register_events": {
+                        "counter": "1",
+                        "guid": {"id": {"addr": "0xb", "creation_num": "0"}}
+                    },
+                    "guid_creation_num": "2",
+                    "key_rotation_events": {
+                        "counter": "0",
+                        "guid": {"id": {"addr": "0xb", "creation_num": "1"}}
+                    },
+                    "rotation_capability_offer": {"for": {"vec": []}},
+                    "sequence_number": "42",
+                    "signer_capability_offer": {"for": {"vec": ["0xc"]}}
+                }
+            },
+            {
+                "type": "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>",
+                "data": {"coin": {"value": "100"}, "frozen": false}
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_resource() {
+        let resources = account_resources();
+        assert_eq!(
+            resources[1].struct_tag(),
+            Some(&StructTag::from_str("0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>").unwrap())
+        );
+
+        let account = resources.find::<Account>().unwrap().unwrap();
+        assert_eq!(account.sequence_number, 42);
+        assert_eq!(account.guid_creation_num, 2);
+        assert_eq!(account.authentication_key.len(), 32);
+        assert_eq!(account.coin_register_events.counter, 1);
+        assert_eq!(
+            account.key_rotation_events.guid.id.addr,
+            AccountAddress::from_str("0xb").unwrap()
+        );
+        assert_eq!(account.rotation_capability_offer.for_, None);
+        assert_eq!(
+            account.signer_capability_offer.for_,
+            Some(AccountAddress::from_str("0xc").unwrap())
+        );
+
+        // Resources round trip with their types as strings
+        let json = serde_json::to_value(&resources).unwrap();
+        assert_eq!(
+            json[1]["type"],
+            "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>"
+        );
+
+        let missing: Vec<AccountResource> = resources[1..].to_vec();
+        assert!(missing.find::<Account>().unwrap().is_none());
+
+        let mut malformed = resources;
+        malformed[0].data["sequence_number"] = json!("forty-two");
+        assert!(malformed.find::<Account>().is_err());
+    }
+
+    #[test]
+    fn test_unparsable_resource_type() {
+        let mut resources = account_resources();
+        resources.extend(
+            serde_json::from_value::<Vec<AccountResource>>(json!([
+                {"type": "0x1::future::Resource<|u64|bool>", "data": {}}
+            ]))
+            .unwrap(),
+        );
+        assert!(resources[2].struct_tag().is_none());
+
+        // The other resources can still be found
+        let account = resources.find::<Account>().unwrap().unwrap();
+        assert_eq!(account.sequence_number, 42);
+    }
 }
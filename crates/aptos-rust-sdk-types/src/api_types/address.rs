@@ -1,3 +1,4 @@
+use crate::api_types::transaction_authenticator::{AuthenticationKey, Scheme};
 use hex::FromHex;
 use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -89,6 +90,45 @@ impl AccountAddress {
 
         result
     }
+
+    /// The address of an object created with a seed, as `object::create_named_object` does with
+    /// the name as the seed
+    pub fn create_object_address(creator: &AccountAddress, seed: &[u8]) -> AccountAddress {
+        let mut preimage = creator.to_vec();
+        preimage.extend(seed);
+        AuthenticationKey::from_preimage(preimage, Scheme::DeriveObjectAddressFromSeed)
+            .account_address()
+    }
+
+    /// The address of an object created from a GUID, as `object::create_object_from_account`
+    /// does with the creator's next GUID creation number
+    pub fn create_object_from_guid(creator: &AccountAddress, creation_num: u64) -> AccountAddress {
+        // The BCS of the GUID's `ID { creation_num, addr }`
+        let mut preimage = creation_num.to_le_bytes().to_vec();
+        preimage.extend(creator.to_bytes());
+        AuthenticationKey::from_preimage(preimage, Scheme::DeriveObjectAddressFromGuid)
+            .account_address()
+    }
+
+    /// The address of an object derived from another address, e.g. an account's primary
+    /// fungible store is derived from the asset's metadata address
+    pub fn create_user_derived_object_address(
+        source: &AccountAddress,
+        derive_from: &AccountAddress,
+    ) -> AccountAddress {
+        let mut preimage = source.to_vec();
+        preimage.extend(derive_from.to_bytes());
+        AuthenticationKey::from_preimage(preimage, Scheme::DeriveObjectAddressFromObject)
+            .account_address()
+    }
+
+    /// The address of a resource account created by `source` with a seed
+    pub fn create_resource_address(source: &AccountAddress, seed: &[u8]) -> AccountAddress {
+        let mut preimage = source.to_vec();
+        preimage.extend(seed);
+        AuthenticationKey::from_preimage(preimage, Scheme::DeriveResourceAccountAddress)
+            .account_address()
+    }
 }
 
 impl Debug for AccountAddress {
@@ -197,3 +237,46 @@ impl Serialize for AccountAddress {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(s: &str) -> AccountAddress {
+        AccountAddress::from_str(s).unwrap()
+    }
+
+    // Expected addresses are the sha3-256 of the preimages the Move framework builds
+
+    #[test]
+    fn test_create_object_address() {
+        assert_eq!(
+            AccountAddress::create_object_address(&address("0xcafe"), b"my_collection"),
+            address("0xaf520e7551a280e8a45f51068ee8bfe6488d409efd8df60e5acd71883ef51382")
+        );
+    }
+
+    #[test]
+    fn test_create_object_from_guid() {
+        assert_eq!(
+            AccountAddress::create_object_from_guid(&address("0xcafe"), 5),
+            address("0xc61bc60fbf72485609393ca5fc094d60d3cca3522050ebdae2b608e4637910ae")
+        );
+    }
+
+    #[test]
+    fn test_create_user_derived_object_address() {
+        assert_eq!(
+            AccountAddress::create_user_derived_object_address(&address("0x123"), &address("0xa")),
+            address("0xb7e65715ea2d87719f30034c6c6b20a73d9ec26adff1190950be9ce9ddcd589b")
+        );
+    }
+
+    #[test]
+    fn test_create_resource_address() {
+        assert_eq!(
+            AccountAddress::create_resource_address(&address("0xcafe"), b"resource_seed"),
+            address("0x63a484ca85b7b2e8eea24396442d858df2f1faa270d0a4d3f7546f3959ad91e9")
+        );
+    }
+}
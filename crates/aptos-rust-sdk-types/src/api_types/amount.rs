@@ -0,0 +1,374 @@
+use crate::api_types::numbers::U128;
+use crate::api_types::transaction::TransactionArgument;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// The number of decimals of APT, i.e. there are 10^8 octas in an APT
+pub const APT_DECIMALS: u8 = 8;
+
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum AmountError {
+    #[error("Amount overflowed")]
+    Overflow,
+    #[error("Amounts have different decimals: {0} and {1}")]
+    DecimalsMismatch(u8, u8),
+    #[error("Amounts have different symbols: {0} and {1}")]
+    SymbolMismatch(String, String),
+    #[error("Invalid amount {0:?}: {1}")]
+    Parse(String, &'static str),
+}
+
+/// A quantity of a coin or fungible asset, held in its smallest unit along with the number of
+/// decimals it's displayed with, e.g. 150000000 octas with 8 decimals is `1.5 APT`.
+///
+/// Arithmetic only combines amounts with the same decimals and symbol, and never rounds.  With
+/// serde, the value is a string, like other large numbers in the API.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(into = "AmountRepr", from = "AmountRepr")]
+pub struct Amount {
+    value: u128,
+    decimals: u8,
+    symbol: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct AmountRepr {
+    value: U128,
+    decimals: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+}
+
+impl Amount {
+    pub fn new(value: impl Into<u128>, decimals: u8) -> Self {
+        Self {
+            value: value.into(),
+            decimals,
+            symbol: None,
+        }
+    }
+
+    /// An amount of APT, given in octas
+    pub fn octas(octas: u64) -> Self {
+        Self::new(octas, APT_DECIMALS).with_symbol("APT")
+    }
+
+    /// Set the symbol shown after the amount
+    pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// Parse an amount in whole units, e.g. `1.5` or `1.5 APT` with 8 decimals is 150000000.
+    ///
+    /// Parsing is exact: more fractional digits than `decimals` is an error rather than being
+    /// rounded.  A symbol after the number is kept.
+    pub fn parse(input: &str, decimals: u8) -> Result<Self, AmountError> {
+        let error = |reason| AmountError::Parse(input.to_string(), reason);
+        let (number, symbol) = match input.trim().split_once(char::is_whitespace) {
+            Some((number, symbol)) => (number, Some(symbol.trim())),
+            None => (input.trim(), None),
+        };
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if number.ends_with('.') {
+            return Err(error("missing digits after the decimal point"));
+        }
+        if whole.is_empty()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(error("expected a non-negative decimal number"));
+        }
+        if fraction.len() > decimals as usize {
+            return Err(error("more fractional digits than the asset's decimals"));
+        }
+
+        // Shift the fraction up to the asset's decimals, so the digits are the value in base units
+        let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+        let value = digits.parse::<u128>().map_err(|_| AmountError::Overflow)?;
+        let amount = Self::new(value, decimals);
+        Ok(match symbol {
+            Some(symbol) => amount.with_symbol(symbol),
+            None => amount,
+        })
+    }
+
+    /// Parse an amount of APT, e.g. `1.5` or `1.5 APT`
+    pub fn parse_apt(input: &str) -> Result<Self, AmountError> {
+        let amount = Self::parse(input, APT_DECIMALS)?;
+        match amount.symbol() {
+            None | Some("APT") => Ok(amount.with_symbol("APT")),
+            Some(symbol) => Err(AmountError::SymbolMismatch(
+                "APT".to_string(),
+                symbol.to_string(),
+            )),
+        }
+    }
+
+    /// The amount in the asset's smallest unit
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    pub fn symbol(&self) -> Option<&str> {
+        self.symbol.as_deref()
+    }
+
+    /// The amount in the asset's smallest unit, if it fits in a `u64`
+    pub fn to_u64(&self) -> Result<u64, AmountError> {
+        u64::try_from(self.value).map_err(|_| AmountError::Overflow)
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Result<Self, AmountError> {
+        let symbol = self.compatible_symbol(other)?;
+        let value = self
+            .value
+            .checked_add(other.value)
+            .ok_or(AmountError::Overflow)?;
+        Ok(Self {
+            value,
+            decimals: self.decimals,
+            symbol,
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, AmountError> {
+        let symbol = self.compatible_symbol(other)?;
+        let value = self
+            .value
+            .checked_sub(other.value)
+            .ok_or(AmountError::Overflow)?;
+        Ok(Self {
+            value,
+            decimals: self.decimals,
+            symbol,
+        })
+    }
+
+    /// Multiply by a whole number, e.g. for paying the same amount to many recipients
+    pub fn checked_mul(&self, factor: u128) -> Result<Self, AmountError> {
+        let value = self
+            .value
+            .checked_mul(factor)
+            .ok_or(AmountError::Overflow)?;
+        Ok(Self {
+            value,
+            ..self.clone()
+        })
+    }
+
+    /// The BCS encoding of the value as a Move `u64`, as used by coin and fungible asset functions
+    pub fn to_u64_argument(&self) -> Result<Vec<u8>, AmountError> {
+        Ok(self.to_u64()?.to_le_bytes().to_vec())
+    }
+
+    /// The BCS encoding of the value as a Move `u128`
+    pub fn to_u128_argument(&self) -> Vec<u8> {
+        self.value.to_le_bytes().to_vec()
+    }
+
+    /// The symbol of the two amounts combined, as long as they're the same asset
+    fn compatible_symbol(&self, other: &Self) -> Result<Option<String>, AmountError> {
+        if self.decimals != other.decimals {
+            return Err(AmountError::DecimalsMismatch(self.decimals, other.decimals));
+        }
+        match (&self.symbol, &other.symbol) {
+            (Some(symbol), Some(other_symbol)) if symbol != other_symbol => Err(
+                AmountError::SymbolMismatch(symbol.clone(), other_symbol.clone()),
+            ),
+            (symbol, other_symbol) => Ok(symbol.clone().or_else(|| other_symbol.clone())),
+        }
+    }
+}
+
+/// A script argument with the value as a Move `u64`
+impl TryFrom<&Amount> for TransactionArgument {
+    type Error = AmountError;
+
+    fn try_from(amount: &Amount) -> Result<Self, Self::Error> {
+        Ok(TransactionArgument::U64(amount.to_u64()?))
+    }
+}
+
+impl From<Amount> for AmountRepr {
+    fn from(amount: Amount) -> Self {
+        Self {
+            value: amount.value.into(),
+            decimals: amount.decimals,
+            symbol: amount.symbol,
+        }
+    }
+}
+
+impl From<AmountRepr> for Amount {
+    fn from(repr: AmountRepr) -> Self {
+        Self {
+            value: repr.value.into(),
+            decimals: repr.decimals,
+            symbol: repr.symbol,
+        }
+    }
+}
+
+/// Formats the amount in whole units, without trailing zeros, e.g. `1.5 APT`
+impl Display for Amount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let decimals = self.decimals as usize;
+        let digits = format!("{:0>width$}", self.value, width = decimals + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+
+        f.write_str(whole)?;
+        if !fraction.is_empty() {
+            write!(f, ".{}", fraction)?;
+        }
+        if let Some(symbol) = &self.symbol {
+            write!(f, " {}", symbol)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Amount::octas(150_000_000).to_string(), "1.5 APT");
+        assert_eq!(Amount::octas(100_000_000).to_string(), "1 APT");
+        assert_eq!(Amount::octas(1).to_string(), "0.00000001 APT");
+        assert_eq!(Amount::octas(0).to_string(), "0 APT");
+        assert_eq!(Amount::new(123_456u64, 0).to_string(), "123456");
+        assert_eq!(Amount::new(1_050_000u64, 6).to_string(), "1.05");
+        assert_eq!(
+            Amount::new(u128::MAX, 38).to_string(),
+            "3.40282366920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Amount::parse_apt("1.5 APT").unwrap(),
+            Amount::octas(150_000_000)
+        );
+        assert_eq!(
+            Amount::parse_apt("1.5").unwrap(),
+            Amount::octas(150_000_000)
+        );
+        assert_eq!(Amount::parse_apt("0.00000001").unwrap(), Amount::octas(1));
+        assert_eq!(Amount::parse("42", 0).unwrap(), Amount::new(42u64, 0));
+        assert_eq!(
+            Amount::parse("1.05 USDC", 6).unwrap(),
+            Amount::new(1_050_000u64, 6).with_symbol("USDC")
+        );
+
+        // Every formatted amount parses back to itself
+        for amount in [
+            Amount::octas(150_000_000),
+            Amount::octas(1),
+            Amount::new(u128::MAX, 38).with_symbol("BIG"),
+        ] {
+            assert_eq!(
+                Amount::parse(&amount.to_string(), amount.decimals()).unwrap(),
+                amount
+            );
+        }
+
+        for invalid in ["", "1.", ".5", "-1", "1e8", "1,5", "0x10", "1.000000001"] {
+            assert!(
+                matches!(Amount::parse_apt(invalid), Err(AmountError::Parse(..))),
+                "{:?} should not parse",
+                invalid
+            );
+        }
+        assert_eq!(
+            Amount::parse_apt("1 USDC"),
+            Err(AmountError::SymbolMismatch(
+                "APT".to_string(),
+                "USDC".to_string()
+            ))
+        );
+        assert_eq!(
+            Amount::parse("340282366920938463463374607431768211456", 0),
+            Err(AmountError::Overflow)
+        );
+        assert_eq!(Amount::parse("1", 39), Err(AmountError::Overflow));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let one = Amount::octas(100_000_000);
+        let half = Amount::parse_apt("0.5").unwrap();
+        assert_eq!(one.checked_add(&half).unwrap().to_string(), "1.5 APT");
+        assert_eq!(one.checked_sub(&half).unwrap(), half);
+        assert_eq!(half.checked_mul(3).unwrap().to_string(), "1.5 APT");
+        assert_eq!(half.checked_sub(&one), Err(AmountError::Overflow));
+        assert_eq!(
+            Amount::new(u128::MAX, 0).checked_add(&Amount::new(1u64, 0)),
+            Err(AmountError::Overflow)
+        );
+
+        // The symbol is picked up from either side, but must agree
+        let bare = Amount::new(1u64, APT_DECIMALS);
+        assert_eq!(bare.checked_add(&one).unwrap().symbol(), Some("APT"));
+        assert_eq!(
+            one.checked_add(&bare.clone().with_symbol("USDC")),
+            Err(AmountError::SymbolMismatch(
+                "APT".to_string(),
+                "USDC".to_string()
+            ))
+        );
+        assert_eq!(
+            one.checked_add(&Amount::new(1u64, 6)),
+            Err(AmountError::DecimalsMismatch(8, 6))
+        );
+    }
+
+    #[test]
+    fn test_arguments() {
+        let amount = Amount::octas(150_000_000);
+        assert_eq!(
+            amount.to_u64_argument().unwrap(),
+            aptos_bcs::to_bytes(&150_000_000u64).unwrap()
+        );
+        assert_eq!(
+            amount.to_u128_argument(),
+            aptos_bcs::to_bytes(&150_000_000u128).unwrap()
+        );
+        assert_eq!(
+            TransactionArgument::try_from(&amount).unwrap(),
+            TransactionArgument::U64(150_000_000)
+        );
+
+        let large = Amount::new(u128::from(u64::MAX) + 1, 0);
+        assert_eq!(large.to_u64_argument(), Err(AmountError::Overflow));
+        assert!(TransactionArgument::try_from(&large).is_err());
+    }
+
+    #[test]
+    fn test_serde() {
+        let amount = Amount::octas(150_000_000);
+        let json = serde_json::to_value(&amount).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"value": "150000000", "decimals": 8, "symbol": "APT"})
+        );
+        assert_eq!(serde_json::from_value::<Amount>(json).unwrap(), amount);
+
+        let bare: Amount = serde_json::from_str(
+            r#"{"value": "340282366920938463463374607431768211455", "decimals": 0}"#,
+        )
+        .unwrap();
+        assert_eq!(bare, Amount::new(u128::MAX, 0));
+        assert!(serde_json::from_str::<Amount>(r#"{"value": 1, "decimals": 0}"#).is_err());
+    }
+}
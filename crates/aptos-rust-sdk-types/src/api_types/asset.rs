@@ -0,0 +1,33 @@
+use crate::api_types::address::AccountAddress;
+use crate::api_types::type_tag::{StructTag, TypeTag};
+use serde::{Deserialize, Serialize};
+
+/// A coin or fungible asset.  Since the migration to fungible assets, a coin's balance can be
+/// split between its `CoinStore` and the primary fungible store of its paired fungible asset.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Asset {
+    /// A coin, by its type, e.g. `0x1::aptos_coin::AptosCoin`
+    Coin(TypeTag),
+    /// A fungible asset, by the address of its metadata object
+    FungibleAsset(AccountAddress),
+}
+
+impl Asset {
+    /// APT, by its coin type
+    pub fn apt() -> Self {
+        Self::Coin(TypeTag::Struct(Box::new(StructTag {
+            address: AccountAddress::ONE,
+            module: "aptos_coin".to_string(),
+            name: "AptosCoin".to_string(),
+            type_args: vec![],
+        })))
+    }
+}
+
+/// How an asset is displayed, from its `CoinInfo` or fungible asset `Metadata`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AssetMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
@@ -0,0 +1,813 @@
+//! A lightweight reader for compiled Move modules, which only reads what's needed for the
+//! module's ABI: its name, friends, structs and exposed functions.  Function bodies are skipped.
+
+use crate::api_types::address::AccountAddress;
+use crate::api_types::move_module::{
+    MoveAbility, MoveFunction, MoveFunctionGenericTypeParam, MoveFunctionVisibility, MoveModule,
+    MoveStruct, MoveStructField, MoveStructGenericTypeParam, MoveStructTag, MoveType,
+};
+use crate::api_types::move_value::read_uleb128;
+use anyhow::{anyhow, bail, ensure};
+
+const MAGIC: [u8; 4] = [0xA1, 0x1C, 0xEB, 0x0B];
+const MIN_VERSION: u32 = 5;
+const MAX_VERSION: u32 = 8;
+/// From v7 the version's high byte holds the bytecode flavor, e.g. `0x0A` for Aptos
+const VERSION_MASK: u32 = 0x00FF_FFFF;
+
+// Table kinds
+const MODULE_HANDLES: u8 = 0x1;
+const STRUCT_HANDLES: u8 = 0x2;
+const FUNCTION_HANDLES: u8 = 0x3;
+const SIGNATURES: u8 = 0x5;
+const IDENTIFIERS: u8 = 0x7;
+const ADDRESS_IDENTIFIERS: u8 = 0x8;
+const STRUCT_DEFS: u8 = 0xA;
+const FUNCTION_DEFS: u8 = 0xC;
+const FRIEND_DECLS: u8 = 0xF;
+
+// Signature tokens
+const TOKEN_BOOL: u8 = 0x1;
+const TOKEN_U8: u8 = 0x2;
+const TOKEN_U64: u8 = 0x3;
+const TOKEN_U128: u8 = 0x4;
+const TOKEN_ADDRESS: u8 = 0x5;
+const TOKEN_REFERENCE: u8 = 0x6;
+const TOKEN_MUTABLE_REFERENCE: u8 = 0x7;
+const TOKEN_STRUCT: u8 = 0x8;
+const TOKEN_TYPE_PARAMETER: u8 = 0x9;
+const TOKEN_VECTOR: u8 = 0xA;
+const TOKEN_STRUCT_INST: u8 = 0xB;
+const TOKEN_SIGNER: u8 = 0xC;
+const TOKEN_U16: u8 = 0xD;
+const TOKEN_U32: u8 = 0xE;
+const TOKEN_U256: u8 = 0xF;
+const TOKEN_FUNCTION: u8 = 0x10;
+
+// Struct field information
+const FIELDS_NATIVE: u8 = 0x1;
+const FIELDS_DECLARED: u8 = 0x2;
+const FIELDS_VARIANTS: u8 = 0x3;
+
+// Function definition flags
+const FUNCTION_NATIVE: u8 = 0x2;
+const FUNCTION_ENTRY: u8 = 0x4;
+
+// Serialized options, e.g. for access specifiers
+const OPTION_NONE: u8 = 0x1;
+
+/// Read a module's ABI from its bytecode
+pub fn deserialize_module(bytes: &[u8]) -> anyhow::Result<MoveModule> {
+    let mut cursor = Cursor::new(bytes);
+    ensure!(cursor.take(4)? == MAGIC, "Not a Move module");
+    let version = u32::from_le_bytes(cursor.take_array()?) & VERSION_MASK;
+    ensure!(
+        (MIN_VERSION..=MAX_VERSION).contains(&version),
+        "Unsupported bytecode version {}",
+        version
+    );
+
+    // Each table header takes at least 3 bytes, so a larger count can't be allocated up front
+    let table_count = cursor.read_uleb128()?;
+    ensure!(table_count <= cursor.bytes.len() / 3, "Truncated module");
+    let mut tables = Vec::with_capacity(table_count);
+    for _ in 0..table_count {
+        let kind = cursor.take(1)?[0];
+        let offset = cursor.read_uleb128()?;
+        let len = cursor.read_uleb128()?;
+        tables.push((kind, offset, len));
+    }
+    let contents = cursor.bytes;
+    let contents_len = tables.iter().map(|(_, _, len)| len).sum::<usize>();
+    ensure!(contents.len() >= contents_len, "Truncated module");
+    let table = |kind: u8| -> anyhow::Result<Cursor> {
+        match tables.iter().find(|(table_kind, _, _)| *table_kind == kind) {
+            Some((_, offset, len)) => {
+                let end = offset
+                    .checked_add(*len)
+                    .filter(|end| *end <= contents_len)
+                    .ok_or_else(|| anyhow!("Table {} is out of bounds", kind))?;
+                Ok(Cursor::with_version(&contents[*offset..end], version))
+            }
+            None => Ok(Cursor::with_version(&[], version)),
+        }
+    };
+
+    let module = Module {
+        identifiers: table(IDENTIFIERS)?.read_all(Cursor::read_identifier)?,
+        addresses: table(ADDRESS_IDENTIFIERS)?
+            .read_all(|cursor| Ok(AccountAddress::new(cursor.take_array()?)))?,
+        module_handles: table(MODULE_HANDLES)?.read_all(Cursor::read_module_handle)?,
+        struct_handles: table(STRUCT_HANDLES)?.read_all(Cursor::read_struct_handle)?,
+        function_handles: table(FUNCTION_HANDLES)?.read_all(Cursor::read_function_handle)?,
+        signatures: table(SIGNATURES)?.read_all(|cursor| cursor.read_vec(Cursor::read_token))?,
+    };
+    let friends = table(FRIEND_DECLS)?.read_all(Cursor::read_module_handle)?;
+    let struct_defs = table(STRUCT_DEFS)?.read_all(Cursor::read_struct_def)?;
+    let function_defs = table(FUNCTION_DEFS)?.read_all(Cursor::read_function_def)?;
+
+    // The index of the module's own handle follows the tables
+    let mut cursor = Cursor::new(&contents[contents_len..]);
+    let self_handle = module.module_handle(cursor.read_uleb128()?)?;
+
+    Ok(MoveModule {
+        address: self_handle.0,
+        name: self_handle.1.to_string(),
+        friends: friends
+            .iter()
+            .map(|handle| {
+                let (address, name) = module.resolve_module_handle(handle)?;
+                Ok(format!("0x{}::{}", address.short_str_lossless(), name))
+            })
+            .collect::<anyhow::Result<_>>()?,
+        exposed_functions: function_defs
+            .iter()
+            .filter(|def| def.visibility != MoveFunctionVisibility::Private || def.is_entry)
+            .map(|def| module.function(def))
+            .collect::<anyhow::Result<_>>()?,
+        structs: struct_defs
+            .iter()
+            .map(|def| module.move_struct(def))
+            .collect::<anyhow::Result<_>>()?,
+    })
+}
+
+/// A module handle, as indexes of its address and name
+type ModuleHandle = (usize, usize);
+
+struct StructHandle {
+    module: usize,
+    name: usize,
+    abilities: Vec<MoveAbility>,
+    type_params: Vec<Vec<MoveAbility>>,
+}
+
+struct FunctionHandle {
+    name: usize,
+    params: usize,
+    return_: usize,
+    type_params: Vec<Vec<MoveAbility>>,
+}
+
+/// A signature token, with struct handles unresolved
+enum Token {
+    Type(MoveType),
+    Vector(Box<Token>),
+    Reference(bool, Box<Token>),
+    Struct(usize, Vec<Token>),
+    Function {
+        args: Vec<Token>,
+        results: Vec<Token>,
+        abilities: Vec<MoveAbility>,
+    },
+}
+
+struct StructDef {
+    handle: usize,
+    is_native: bool,
+    fields: Vec<(usize, Token)>,
+}
+
+struct FunctionDef {
+    handle: usize,
+    visibility: MoveFunctionVisibility,
+    is_entry: bool,
+}
+
+/// The tables needed to resolve handles and signatures
+struct Module {
+    identifiers: Vec<String>,
+    addresses: Vec<AccountAddress>,
+    module_handles: Vec<ModuleHandle>,
+    struct_handles: Vec<StructHandle>,
+    function_handles: Vec<FunctionHandle>,
+    signatures: Vec<Vec<Token>>,
+}
+
+impl Module {
+    fn identifier(&self, index: usize) -> anyhow::Result<&str> {
+        self.identifiers
+            .get(index)
+            .map(String::as_str)
+            .ok_or_else(|| anyhow!("Invalid identifier index {}", index))
+    }
+
+    fn module_handle(&self, index: usize) -> anyhow::Result<(AccountAddress, &str)> {
+        let handle = self
+            .module_handles
+            .get(index)
+            .ok_or_else(|| anyhow!("Invalid module handle index {}", index))?;
+        self.resolve_module_handle(handle)
+    }
+
+    fn resolve_module_handle(
+        &self,
+        (address, name): &ModuleHandle,
+    ) -> anyhow::Result<(AccountAddress, &str)> {
+        let address = self
+            .addresses
+            .get(*address)
+            .ok_or_else(|| anyhow!("Invalid address index {}", address))?;
+        Ok((*address, self.identifier(*name)?))
+    }
+
+    fn struct_handle(&self, index: usize) -> anyhow::Result<&StructHandle> {
+        self.struct_handles
+            .get(index)
+            .ok_or_else(|| anyhow!("Invalid struct handle index {}", index))
+    }
+
+    fn signature(&self, index: usize) -> anyhow::Result<Vec<MoveType>> {
+        self.signatures
+            .get(index)
+            .ok_or_else(|| anyhow!("Invalid signature index {}", index))?
+            .iter()
+            .map(|token| self.move_type(token))
+            .collect()
+    }
+
+    fn move_type(&self, token: &Token) -> anyhow::Result<MoveType> {
+        Ok(match token {
+            Token::Type(move_type) => move_type.clone(),
+            Token::Vector(inner) => MoveType::Vector(Box::new(self.move_type(inner)?)),
+            Token::Reference(mutable, to) => MoveType::Reference {
+                mutable: *mutable,
+                to: Box::new(self.move_type(to)?),
+            },
+            Token::Struct(handle, type_args) => {
+                let handle = self.struct_handle(*handle)?;
+                let (address, module) = self.module_handle(handle.module)?;
+                MoveType::Struct(MoveStructTag {
+                    address,
+                    module: module.to_string(),
+                    name: self.identifier(handle.name)?.to_string(),
+                    generic_type_params: type_args
+                        .iter()
+                        .map(|token| self.move_type(token))
+                        .collect::<anyhow::Result<_>>()?,
+                })
+            }
+            // Function values have no ABI type, so they're written out as in Move
+            Token::Function {
+                args,
+                results,
+                abilities,
+            } => {
+                let types = |tokens: &[Token]| {
+                    tokens
+                        .iter()
+                        .map(|token| Ok(self.move_type(token)?.to_string()))
+                        .collect::<anyhow::Result<Vec<_>>>()
+                };
+                let results = types(results)?;
+                let mut type_ = format!("|{}|", types(args)?.join(", "));
+                match results.len() {
+                    0 => {}
+                    1 => type_.push_str(&results[0]),
+                    _ => type_.push_str(&format!("({})", results.join(", "))),
+                }
+                if !abilities.is_empty() {
+                    let abilities: Vec<_> = abilities.iter().map(ToString::to_string).collect();
+                    type_.push_str(&format!(" has {}", abilities.join(" + ")));
+                }
+                MoveType::Unparsable(type_)
+            }
+        })
+    }
+
+    fn function(&self, def: &FunctionDef) -> anyhow::Result<MoveFunction> {
+        let handle = self
+            .function_handles
+            .get(def.handle)
+            .ok_or_else(|| anyhow!("Invalid function handle index {}", def.handle))?;
+        Ok(MoveFunction {
+            name: self.identifier(handle.name)?.to_string(),
+            visibility: def.visibility,
+            is_entry: def.is_entry,
+            is_view: false,
+            generic_type_params: handle
+                .type_params
+                .iter()
+                .map(|constraints| MoveFunctionGenericTypeParam {
+                    constraints: constraints.clone(),
+                })
+                .collect(),
+            params: self.signature(handle.params)?,
+            return_: self.signature(handle.return_)?,
+        })
+    }
+
+    fn move_struct(&self, def: &StructDef) -> anyhow::Result<MoveStruct> {
+        let handle = self.struct_handle(def.handle)?;
+        Ok(MoveStruct {
+            name: self.identifier(handle.name)?.to_string(),
+            is_native: def.is_native,
+            abilities: handle.abilities.clone(),
+            generic_type_params: handle
+                .type_params
+                .iter()
+                .map(|constraints| MoveStructGenericTypeParam {
+                    constraints: constraints.clone(),
+                })
+                .collect(),
+            fields: def
+                .fields
+                .iter()
+                .map(|(name, token)| {
+                    Ok(MoveStructField {
+                        name: self.identifier(*name)?.to_string(),
+                        type_: self.move_type(token)?,
+                    })
+                })
+                .collect::<anyhow::Result<_>>()?,
+        })
+    }
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    version: u32,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self::with_version(bytes, MAX_VERSION)
+    }
+
+    fn with_version(bytes: &'a [u8], version: u32) -> Self {
+        Self { bytes, version }
+    }
+
+    fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        ensure!(self.bytes.len() >= len, "Unexpected end of module");
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> anyhow::Result<[u8; N]> {
+        Ok(self.take(N)?.try_into()?)
+    }
+
+    fn read_u8(&mut self) -> anyhow::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_uleb128(&mut self) -> anyhow::Result<usize> {
+        read_uleb128(&mut self.bytes)
+    }
+
+    /// Read entries until the table is used up
+    fn read_all<T>(
+        mut self,
+        mut read: impl FnMut(&mut Self) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Vec<T>> {
+        let mut entries = vec![];
+        while !self.bytes.is_empty() {
+            entries.push(read(&mut self)?);
+        }
+        Ok(entries)
+    }
+
+    /// Read a length prefixed list
+    fn read_vec<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Vec<T>> {
+        let len = self.read_uleb128()?;
+        (0..len).map(|_| read(self)).collect()
+    }
+
+    fn read_identifier(&mut self) -> anyhow::Result<String> {
+        let len = self.read_uleb128()?;
+        Ok(std::str::from_utf8(self.take(len)?)?.to_string())
+    }
+
+    fn read_module_handle(&mut self) -> anyhow::Result<ModuleHandle> {
+        Ok((self.read_uleb128()?, self.read_uleb128()?))
+    }
+
+    fn read_abilities(&mut self) -> anyhow::Result<Vec<MoveAbility>> {
+        let bits = self.read_u8()?;
+        ensure!(bits & !0xF == 0, "Invalid ability set {}", bits);
+        Ok([
+            (0x1, MoveAbility::Copy),
+            (0x2, MoveAbility::Drop),
+            (0x4, MoveAbility::Store),
+            (0x8, MoveAbility::Key),
+        ]
+        .into_iter()
+        .filter(|(bit, _)| bits & bit != 0)
+        .map(|(_, ability)| ability)
+        .collect())
+    }
+
+    fn read_struct_handle(&mut self) -> anyhow::Result<StructHandle> {
+        Ok(StructHandle {
+            module: self.read_uleb128()?,
+            name: self.read_uleb128()?,
+            abilities: self.read_abilities()?,
+            type_params: self.read_vec(|cursor| {
+                let constraints = cursor.read_abilities()?;
+                // Whether the param is phantom
+                cursor.read_u8()?;
+                Ok(constraints)
+            })?,
+        })
+    }
+
+    fn read_function_handle(&mut self) -> anyhow::Result<FunctionHandle> {
+        let handle = FunctionHandle {
+            // The module is always the module itself for exposed functions
+            name: {
+                self.read_uleb128()?;
+                self.read_uleb128()?
+            },
+            params: self.read_uleb128()?,
+            return_: self.read_uleb128()?,
+            type_params: self.read_vec(Cursor::read_abilities)?,
+        };
+        if self.version >= 7 {
+            ensure!(
+                self.read_u8()? == OPTION_NONE,
+                "Access specifiers are not supported"
+            );
+        }
+        if self.version >= 8 {
+            // Function attributes
+            self.read_vec(Cursor::read_u8)?;
+        }
+        Ok(handle)
+    }
+
+    fn read_token(&mut self) -> anyhow::Result<Token> {
+        Ok(match self.read_u8()? {
+            TOKEN_BOOL => Token::Type(MoveType::Bool),
+            TOKEN_U8 => Token::Type(MoveType::U8),
+            TOKEN_U16 => Token::Type(MoveType::U16),
+            TOKEN_U32 => Token::Type(MoveType::U32),
+            TOKEN_U64 => Token::Type(MoveType::U64),
+            TOKEN_U128 => Token::Type(MoveType::U128),
+            TOKEN_U256 => Token::Type(MoveType::U256),
+            TOKEN_ADDRESS => Token::Type(MoveType::Address),
+            TOKEN_SIGNER => Token::Type(MoveType::Signer),
+            TOKEN_VECTOR => Token::Vector(Box::new(self.read_token()?)),
+            TOKEN_REFERENCE => Token::Reference(false, Box::new(self.read_token()?)),
+            TOKEN_MUTABLE_REFERENCE => Token::Reference(true, Box::new(self.read_token()?)),
+            TOKEN_STRUCT => Token::Struct(self.read_uleb128()?, vec![]),
+            TOKEN_STRUCT_INST => {
+                Token::Struct(self.read_uleb128()?, self.read_vec(Cursor::read_token)?)
+            }
+            TOKEN_TYPE_PARAMETER => Token::Type(MoveType::GenericTypeParam(u16::try_from(
+                self.read_uleb128()?,
+            )?)),
+            TOKEN_FUNCTION => {
+                let args_len = self.read_uleb128()?;
+                let results_len = self.read_uleb128()?;
+                let abilities = self.read_abilities()?;
+                Token::Function {
+                    args: (0..args_len)
+                        .map(|_| self.read_token())
+                        .collect::<anyhow::Result<_>>()?,
+                    results: (0..results_len)
+                        .map(|_| self.read_token())
+                        .collect::<anyhow::Result<_>>()?,
+                    abilities,
+                }
+            }
+            // Types from newer bytecode versions are kept, so the rest of the ABI still loads
+            other => Token::Type(MoveType::Unparsable(format!("<unknown type {:#x}>", other))),
+        })
+    }
+
+    fn read_struct_def(&mut self) -> anyhow::Result<StructDef> {
+        let handle = self.read_uleb128()?;
+        let read_field = |cursor: &mut Self| Ok((cursor.read_uleb128()?, cursor.read_token()?));
+        let (is_native, fields) = match self.read_u8()? {
+            FIELDS_NATIVE => (true, vec![]),
+            FIELDS_DECLARED => (false, self.read_vec(read_field)?),
+            FIELDS_VARIANTS => {
+                // Enum variants have no fields of the struct itself
+                self.read_vec(|cursor| {
+                    cursor.read_uleb128()?;
+                    cursor.read_vec(read_field)
+                })?;
+                (false, vec![])
+            }
+            other => bail!("Invalid struct field information {:#x}", other),
+        };
+        Ok(StructDef {
+            handle,
+            is_native,
+            fields,
+        })
+    }
+
+    fn read_function_def(&mut self) -> anyhow::Result<FunctionDef> {
+        let handle = self.read_uleb128()?;
+        let visibility = match self.read_u8()? {
+            0 => MoveFunctionVisibility::Private,
+            1 => MoveFunctionVisibility::Public,
+            3 => MoveFunctionVisibility::Friend,
+            other => bail!("Invalid visibility {}", other),
+        };
+        let flags = self.read_u8()?;
+        // Acquired resources
+        self.read_vec(Cursor::read_uleb128)?;
+        if flags & FUNCTION_NATIVE == 0 {
+            // Locals, then the code
+            self.read_uleb128()?;
+            let len = self.read_uleb128()?;
+            for _ in 0..len {
+                self.skip_instruction()?;
+            }
+        }
+        Ok(FunctionDef {
+            handle,
+            visibility,
+            is_entry: flags & FUNCTION_ENTRY != 0,
+        })
+    }
+
+    /// Skip an instruction and its operands
+    fn skip_instruction(&mut self) -> anyhow::Result<()> {
+        match self.read_u8()? {
+            // Local indexes
+            0x0A..=0x0E => {
+                self.read_u8()?;
+            }
+            // Constants
+            0x31 => {
+                self.take(1)?;
+            }
+            0x48 => {
+                self.take(2)?;
+            }
+            0x49 => {
+                self.take(4)?;
+            }
+            0x06 => {
+                self.take(8)?;
+            }
+            0x32 => {
+                self.take(16)?;
+            }
+            0x4A => {
+                self.take(32)?;
+            }
+            // A signature index and an element count
+            0x40 | 0x46 => {
+                self.read_uleb128()?;
+                self.take(8)?;
+            }
+            // A function handle index and a mask of captured arguments
+            0x58 | 0x59 => {
+                self.read_uleb128()?;
+                self.take(8)?;
+            }
+            // A single index or code offset
+            0x03..=0x05
+            | 0x07
+            | 0x0F..=0x13
+            | 0x29..=0x2D
+            | 0x36..=0x3F
+            | 0x41..=0x45
+            | 0x47
+            | 0x4E..=0x57
+            | 0x5A => {
+                self.read_uleb128()?;
+            }
+            // No operands
+            0x01 | 0x02 | 0x08 | 0x09 | 0x14..=0x28 | 0x2E..=0x30 | 0x33..=0x35 | 0x4B..=0x4D => {}
+            other => bail!("Unknown instruction {:#x}", other),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn uleb128(mut value: usize) -> Vec<u8> {
+        let mut bytes = vec![];
+        while value >= 0x80 {
+            bytes.push((value & 0x7f) as u8 | 0x80);
+            value >>= 7;
+        }
+        bytes.push(value as u8);
+        bytes
+    }
+
+    fn identifier(name: &str) -> Vec<u8> {
+        [uleb128(name.len()), name.as_bytes().to_vec()].concat()
+    }
+
+    /// Assemble a module from its tables, in the binary format.  Versions from v7 are marked as
+    /// Aptos bytecode, as the Aptos compiler does.
+    fn assemble(version: u32, tables: &[(u8, Vec<u8>)], self_handle: usize) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        let flavor = if version >= 7 { 0x0A00_0000 } else { 0 };
+        bytes.extend((version | flavor).to_le_bytes());
+        bytes.extend(uleb128(tables.len()));
+        let mut offset = 0;
+        for (kind, contents) in tables {
+            bytes.push(*kind);
+            bytes.extend(uleb128(offset));
+            bytes.extend(uleb128(contents.len()));
+            offset += contents.len();
+        }
+        for (_, contents) in tables {
+            bytes.extend(contents);
+        }
+        bytes.extend(uleb128(self_handle));
+        bytes
+    }
+
+    /// A module equivalent to
+    ///
+    /// ```move
+    /// module 0xcafe::example {
+    ///     friend 0xcafe::other;
+    ///     struct Box<T: store> has copy, drop, store { value: T }
+    ///     entry fun create<T: store>(account: &signer, value: T) { ... }
+    ///     public fun get<T>(box: Box<T>): vector<u8> { ... }
+    ///     native fun helper();
+    /// }
+    /// ```
+    fn example_module(version: u32) -> Vec<u8> {
+        let identifiers = [
+            "example", "Box", "value", "create", "get", "helper", "other",
+        ]
+        .iter()
+        .flat_map(|name| identifier(name))
+        .collect();
+        let addresses = AccountAddress::from_str("0xcafe").unwrap().to_vec();
+        let module_handles = vec![0, 0];
+        // Box, with `store` on its param, which isn't phantom
+        let struct_handles = vec![0, 1, 0x7, 1, 0x4, 0];
+        let signatures = [
+            vec![0],
+            vec![2, TOKEN_REFERENCE, TOKEN_SIGNER, TOKEN_TYPE_PARAMETER, 0],
+            vec![1, TOKEN_STRUCT_INST, 0, 1, TOKEN_TYPE_PARAMETER, 0],
+            vec![1, TOKEN_VECTOR, TOKEN_U8],
+        ]
+        .concat();
+        let function_handle = |name: u8, params: u8, return_: u8, type_params: &[u8]| {
+            let mut handle = vec![0, name, params, return_, type_params.len() as u8];
+            handle.extend(type_params);
+            if version >= 7 {
+                handle.push(OPTION_NONE);
+            }
+            if version >= 8 {
+                handle.push(0);
+            }
+            handle
+        };
+        let function_handles = [
+            function_handle(3, 1, 0, &[0x4]),
+            function_handle(4, 2, 3, &[0x0]),
+            function_handle(5, 0, 0, &[]),
+        ]
+        .concat();
+        let struct_defs = vec![0, FIELDS_DECLARED, 1, 2, TOKEN_TYPE_PARAMETER, 0];
+        let function_defs = [
+            // create: private entry, moving a local and returning
+            vec![0, 0, FUNCTION_ENTRY, 0, 0, 3, 0x0B, 1, 0x01, 0x02],
+            // get: public, loading a u64 and a constant
+            [
+                vec![1, 1, 0, 0, 0, 5, 0x06],
+                7u64.to_le_bytes().to_vec(),
+                vec![0x01, 0x07, 0, 0x01, 0x02],
+            ]
+            .concat(),
+            // helper: private native, which isn't exposed
+            vec![2, 0, FUNCTION_NATIVE, 0],
+        ]
+        .concat();
+        let friend_decls = vec![0, 6];
+        assemble(
+            version,
+            &[
+                (MODULE_HANDLES, module_handles),
+                (STRUCT_HANDLES, struct_handles),
+                (FUNCTION_HANDLES, function_handles),
+                (SIGNATURES, signatures),
+                (IDENTIFIERS, identifiers),
+                (ADDRESS_IDENTIFIERS, addresses),
+                (STRUCT_DEFS, struct_defs),
+                (FUNCTION_DEFS, function_defs),
+                (FRIEND_DECLS, friend_decls),
+            ],
+            0,
+        )
+    }
+
+    #[test]
+    fn test_deserialize_module() {
+        for version in [6, 7, 8] {
+            let module = MoveModule::from_bytecode(&example_module(version)).unwrap();
+            assert_eq!(module.address, AccountAddress::from_str("0xcafe").unwrap());
+            assert_eq!(module.name, "example");
+            assert_eq!(module.friends, vec!["0xcafe::other"]);
+
+            let names: Vec<_> = module
+                .exposed_functions
+                .iter()
+                .map(|function| function.name.as_str())
+                .collect();
+            assert_eq!(names, vec!["create", "get"]);
+
+            let create = module.function("create").unwrap();
+            assert!(create.is_entry);
+            assert_eq!(create.visibility, MoveFunctionVisibility::Private);
+            assert_eq!(
+                create.generic_type_params[0].constraints,
+                vec![MoveAbility::Store]
+            );
+            let params: Vec<_> = create.params.iter().map(MoveType::to_string).collect();
+            assert_eq!(params, vec!["&signer", "T0"]);
+
+            let get = module.function("get").unwrap();
+            assert!(!get.is_entry);
+            assert_eq!(get.params[0].to_string(), "0xcafe::example::Box<T0>");
+            assert_eq!(get.return_[0].to_string(), "vector<u8>");
+
+            let move_struct = module.move_struct("Box").unwrap();
+            assert_eq!(
+                move_struct.abilities,
+                vec![MoveAbility::Copy, MoveAbility::Drop, MoveAbility::Store]
+            );
+            assert_eq!(move_struct.fields[0].name, "value");
+            assert_eq!(move_struct.fields[0].type_, MoveType::GenericTypeParam(0));
+        }
+    }
+
+    #[test]
+    fn test_flavored_version() {
+        let module = example_module(7);
+        assert_eq!(module[4..8], [0x07, 0x00, 0x00, 0x0A]);
+        assert!(MoveModule::from_bytecode(&module).is_ok());
+    }
+
+    #[test]
+    fn test_unsupported_types() {
+        let module = Module {
+            identifiers: vec![],
+            addresses: vec![],
+            module_handles: vec![],
+            struct_handles: vec![],
+            function_handles: vec![],
+            signatures: vec![],
+        };
+        let move_type = |bytes: &[u8]| {
+            let mut cursor = Cursor::new(bytes);
+            let move_type = module.move_type(&cursor.read_token().unwrap()).unwrap();
+            assert!(cursor.bytes.is_empty());
+            move_type
+        };
+
+        // A v8 function value, `|u64, &signer|bool has copy + drop`
+        let function = [
+            TOKEN_FUNCTION,
+            2,
+            1,
+            0x3,
+            TOKEN_U64,
+            TOKEN_REFERENCE,
+            TOKEN_SIGNER,
+            TOKEN_BOOL,
+        ];
+        assert_eq!(
+            move_type(&function),
+            MoveType::Unparsable("|u64, &signer|bool has copy + drop".to_string())
+        );
+        assert_eq!(
+            move_type(&[TOKEN_VECTOR, TOKEN_FUNCTION, 0, 0, 0]),
+            MoveType::Vector(Box::new(MoveType::Unparsable("||".to_string())))
+        );
+        assert_eq!(
+            move_type(&[0x20]),
+            MoveType::Unparsable("<unknown type 0x20>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_invalid_module() {
+        let module = example_module(7);
+        assert!(MoveModule::from_bytecode(&module[..module.len() - 10]).is_err());
+        assert!(MoveModule::from_bytecode(&example_module(4)).is_err());
+
+        let mut bad_magic = module;
+        bad_magic[0] = 0;
+        assert!(MoveModule::from_bytecode(&bad_magic).is_err());
+
+        // A huge table count fails rather than being allocated
+        let mut huge_table_count = MAGIC.to_vec();
+        huge_table_count.extend([0x07, 0x00, 0x00, 0x0A]);
+        huge_table_count.extend([0xff, 0xff, 0xff, 0xff, 0x0f]);
+        huge_table_count.extend([0x01, 0x00, 0x00]);
+        assert!(MoveModule::from_bytecode(&huge_table_count).is_err());
+    }
+}
@@ -52,12 +52,39 @@ impl<'de> Deserialize<'de> for ChainId {
     where
         D: Deserializer<'de>,
     {
-        let str = String::deserialize(deserializer)?;
-        Ok(match str.as_str() {
-            MAINNET => ChainId::Mainnet,
-            TESTNET => ChainId::Testnet,
-            TESTING => ChainId::Testing,
-            other => ChainId::Other(u8::from_str(other).map_err(serde::de::Error::custom)?),
-        })
+        if deserializer.is_human_readable() {
+            let str = String::deserialize(deserializer)?;
+            Ok(match str.as_str() {
+                MAINNET => ChainId::Mainnet,
+                TESTNET => ChainId::Testnet,
+                TESTING => ChainId::Testing,
+                other => ChainId::Other(u8::from_str(other).map_err(serde::de::Error::custom)?),
+            })
+        } else {
+            Ok(ChainId::from(u8::deserialize(deserializer)?))
+        }
+    }
+}
+
+impl ChainId {
+    /// The numeric id of the chain, as used on-chain and in transactions
+    pub fn id(&self) -> u8 {
+        match self {
+            ChainId::Mainnet => 1,
+            ChainId::Testnet => 2,
+            ChainId::Testing => 3,
+            ChainId::Other(other) => *other,
+        }
+    }
+}
+
+impl From<u8> for ChainId {
+    fn from(id: u8) -> Self {
+        match id {
+            1 => ChainId::Mainnet,
+            2 => ChainId::Testnet,
+            3 => ChainId::Testing,
+            other => ChainId::Other(other),
+        }
     }
 }
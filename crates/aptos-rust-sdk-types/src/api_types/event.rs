@@ -1,5 +1,10 @@
 use crate::api_types::address::AccountAddress;
+use crate::api_types::move_json::from_move_json;
+use crate::api_types::move_type_tag::{MoveStruct, MoveTypeTag};
+use crate::api_types::numbers::U64;
 use crate::api_types::type_tag::TypeTag;
+use anyhow::anyhow;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -8,12 +13,67 @@ pub struct EventKey {
     account_address: AccountAddress,
 }
 
+impl EventKey {
+    pub fn new(creation_number: u64, account_address: AccountAddress) -> Self {
+        Self {
+            creation_number,
+            account_address,
+        }
+    }
+
+    /// The creation number of the event handle's GUID
+    pub fn creation_number(&self) -> u64 {
+        self.creation_number
+    }
+
+    /// The account or object holding the event handle
+    pub fn account_address(&self) -> AccountAddress {
+        self.account_address
+    }
+}
+
 #[derive(Debug, Hash, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ContractEvent {
     V1(ContractEventV1),
     V2(ContractEventV2),
 }
 
+impl ContractEvent {
+    pub fn type_tag(&self) -> &TypeTag {
+        match self {
+            ContractEvent::V1(event) => event.type_tag(),
+            ContractEvent::V2(event) => event.type_tag(),
+        }
+    }
+
+    pub fn event_data(&self) -> &[u8] {
+        match self {
+            ContractEvent::V1(event) => event.event_data(),
+            ContractEvent::V2(event) => event.event_data(),
+        }
+    }
+
+    pub fn v1(&self) -> Option<&ContractEventV1> {
+        match self {
+            ContractEvent::V1(event) => Some(event),
+            ContractEvent::V2(_) => None,
+        }
+    }
+
+    /// The event if it's a module event
+    pub fn v2(&self) -> Option<&ContractEventV2> {
+        match self {
+            ContractEvent::V1(_) => None,
+            ContractEvent::V2(event) => Some(event),
+        }
+    }
+
+    /// Decode the event's data as `T`, after checking the event has `T`'s type
+    pub fn decode<T: MoveTypeTag + DeserializeOwned>(&self) -> anyhow::Result<T> {
+        decode_event_data(self.type_tag(), self.event_data())
+    }
+}
+
 #[derive(Debug, Hash, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ContractEventV1 {
     /// The unique key that the event was emitted to
@@ -27,6 +87,43 @@ pub struct ContractEventV1 {
     event_data: Vec<u8>,
 }
 
+impl ContractEventV1 {
+    pub fn new(
+        key: EventKey,
+        sequence_number: u64,
+        type_tag: TypeTag,
+        event_data: Vec<u8>,
+    ) -> Self {
+        Self {
+            key,
+            sequence_number,
+            type_tag,
+            event_data,
+        }
+    }
+
+    pub fn key(&self) -> &EventKey {
+        &self.key
+    }
+
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    pub fn type_tag(&self) -> &TypeTag {
+        &self.type_tag
+    }
+
+    pub fn event_data(&self) -> &[u8] {
+        &self.event_data
+    }
+
+    /// Decode the event's data as `T`, after checking the event has `T`'s type
+    pub fn decode<T: MoveTypeTag + DeserializeOwned>(&self) -> anyhow::Result<T> {
+        decode_event_data(&self.type_tag, &self.event_data)
+    }
+}
+
 /// Entry produced via a call to the `emit` builtin.
 #[derive(Debug, Hash, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ContractEventV2 {
@@ -36,3 +133,348 @@ pub struct ContractEventV2 {
     #[serde(with = "serde_bytes")]
     event_data: Vec<u8>,
 }
+
+impl ContractEventV2 {
+    pub fn new(type_tag: TypeTag, event_data: Vec<u8>) -> Self {
+        Self {
+            type_tag,
+            event_data,
+        }
+    }
+
+    pub fn type_tag(&self) -> &TypeTag {
+        &self.type_tag
+    }
+
+    pub fn event_data(&self) -> &[u8] {
+        &self.event_data
+    }
+
+    /// Decode the event's data as `T`, after checking the event has `T`'s type
+    pub fn decode<T: MoveTypeTag + DeserializeOwned>(&self) -> anyhow::Result<T> {
+        decode_event_data(&self.type_tag, &self.event_data)
+    }
+}
+
+fn check_event_type<T: MoveTypeTag>(type_tag: &TypeTag) -> anyhow::Result<()> {
+    let expected = T::type_tag();
+    if *type_tag != expected {
+        return Err(anyhow!(
+            "Expected a {} event, got {}",
+            expected.to_canonical_string(),
+            type_tag.to_canonical_string()
+        ));
+    }
+    Ok(())
+}
+
+fn decode_event_data<T: MoveTypeTag + DeserializeOwned>(
+    type_tag: &TypeTag,
+    event_data: &[u8],
+) -> anyhow::Result<T> {
+    check_event_type::<T>(type_tag)?;
+    Ok(aptos_bcs::from_bytes(event_data)?)
+}
+
+/// The GUID of the handle an event was emitted to, as returned in JSON.  Module events have
+/// none, which the API returns as creation number 0 at address 0x0.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EventGuid {
+    pub creation_number: U64,
+    pub account_address: AccountAddress,
+}
+
+/// An event as returned in JSON, e.g. in a transaction's `events`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    pub guid: EventGuid,
+    pub sequence_number: U64,
+    #[serde(rename = "type", with = "crate::api_types::type_tag::type_tag_string")]
+    pub type_: TypeTag,
+    pub data: serde_json::Value,
+}
+
+impl Event {
+    /// Whether the event is a module event, rather than one emitted to an event handle
+    pub fn is_module_event(&self) -> bool {
+        self.guid.creation_number.inner() == 0 && self.guid.account_address == AccountAddress::ZERO
+    }
+
+    /// Decode the event's data as `T`, after checking the event has `T`'s type, see
+    /// [`from_move_json`]
+    pub fn decode<T: MoveTypeTag + DeserializeOwned>(&self) -> anyhow::Result<T> {
+        check_event_type::<T>(&self.type_)?;
+        Ok(from_move_json(self.data.clone())?)
+    }
+}
+
+/// A JSON event along with the version of the transaction that emitted it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VersionedEvent {
+    pub version: U64,
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+/// An event along with the version of the transaction that emitted it, as returned in BCS by
+/// the event endpoints
+#[derive(Debug, Hash, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EventWithVersion {
+    pub transaction_version: u64,
+    pub event: ContractEvent,
+}
+
+/// Matches module events, i.e. `ContractEvent::V2`, by type.  A struct type without type
+/// arguments matches the struct with any type arguments, and a filter without types matches
+/// every module event.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ModuleEventFilter {
+    types: Vec<TypeTag>,
+}
+
+impl ModuleEventFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also match events of a type
+    pub fn with_type(mut self, type_tag: TypeTag) -> Self {
+        self.types.push(type_tag);
+        self
+    }
+
+    /// Also match events of `T`'s type
+    pub fn with_event<T: MoveTypeTag>(self) -> Self {
+        self.with_type(T::type_tag())
+    }
+
+    pub fn matches(&self, event: &ContractEvent) -> bool {
+        match event {
+            ContractEvent::V1(_) => false,
+            ContractEvent::V2(event) => self.matches_type(event.type_tag()),
+        }
+    }
+
+    /// Like `matches`, for an event returned in JSON
+    pub fn matches_json(&self, event: &Event) -> bool {
+        event.is_module_event() && self.matches_type(&event.type_)
+    }
+
+    fn matches_type(&self, type_tag: &TypeTag) -> bool {
+        self.types.is_empty()
+            || self.types.iter().any(|filter| match (filter, type_tag) {
+                (TypeTag::Struct(filter), TypeTag::Struct(struct_tag))
+                    if filter.type_args.is_empty() =>
+                {
+                    filter.address == struct_tag.address
+                        && filter.module == struct_tag.module
+                        && filter.name == struct_tag.name
+                }
+                _ => filter == type_tag,
+            })
+    }
+
+    /// The matching module events among a transaction's events
+    pub fn filter<'a>(
+        &'a self,
+        events: &'a [ContractEvent],
+    ) -> impl Iterator<Item = &'a ContractEventV2> + 'a {
+        events
+            .iter()
+            .filter_map(ContractEvent::v2)
+            .filter(|event| self.matches_type(event.type_tag()))
+    }
+}
+
+/// The `0x1::guid::ID` of an event handle or object
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(address = "0x1", module = "guid", name = "ID")]
+pub struct Id {
+    /// The number of GUIDs the address had created before this one
+    pub creation_num: u64,
+    /// The address that created the GUID
+    pub addr: AccountAddress,
+}
+
+/// The `0x1::guid::GUID` of an event handle
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(address = "0x1", module = "guid", name = "GUID")]
+pub struct Guid {
+    pub id: Id,
+}
+
+/// A `0x1::event::EventHandle`, which V1 events are emitted to.  It has the Move struct's layout,
+/// but not its phantom event type parameter, so it has no struct tag.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EventHandle {
+    /// The number of events emitted to the handle
+    pub counter: u64,
+    pub guid: Guid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_types::type_tag::StructTag;
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0x1", module = "fungible_asset")]
+    struct Deposit {
+        store: AccountAddress,
+        amount: u64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0x1", module = "coin", name = "CoinDeposit")]
+    struct CoinDeposit<CoinType> {
+        account: AccountAddress,
+        amount: u64,
+        #[serde(skip)]
+        phantom: std::marker::PhantomData<CoinType>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0x1", module = "aptos_coin")]
+    struct AptosCoin {
+        dummy_field: bool,
+    }
+
+    fn deposit_event() -> ContractEvent {
+        let deposit = Deposit {
+            store: AccountAddress::TWO,
+            amount: 50,
+        };
+        ContractEvent::V2(ContractEventV2::new(
+            Deposit::type_tag(),
+            aptos_bcs::to_bytes(&deposit).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn test_decode_event() {
+        let event = deposit_event();
+        assert_eq!(
+            event.decode::<Deposit>().unwrap(),
+            Deposit {
+                store: AccountAddress::TWO,
+                amount: 50
+            }
+        );
+        assert!(event.v1().is_none());
+        assert_eq!(event.v2().unwrap().event_data(), event.event_data());
+
+        let key = EventKey::new(3, AccountAddress::ONE);
+        let event = ContractEventV1::new(
+            key,
+            7,
+            TypeTag::from_str("0x1::coin::CoinDeposit<0x1::aptos_coin::AptosCoin>").unwrap(),
+            aptos_bcs::to_bytes(&(AccountAddress::ONE, 10u64)).unwrap(),
+        );
+        assert_eq!(event.key().creation_number(), 3);
+        assert_eq!(event.key().account_address(), AccountAddress::ONE);
+        assert_eq!(event.sequence_number(), 7);
+        assert_eq!(event.decode::<CoinDeposit<AptosCoin>>().unwrap().amount, 10);
+        // The wrong type is rejected, even if the data would decode
+        assert!(event.decode::<Deposit>().is_err());
+
+        let versioned = EventWithVersion {
+            transaction_version: 100,
+            event: ContractEvent::V1(event),
+        };
+        let bytes = aptos_bcs::to_bytes(&versioned).unwrap();
+        assert_eq!(
+            aptos_bcs::from_bytes::<EventWithVersion>(&bytes).unwrap(),
+            versioned
+        );
+    }
+
+    #[test]
+    fn test_module_event_filter() {
+        let coin_deposit = |coin: &str| {
+            ContractEvent::V2(ContractEventV2::new(
+                TypeTag::from_str(&format!("0x1::coin::CoinDeposit<{}>", coin)).unwrap(),
+                vec![],
+            ))
+        };
+        let v1_deposit = ContractEvent::V1(ContractEventV1::new(
+            EventKey::new(0, AccountAddress::ONE),
+            0,
+            Deposit::type_tag(),
+            vec![],
+        ));
+        let events = vec![
+            deposit_event(),
+            coin_deposit("0x1::aptos_coin::AptosCoin"),
+            coin_deposit("0xcafe::token::Token"),
+            v1_deposit.clone(),
+        ];
+
+        let filter = ModuleEventFilter::new().with_event::<Deposit>();
+        assert!(filter.matches(&events[0]));
+        assert!(!filter.matches(&v1_deposit));
+        assert_eq!(filter.filter(&events).count(), 1);
+
+        let filter = ModuleEventFilter::new().with_event::<CoinDeposit<AptosCoin>>();
+        assert_eq!(
+            filter.filter(&events).collect::<Vec<_>>(),
+            vec![events[1].v2().unwrap()]
+        );
+
+        // Without type arguments, any instantiation matches
+        let filter = ModuleEventFilter::new().with_type(TypeTag::Struct(Box::new(
+            StructTag::from_str("0x1::coin::CoinDeposit").unwrap(),
+        )));
+        assert_eq!(filter.filter(&events).count(), 2);
+
+        assert_eq!(ModuleEventFilter::new().filter(&events).count(), 3);
+    }
+
+    #[test]
+    fn test_json_event() {
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            {
+                "guid": {"creation_number": "0", "account_address": "0x0"},
+                "sequence_number": "0",
+                "type": "0x1::fungible_asset::Deposit",
+                "data": {"store": "0x2", "amount": "50"}
+            },
+            {
+                "guid": {"creation_number": "2", "account_address": "0xb"},
+                "sequence_number": "4",
+                "type": "0x1::fungible_asset::Deposit",
+                "data": {"store": "0x2", "amount": "50"}
+            }
+        ]))
+        .unwrap();
+        assert!(events[0].is_module_event());
+        assert!(!events[1].is_module_event());
+        assert_eq!(
+            events[0].decode::<Deposit>().unwrap(),
+            Deposit {
+                store: AccountAddress::TWO,
+                amount: 50
+            }
+        );
+        assert!(events[0].decode::<AptosCoin>().is_err());
+
+        let filter = ModuleEventFilter::new().with_event::<Deposit>();
+        assert!(filter.matches_json(&events[0]));
+        assert!(!filter.matches_json(&events[1]));
+        assert!(!ModuleEventFilter::new()
+            .with_event::<AptosCoin>()
+            .matches_json(&events[0]));
+
+        let versioned: VersionedEvent = serde_json::from_value(serde_json::json!({
+            "version": "100",
+            "guid": {"creation_number": "2", "account_address": "0xb"},
+            "sequence_number": "4",
+            "type": "u64",
+            "data": "7"
+        }))
+        .unwrap();
+        assert_eq!(versioned.version.inner(), 100);
+        assert_eq!(versioned.event.type_, TypeTag::U64);
+        assert_eq!(versioned.event.decode::<u64>().unwrap(), 7);
+    }
+}
@@ -1,14 +1,23 @@
 pub mod account;
 pub mod address;
+pub mod amount;
+pub mod asset;
+pub mod bytecode;
 pub mod chain_id;
 pub mod event;
 pub mod hash;
 pub mod identifier;
 pub mod module_id;
+pub mod move_json;
+pub mod move_module;
+pub mod move_type_tag;
+pub mod move_value;
 pub mod numbers;
+pub mod object;
 pub mod parser;
 pub mod safe_serialize;
 pub mod state_key;
+pub mod table;
 pub mod transaction;
 pub mod transaction_authenticator;
 pub mod type_tag;
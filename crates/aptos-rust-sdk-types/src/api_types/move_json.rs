@@ -0,0 +1,305 @@
+use serde::de::{self, DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+use serde_json::Value;
+
+/// Deserialize a Move value as the API returns it in JSON, e.g. a resource's `data`, into a Rust
+/// type with the layout of the Move type, e.g. one deriving `MoveStruct`.
+///
+/// Unlike `serde_json::from_value`, this accepts:
+/// * `u64`, `u128` and `u256` encoded as strings, as the API does
+/// * `vector<u8>` encoded as hex, for `Vec<u8>` fields
+/// * `0x1::option::Option` encoded as `{"vec": [...]}`, for `Option` fields
+/// * `0x1::object::Object` encoded as `{"inner": "0x..."}`, for `AccountAddress` fields
+///
+/// The API leaves out phantom type parameters, so a `PhantomData` field needs `#[serde(default)]`.
+pub fn from_move_json<T: DeserializeOwned>(value: Value) -> Result<T, serde_json::Error> {
+    T::deserialize(MoveJson(value))
+}
+
+struct MoveJson(Value);
+
+macro_rules! deserialize_integer {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0 {
+                    Value::String(string) => {
+                        visitor.$visit(string.parse().map_err(de::Error::custom)?)
+                    }
+                    value => value.$method(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for MoveJson {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Array(values) => visitor.visit_seq(MoveJsonSeq(values.into_iter())),
+            Value::Object(map) => visitor.visit_map(MoveJsonMap {
+                iter: map.into_iter(),
+                value: None,
+            }),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    deserialize_integer!(
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+    );
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            Value::Object(mut map)
+                if map.len() == 1
+                    && map
+                        .get("vec")
+                        .and_then(Value::as_array)
+                        .is_some_and(|vec| vec.len() <= 1) =>
+            {
+                match map
+                    .remove("vec")
+                    .and_then(|mut vec| vec.as_array_mut()?.pop())
+                {
+                    Some(value) => visitor.visit_some(MoveJson(value)),
+                    None => visitor.visit_none(),
+                }
+            }
+            value => visitor.visit_some(MoveJson(value)),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Object(mut map) if map.len() == 1 && map.contains_key("inner") => {
+                map.remove("inner").unwrap().deserialize_string(visitor)
+            }
+            value => value.deserialize_string(visitor),
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::String(string) => visitor.visit_byte_buf(decode_hex(&string)?),
+            value => value.deserialize_byte_buf(visitor),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::String(string) => {
+                let bytes = decode_hex(&string)?;
+                visitor.visit_seq(MoveJsonSeq(
+                    bytes
+                        .into_iter()
+                        .map(Value::from)
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                ))
+            }
+            value => MoveJson(value).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool f32 f64 char unit map struct identifier ignored_any
+    }
+}
+
+fn decode_hex(string: &str) -> Result<Vec<u8>, serde_json::Error> {
+    hex::decode(string.strip_prefix("0x").unwrap_or(string)).map_err(de::Error::custom)
+}
+
+struct MoveJsonSeq(std::vec::IntoIter<Value>);
+
+impl<'de> SeqAccess<'de> for MoveJsonSeq {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.0
+            .next()
+            .map(|value| seed.deserialize(MoveJson(value)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct MoveJsonMap {
+    iter: serde_json::map::IntoIter,
+    value: Option<Value>,
+}
+
+impl<'de> MapAccess<'de> for MoveJsonMap {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(Value::String(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("Value is missing"))?;
+        seed.deserialize(MoveJson(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_types::address::AccountAddress;
+    use crate::api_types::u256::U256;
+    use serde::Deserialize;
+    use serde_json::json;
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Coin<T> {
+        value: u64,
+        #[serde(default)]
+        phantom: PhantomData<T>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Vault {
+        coin: Coin<u8>,
+        total: u128,
+        shares: U256,
+        owner: AccountAddress,
+        metadata: AccountAddress,
+        seed: Vec<u8>,
+        weights: Vec<u16>,
+        label: Option<String>,
+        delegate: Option<AccountAddress>,
+        #[serde(rename = "for")]
+        for_: Option<u64>,
+    }
+
+    #[test]
+    fn test_from_move_json() {
+        let vault: Vault = from_move_json(json!({
+            "coin": {"value": "18446744073709551615"},
+            "total": "340282366920938463463374607431768211455",
+            "shares": "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            "owner": "0x1",
+            "metadata": {"inner": "0xa"},
+            "seed": "0x0102ff",
+            "weights": [1, 2],
+            "label": {"vec": ["savings"]},
+            "delegate": {"vec": []},
+            "for": null,
+        }))
+        .unwrap();
+        assert_eq!(
+            vault,
+            Vault {
+                coin: Coin {
+                    value: u64::MAX,
+                    phantom: PhantomData,
+                },
+                total: u128::MAX,
+                shares: U256::max_value(),
+                owner: AccountAddress::ONE,
+                metadata: AccountAddress::from_str("0xa").unwrap(),
+                seed: vec![1, 2, 255],
+                weights: vec![1, 2],
+                label: Some("savings".to_string()),
+                delegate: None,
+                for_: None,
+            }
+        );
+
+        // Plain JSON numbers are still accepted
+        let coin: Coin<u8> = from_move_json(json!({"value": 5})).unwrap();
+        assert_eq!(coin.value, 5);
+        assert!(from_move_json::<Coin<u8>>(json!({"value": "five"})).is_err());
+        assert!(from_move_json::<Coin<u8>>(json!({"value": "-1"})).is_err());
+    }
+}
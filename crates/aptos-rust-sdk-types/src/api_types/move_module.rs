@@ -0,0 +1,760 @@
+use crate::api_types::address::AccountAddress;
+use crate::api_types::module_id::ModuleId;
+use crate::api_types::parser;
+use crate::api_types::type_tag::{StructTag, TypeTag};
+use anyhow::{anyhow, bail, ensure};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The ABI of a Move module, as returned in the `abi` of `/accounts/{address}/module/{name}`, or
+/// read from its bytecode with [`MoveModule::from_bytecode`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MoveModule {
+    pub address: AccountAddress,
+    pub name: String,
+    #[serde(default)]
+    pub friends: Vec<String>,
+    pub exposed_functions: Vec<MoveFunction>,
+    #[serde(default)]
+    pub structs: Vec<MoveStruct>,
+}
+
+impl MoveModule {
+    /// Read the ABI from compiled module bytecode.  Functions can't be known to be view
+    /// functions from bytecode alone, so `is_view` is always false.
+    pub fn from_bytecode(bytes: &[u8]) -> anyhow::Result<Self> {
+        crate::api_types::bytecode::deserialize_module(bytes)
+    }
+
+    pub fn module_id(&self) -> ModuleId {
+        ModuleId::new(self.address, self.name.clone())
+    }
+
+    /// An exposed function, by name
+    pub fn function(&self, name: &str) -> Option<&MoveFunction> {
+        self.exposed_functions
+            .iter()
+            .find(|function| function.name == name)
+    }
+
+    /// A struct defined in the module, by name
+    pub fn move_struct(&self, name: &str) -> Option<&MoveStruct> {
+        self.structs
+            .iter()
+            .find(|move_struct| move_struct.name == name)
+    }
+}
+
+/// A public, friend or entry function of a module
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MoveFunction {
+    pub name: String,
+    pub visibility: MoveFunctionVisibility,
+    pub is_entry: bool,
+    #[serde(default)]
+    pub is_view: bool,
+    pub generic_type_params: Vec<MoveFunctionGenericTypeParam>,
+    /// The parameter types, e.g. `&signer` or `0x1::object::Object<T0>`
+    pub params: Vec<MoveType>,
+    #[serde(rename = "return")]
+    pub return_: Vec<MoveType>,
+}
+
+impl MoveFunction {
+    /// The parameter types that are passed as arguments, i.e. without the leading signers
+    pub fn non_signer_params(&self) -> impl Iterator<Item = &MoveType> {
+        self.params.iter().filter(|param| !param.is_signer())
+    }
+
+    /// The types of the arguments, given the type arguments of a call, after checking the type
+    /// arguments satisfy the function's generic type params
+    pub fn argument_types(&self, ty_args: &[TypeTag]) -> anyhow::Result<Vec<TypeTag>> {
+        ensure!(
+            ty_args.len() == self.generic_type_params.len(),
+            "{} expects {} type arguments, got {}",
+            self.name,
+            self.generic_type_params.len(),
+            ty_args.len()
+        );
+        for (i, (ty_arg, param)) in ty_args.iter().zip(&self.generic_type_params).enumerate() {
+            param.check(ty_arg).map_err(|err| {
+                anyhow!(
+                    "Type argument {} ({}): {}",
+                    i,
+                    ty_arg.to_canonical_string(),
+                    err
+                )
+            })?;
+        }
+        self.non_signer_params()
+            .map(|param| param.instantiate(ty_args))
+            .collect()
+    }
+
+    /// The types of the return values, given the type arguments of a call
+    pub fn return_types(&self, ty_args: &[TypeTag]) -> anyhow::Result<Vec<TypeTag>> {
+        self.return_
+            .iter()
+            .map(|return_type| return_type.instantiate(ty_args))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MoveFunctionVisibility {
+    Private,
+    Public,
+    Friend,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MoveFunctionGenericTypeParam {
+    pub constraints: Vec<MoveAbility>,
+}
+
+impl MoveFunctionGenericTypeParam {
+    /// Check a type argument has the abilities this param requires.  The abilities of structs
+    /// aren't in the ABI of the function's module, so only the type arguments of structs are
+    /// checked, see [`MoveModuleSet::abilities`] to check them against their modules.
+    pub fn check(&self, ty_arg: &TypeTag) -> anyhow::Result<()> {
+        let (abilities, inner): (&[MoveAbility], &[TypeTag]) = match ty_arg {
+            TypeTag::Signer => (&[MoveAbility::Drop], &[]),
+            TypeTag::Vector(inner) => (PRIMITIVE_ABILITIES, std::slice::from_ref(inner)),
+            TypeTag::Struct(struct_tag) => (ALL_ABILITIES, &struct_tag.type_args),
+            _ => (PRIMITIVE_ABILITIES, &[]),
+        };
+        check_constraints(&self.constraints, abilities)?;
+        // Abilities of generic types are conditional on their type arguments having them, and
+        // `key` on them having `store`
+        let inner_param = Self {
+            constraints: self
+                .constraints
+                .iter()
+                .map(|ability| match ability {
+                    MoveAbility::Key => MoveAbility::Store,
+                    other => *other,
+                })
+                .collect(),
+        };
+        inner
+            .iter()
+            .try_for_each(|ty_arg| inner_param.check(ty_arg))
+    }
+}
+
+fn check_constraints(constraints: &[MoveAbility], abilities: &[MoveAbility]) -> anyhow::Result<()> {
+    for constraint in constraints {
+        if !abilities.contains(constraint) {
+            bail!("Missing the {} ability", constraint);
+        }
+    }
+    Ok(())
+}
+
+const PRIMITIVE_ABILITIES: &[MoveAbility] =
+    &[MoveAbility::Copy, MoveAbility::Drop, MoveAbility::Store];
+const ALL_ABILITIES: &[MoveAbility] = &[
+    MoveAbility::Copy,
+    MoveAbility::Drop,
+    MoveAbility::Store,
+    MoveAbility::Key,
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MoveAbility {
+    Copy,
+    Drop,
+    Store,
+    Key,
+}
+
+impl Display for MoveAbility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ability = match self {
+            MoveAbility::Copy => "copy",
+            MoveAbility::Drop => "drop",
+            MoveAbility::Store => "store",
+            MoveAbility::Key => "key",
+        };
+        write!(f, "{}", ability)
+    }
+}
+
+/// A struct defined in a module
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MoveStruct {
+    pub name: String,
+    pub is_native: bool,
+    pub abilities: Vec<MoveAbility>,
+    pub generic_type_params: Vec<MoveStructGenericTypeParam>,
+    /// Empty for native structs and enums
+    pub fields: Vec<MoveStructField>,
+}
+
+impl MoveStruct {
+    /// The types of the fields, in declaration order, given the struct's type arguments
+    pub fn field_types(&self, ty_args: &[TypeTag]) -> anyhow::Result<Vec<(String, TypeTag)>> {
+        self.fields
+            .iter()
+            .map(|field| Ok((field.name.clone(), field.type_.instantiate(ty_args)?)))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MoveStructGenericTypeParam {
+    pub constraints: Vec<MoveAbility>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MoveStructField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: MoveType,
+}
+
+/// A type in a module ABI, which unlike a [`TypeTag`] can be a reference or refer to the generic
+/// type params of its function or struct.  It's written the same way as in the node's ABI, e.g.
+/// `&mut vector<T0>`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MoveType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<MoveType>),
+    Struct(MoveStructTag),
+    /// The index of a generic type param, `T0`, `T1`, ...
+    GenericTypeParam(u16),
+    Reference {
+        mutable: bool,
+        to: Box<MoveType>,
+    },
+    /// A type the SDK can't represent, e.g. a function type
+    Unparsable(String),
+}
+
+/// A struct type in a module ABI, whose type arguments may be generic type params
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoveStructTag {
+    pub address: AccountAddress,
+    pub module: String,
+    pub name: String,
+    pub generic_type_params: Vec<MoveType>,
+}
+
+impl MoveType {
+    /// Whether the type is `signer` or `&signer`
+    pub fn is_signer(&self) -> bool {
+        match self {
+            MoveType::Signer => true,
+            MoveType::Reference { to, .. } => **to == MoveType::Signer,
+            _ => false,
+        }
+    }
+
+    /// The type with its generic type params replaced by type arguments
+    pub fn instantiate(&self, ty_args: &[TypeTag]) -> anyhow::Result<TypeTag> {
+        Ok(match self {
+            MoveType::Bool => TypeTag::Bool,
+            MoveType::U8 => TypeTag::U8,
+            MoveType::U16 => TypeTag::U16,
+            MoveType::U32 => TypeTag::U32,
+            MoveType::U64 => TypeTag::U64,
+            MoveType::U128 => TypeTag::U128,
+            MoveType::U256 => TypeTag::U256,
+            MoveType::Address => TypeTag::Address,
+            MoveType::Signer => TypeTag::Signer,
+            MoveType::Vector(inner) => TypeTag::Vector(Box::new(inner.instantiate(ty_args)?)),
+            MoveType::Struct(struct_tag) => TypeTag::Struct(Box::new(StructTag {
+                address: struct_tag.address,
+                module: struct_tag.module.clone(),
+                name: struct_tag.name.clone(),
+                type_args: struct_tag
+                    .generic_type_params
+                    .iter()
+                    .map(|param| param.instantiate(ty_args))
+                    .collect::<anyhow::Result<_>>()?,
+            })),
+            MoveType::GenericTypeParam(index) => ty_args
+                .get(*index as usize)
+                .cloned()
+                .ok_or_else(|| anyhow!("Missing type argument T{}", index))?,
+            MoveType::Reference { .. } | MoveType::Unparsable(_) => {
+                bail!("{} has no type tag", self)
+            }
+        })
+    }
+}
+
+impl From<&TypeTag> for MoveType {
+    fn from(type_tag: &TypeTag) -> Self {
+        match type_tag {
+            TypeTag::Bool => MoveType::Bool,
+            TypeTag::U8 => MoveType::U8,
+            TypeTag::U16 => MoveType::U16,
+            TypeTag::U32 => MoveType::U32,
+            TypeTag::U64 => MoveType::U64,
+            TypeTag::U128 => MoveType::U128,
+            TypeTag::U256 => MoveType::U256,
+            TypeTag::Address => MoveType::Address,
+            TypeTag::Signer => MoveType::Signer,
+            TypeTag::Vector(inner) => MoveType::Vector(Box::new(inner.as_ref().into())),
+            TypeTag::Struct(struct_tag) => MoveType::Struct(MoveStructTag {
+                address: struct_tag.address,
+                module: struct_tag.module.clone(),
+                name: struct_tag.name.clone(),
+                generic_type_params: struct_tag.type_args.iter().map(Into::into).collect(),
+            }),
+        }
+    }
+}
+
+impl Display for MoveType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveType::Bool => write!(f, "bool"),
+            MoveType::U8 => write!(f, "u8"),
+            MoveType::U16 => write!(f, "u16"),
+            MoveType::U32 => write!(f, "u32"),
+            MoveType::U64 => write!(f, "u64"),
+            MoveType::U128 => write!(f, "u128"),
+            MoveType::U256 => write!(f, "u256"),
+            MoveType::Address => write!(f, "address"),
+            MoveType::Signer => write!(f, "signer"),
+            MoveType::Vector(inner) => write!(f, "vector<{}>", inner),
+            MoveType::Struct(struct_tag) => {
+                write!(
+                    f,
+                    "0x{}::{}::{}",
+                    struct_tag.address.short_str_lossless(),
+                    struct_tag.module,
+                    struct_tag.name
+                )?;
+                if !struct_tag.generic_type_params.is_empty() {
+                    let params: Vec<_> = struct_tag
+                        .generic_type_params
+                        .iter()
+                        .map(MoveType::to_string)
+                        .collect();
+                    write!(f, "<{}>", params.join(", "))?;
+                }
+                Ok(())
+            }
+            MoveType::GenericTypeParam(index) => write!(f, "T{}", index),
+            MoveType::Reference { mutable: true, to } => write!(f, "&mut {}", to),
+            MoveType::Reference { mutable: false, to } => write!(f, "&{}", to),
+            MoveType::Unparsable(type_) => write!(f, "{}", type_),
+        }
+    }
+}
+
+impl FromStr for MoveType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse_move_type(s)
+    }
+}
+
+impl Serialize for MoveType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Types the SDK can't parse are kept as [`MoveType::Unparsable`], so that newer ABIs still load
+impl<'de> Deserialize<'de> for MoveType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let type_ = String::deserialize(deserializer)?;
+        Ok(MoveType::from_str(&type_).unwrap_or(MoveType::Unparsable(type_)))
+    }
+}
+
+/// A set of module ABIs, to validate and resolve module ids, struct tags and type tags offline
+#[derive(Clone, Debug, Default)]
+pub struct MoveModuleSet {
+    modules: BTreeMap<ModuleId, MoveModule>,
+}
+
+impl MoveModuleSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a module, replacing any module with the same id
+    pub fn insert(&mut self, module: MoveModule) {
+        self.modules.insert(module.module_id(), module);
+    }
+
+    pub fn get(&self, module_id: &ModuleId) -> Option<&MoveModule> {
+        self.modules.get(module_id)
+    }
+
+    /// The module with the given id, failing if it isn't in the set
+    pub fn resolve_module(&self, module_id: &ModuleId) -> anyhow::Result<&MoveModule> {
+        self.get(module_id).ok_or_else(|| {
+            anyhow!(
+                "Unknown module 0x{}::{}",
+                module_id.address().short_str_lossless(),
+                module_id.name()
+            )
+        })
+    }
+
+    /// The struct a struct tag refers to, after checking its type arguments exist and satisfy
+    /// the struct's generic type params
+    pub fn resolve_struct(&self, struct_tag: &StructTag) -> anyhow::Result<&MoveStruct> {
+        let module = self.resolve_module(&struct_tag.module_id())?;
+        let move_struct = module
+            .move_struct(&struct_tag.name)
+            .ok_or_else(|| anyhow!("Unknown struct {}", struct_tag.to_canonical_string()))?;
+        ensure!(
+            struct_tag.type_args.len() == move_struct.generic_type_params.len(),
+            "{} expects {} type arguments, got {}",
+            move_struct.name,
+            move_struct.generic_type_params.len(),
+            struct_tag.type_args.len()
+        );
+        for (ty_arg, param) in struct_tag
+            .type_args
+            .iter()
+            .zip(&move_struct.generic_type_params)
+        {
+            check_constraints(&param.constraints, &self.abilities(ty_arg)?).map_err(|err| {
+                anyhow!(
+                    "Type argument {} of {}: {}",
+                    ty_arg.to_canonical_string(),
+                    struct_tag.to_canonical_string(),
+                    err
+                )
+            })?;
+        }
+        Ok(move_struct)
+    }
+
+    /// Check every struct in a type tag exists, with valid type arguments
+    pub fn check_type_tag(&self, type_tag: &TypeTag) -> anyhow::Result<()> {
+        self.abilities(type_tag).map(|_| ())
+    }
+
+    /// The abilities of a type.  A generic struct has its declared abilities, as the JSON ABI
+    /// doesn't say which type params are phantom, which is needed to narrow them.
+    pub fn abilities(&self, type_tag: &TypeTag) -> anyhow::Result<Vec<MoveAbility>> {
+        Ok(match type_tag {
+            TypeTag::Signer => vec![MoveAbility::Drop],
+            TypeTag::Vector(inner) => self
+                .abilities(inner)?
+                .into_iter()
+                .filter(|ability| *ability != MoveAbility::Key)
+                .collect(),
+            TypeTag::Struct(struct_tag) => self.resolve_struct(struct_tag)?.abilities.clone(),
+            _ => PRIMITIVE_ABILITIES.to_vec(),
+        })
+    }
+
+    /// The field names and types of a struct, e.g. to decode it
+    pub fn field_types(&self, struct_tag: &StructTag) -> anyhow::Result<Vec<(String, TypeTag)>> {
+        self.resolve_struct(struct_tag)?
+            .field_types(&struct_tag.type_args)
+    }
+}
+
+impl FromIterator<MoveModule> for MoveModuleSet {
+    fn from_iter<I: IntoIterator<Item = MoveModule>>(modules: I) -> Self {
+        let mut set = Self::new();
+        modules.into_iter().for_each(|module| set.insert(module));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_types::move_value::MoveValue;
+    use crate::api_types::transaction::EntryFunction;
+    use serde_json::json;
+
+    /// A trimmed down ABI, in the node's JSON format
+    fn abi() -> MoveModule {
+        serde_json::from_value(json!({
+            "address": "0x1",
+            "name": "example",
+            "friends": [],
+            "exposed_functions": [
+                {
+                    "name": "call",
+                    "visibility": "public",
+                    "is_entry": true,
+                    "is_view": false,
+                    "generic_type_params": [{"constraints": ["store"]}],
+                    "params": [
+                        "&signer",
+                        "vector<T0>",
+                        "0x1::option::Option<u64>",
+                        "0x1::string::String",
+                        "0x1::object::Object<0x1::fungible_asset::Metadata>",
+                        "vector<u8>"
+                    ],
+                    "return": []
+                },
+                {
+                    "name": "view",
+                    "visibility": "public",
+                    "is_entry": false,
+                    "is_view": true,
+                    "generic_type_params": [],
+                    "params": ["address"],
+                    "return": ["u64"]
+                }
+            ],
+            "structs": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_abi() {
+        let abi = abi();
+        assert_eq!(abi.address, AccountAddress::ONE);
+        let call = abi.function("call").unwrap();
+        assert_eq!(call.visibility, MoveFunctionVisibility::Public);
+        assert_eq!(
+            call.generic_type_params[0].constraints,
+            vec![MoveAbility::Store]
+        );
+        assert_eq!(call.non_signer_params().count(), 5);
+        assert_eq!(
+            call.argument_types(&[TypeTag::U8]).unwrap()[0],
+            TypeTag::Vector(Box::new(TypeTag::U8))
+        );
+        assert_eq!(abi.function("view").unwrap().return_, vec![MoveType::U64]);
+        assert!(abi.function("missing").is_none());
+    }
+
+    /// Structs from the node's JSON ABI of `0x1::coin` and `0x1::aptos_coin`, trimmed down
+    fn coin_modules() -> MoveModuleSet {
+        let coin = json!({
+            "address": "0x1",
+            "name": "coin",
+            "exposed_functions": [],
+            "structs": [
+                {
+                    "name": "Coin",
+                    "is_native": false,
+                    "abilities": ["store"],
+                    "generic_type_params": [{"constraints": []}],
+                    "fields": [{"name": "value", "type": "u64"}]
+                },
+                {
+                    "name": "CoinStore",
+                    "is_native": false,
+                    "abilities": ["key"],
+                    "generic_type_params": [{"constraints": []}],
+                    "fields": [
+                        {"name": "coin", "type": "0x1::coin::Coin<T0>"},
+                        {"name": "frozen", "type": "bool"},
+                        {"name": "hook", "type": "|u64|bool has drop"}
+                    ]
+                },
+                {
+                    "name": "Stored",
+                    "is_native": false,
+                    "abilities": ["key"],
+                    "generic_type_params": [{"constraints": ["store"]}],
+                    "fields": [{"name": "value", "type": "T0"}]
+                }
+            ]
+        });
+        let aptos_coin = json!({
+            "address": "0x1",
+            "name": "aptos_coin",
+            "exposed_functions": [],
+            "structs": [{
+                "name": "AptosCoin",
+                "is_native": false,
+                "abilities": ["key"],
+                "generic_type_params": [],
+                "fields": [{"name": "dummy_field", "type": "bool"}]
+            }]
+        });
+        [coin, aptos_coin]
+            .into_iter()
+            .map(|module| serde_json::from_value(module).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_move_type_serde() {
+        let types = [
+            "&mut vector<T0>",
+            "0x1::table::Table<u64, 0x1::string::String>",
+            "signer",
+        ];
+        for type_ in types {
+            let move_type: MoveType = serde_json::from_value(json!(type_)).unwrap();
+            assert_eq!(serde_json::to_value(&move_type).unwrap(), json!(type_));
+        }
+        let move_type: MoveType = serde_json::from_value(json!("|u64|bool")).unwrap();
+        assert_eq!(move_type, MoveType::Unparsable("|u64|bool".to_string()));
+        assert!(move_type.instantiate(&[]).is_err());
+        assert!(MoveType::GenericTypeParam(1)
+            .instantiate(&[TypeTag::U8])
+            .is_err());
+    }
+
+    #[test]
+    fn test_module_set() {
+        let modules = coin_modules();
+        let coin_store =
+            StructTag::from_str("0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>").unwrap();
+        let move_struct = modules.resolve_struct(&coin_store).unwrap();
+        assert_eq!(move_struct.abilities, vec![MoveAbility::Key]);
+
+        let field_types = modules.field_types(&coin_store);
+        // The function typed field can't be instantiated
+        assert!(field_types.is_err());
+        let coin = StructTag::from_str("0x1::coin::Coin<0x1::aptos_coin::AptosCoin>").unwrap();
+        assert_eq!(
+            modules.field_types(&coin).unwrap(),
+            vec![("value".to_string(), TypeTag::U64)]
+        );
+
+        let check = |type_: &str| modules.check_type_tag(&TypeTag::from_str(type_).unwrap());
+        assert!(check("vector<0x1::coin::Coin<u8>>").is_ok());
+        assert!(check("0x1::coin::Coin")
+            .unwrap_err()
+            .to_string()
+            .contains("expects 1"));
+        assert!(check("0x1::coin::Missing")
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown struct"));
+        assert!(check("0x2::coin::Coin<u8>")
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown module"));
+        // `Stored` needs its type argument to have `store`
+        assert!(check("0x1::coin::Stored<0x1::coin::Coin<u8>>").is_ok());
+        assert!(check("0x1::coin::Stored<0x1::aptos_coin::AptosCoin>")
+            .unwrap_err()
+            .to_string()
+            .contains("store"));
+        assert!(check("0x1::coin::Stored<signer>").is_err());
+
+        assert_eq!(
+            modules
+                .abilities(&TypeTag::from_str("vector<0x1::aptos_coin::AptosCoin>").unwrap())
+                .unwrap(),
+            vec![]
+        );
+        assert!(modules
+            .resolve_module(&ModuleId::new(AccountAddress::ONE, "coin".to_string()))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_generic_bounds() {
+        let store = MoveFunctionGenericTypeParam {
+            constraints: vec![MoveAbility::Store],
+        };
+        let key = MoveFunctionGenericTypeParam {
+            constraints: vec![MoveAbility::Key],
+        };
+        assert!(store.check(&TypeTag::U64).is_ok());
+        assert!(store.check(&TypeTag::Signer).is_err());
+        assert!(store
+            .check(&TypeTag::Vector(Box::new(TypeTag::Signer)))
+            .is_err());
+        assert!(key.check(&TypeTag::U64).is_err());
+        assert!(key
+            .check(&TypeTag::from_str("0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>").unwrap())
+            .is_ok());
+        assert!(key
+            .check(&TypeTag::from_str("0x1::example::Box<signer>").unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_json_args() {
+        let entry_function = EntryFunction::from_json_args(
+            &abi(),
+            "call",
+            vec![TypeTag::U64],
+            &[
+                json!(["1", 2]),
+                json!(null),
+                json!("hello"),
+                json!({"inner": "0xa"}),
+                json!("0x0102"),
+            ],
+        )
+        .unwrap();
+        let expected: Vec<MoveValue> = vec![
+            vec![1u64, 2].into(),
+            None::<u64>.into(),
+            "hello".into(),
+            AccountAddress::from_str("0xa").unwrap().into(),
+            vec![1u8, 2].into(),
+        ];
+        assert_eq!(
+            entry_function.args(),
+            expected.iter().map(MoveValue::to_bcs).collect::<Vec<_>>()
+        );
+        assert_eq!(entry_function.module().name(), "example");
+
+        // Options can also be given in the node's `{"vec": [...]}` format, or as the value
+        let some = |option: serde_json::Value| {
+            EntryFunction::from_json_args(
+                &abi(),
+                "call",
+                vec![TypeTag::U64],
+                &[json!([]), option, json!(""), json!("0xa"), json!([])],
+            )
+            .unwrap()
+            .args()[1]
+                .clone()
+        };
+        assert_eq!(some(json!({"vec": ["5"]})), some(json!(5)));
+        assert_eq!(some(json!({"vec": []})), some(json!(null)));
+    }
+
+    #[test]
+    fn test_from_json_args_errors() {
+        let error = |function: &str, ty_args: Vec<TypeTag>, args: &[serde_json::Value]| {
+            EntryFunction::from_json_args(&abi(), function, ty_args, args)
+                .unwrap_err()
+                .to_string()
+        };
+        let valid = [json!([]), json!(null), json!(""), json!("0xa"), json!("0x")];
+
+        assert!(error("missing", vec![TypeTag::U64], &valid).contains("has no function"));
+        assert!(error("view", vec![], &[json!("0x1")]).contains("not an entry function"));
+        assert!(error("call", vec![], &valid).contains("expects 1 type arguments"));
+        assert!(error("call", vec![TypeTag::Signer], &valid).contains("store"));
+        assert!(error("call", vec![TypeTag::U64], &valid[..4]).contains("expects 5 arguments"));
+
+        let mut args = valid.to_vec();
+        args[0] = json!(["1", "x"]);
+        assert_eq!(
+            error("call", vec![TypeTag::U8], &args),
+            "Argument 0 (vector<u8>): Element 1: Expected an integer in range, got \"x\""
+        );
+        args[0] = json!([256]);
+        assert!(error("call", vec![TypeTag::U8], &args).starts_with("Argument 0"));
+
+        let mut args = valid.to_vec();
+        args[3] = json!(1);
+        assert!(error("call", vec![TypeTag::U8], &args)
+            .starts_with("Argument 3 (0x1::object::Object<0x1::fungible_asset::Metadata>)"));
+    }
+}
@@ -0,0 +1,205 @@
+use crate::api_types::address::AccountAddress;
+use crate::api_types::module_id::ModuleId;
+use crate::api_types::type_tag::{StructTag, TypeTag};
+use crate::api_types::u256::U256;
+
+pub use aptos_crypto_derive::{MoveStruct, MoveType};
+
+/// A Rust type with the BCS layout of a Move type, so that values can be (de)serialized as the
+/// Move type.  Derive it for newtypes with `#[derive(MoveType)]`, and for structs with
+/// `#[derive(MoveStruct)]`.
+pub trait MoveTypeTag {
+    fn type_tag() -> TypeTag;
+}
+
+/// A Rust struct with the BCS layout of a Move struct, e.g. a resource or an event.  Usually
+/// derived, with `#[derive(MoveStruct)]` and `#[move_struct(address = "0x1", module = "coin")]`.
+pub trait MoveStructType {
+    const ADDRESS: AccountAddress;
+    const MODULE_NAME: &'static str;
+    const STRUCT_NAME: &'static str;
+
+    /// The type arguments of the Move struct, from the Rust struct's type parameters
+    fn type_args() -> Vec<TypeTag> {
+        vec![]
+    }
+
+    fn module_id() -> ModuleId {
+        ModuleId::new(Self::ADDRESS, Self::MODULE_NAME.to_string())
+    }
+
+    fn struct_tag() -> StructTag {
+        StructTag {
+            address: Self::ADDRESS,
+            module: Self::MODULE_NAME.to_string(),
+            name: Self::STRUCT_NAME.to_string(),
+            type_args: Self::type_args(),
+        }
+    }
+}
+
+macro_rules! impl_move_type_tag {
+    ($($type_:ty => $type_tag:ident),* $(,)?) => {
+        $(
+            impl MoveTypeTag for $type_ {
+                fn type_tag() -> TypeTag {
+                    TypeTag::$type_tag
+                }
+            }
+        )*
+    };
+}
+
+impl_move_type_tag!(
+    bool => Bool,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    U256 => U256,
+    AccountAddress => Address,
+);
+
+impl<T: MoveTypeTag> MoveTypeTag for Vec<T> {
+    fn type_tag() -> TypeTag {
+        TypeTag::Vector(Box::new(T::type_tag()))
+    }
+}
+
+/// `0x1::string::String`, which is encoded as its UTF-8 bytes
+impl MoveTypeTag for String {
+    fn type_tag() -> TypeTag {
+        TypeTag::Struct(Box::new(StructTag {
+            address: AccountAddress::ONE,
+            module: "string".to_string(),
+            name: "String".to_string(),
+            type_args: vec![],
+        }))
+    }
+}
+
+/// `0x1::option::Option<T>`, which is encoded as a vector of at most one value, like a Rust
+/// `Option`
+impl<T: MoveTypeTag> MoveTypeTag for Option<T> {
+    fn type_tag() -> TypeTag {
+        TypeTag::Struct(Box::new(StructTag {
+            address: AccountAddress::ONE,
+            module: "option".to_string(),
+            name: "Option".to_string(),
+            type_args: vec![T::type_tag()],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_types::move_value::MoveValue;
+    use serde::{Deserialize, Serialize};
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0x1", module = "aptos_coin")]
+    struct AptosCoin {
+        dummy_field: bool,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0x1", module = "coin")]
+    struct Coin<CoinType> {
+        value: u64,
+        phantom: PhantomData<CoinType>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0x1", module = "guid", name = "ID")]
+    struct Id {
+        creation_num: u64,
+        addr: AccountAddress,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0x1", module = "event")]
+    struct EventHandle {
+        counter: u64,
+        guid: Id,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0x1", module = "coin")]
+    struct CoinStore<CoinType> {
+        coin: Coin<CoinType>,
+        frozen: bool,
+        deposit_events: EventHandle,
+        withdraw_events: EventHandle,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(
+        address = "0x000000000000000000000000000000000000000000000000000000000000cafe",
+        module = "vault",
+        name = "Vault"
+    )]
+    struct Vault<K, V> {
+        keys: Vec<K>,
+        values: Vec<V>,
+        label: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MoveType)]
+    struct Amount(u64);
+
+    #[test]
+    fn test_type_tags() {
+        assert_eq!(u8::type_tag(), TypeTag::U8);
+        assert_eq!(Amount::type_tag(), TypeTag::U64);
+        assert_eq!(
+            Vec::<Option<String>>::type_tag(),
+            TypeTag::from_str("vector<0x1::option::Option<0x1::string::String>>").unwrap()
+        );
+        assert_eq!(
+            CoinStore::<AptosCoin>::struct_tag(),
+            StructTag::from_str("0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>").unwrap()
+        );
+        assert_eq!(Id::type_tag(), TypeTag::from_str("0x1::guid::ID").unwrap());
+        assert_eq!(
+            Vault::<AccountAddress, Amount>::struct_tag(),
+            StructTag::from_str("0xcafe::vault::Vault<address, u64>").unwrap()
+        );
+        assert_eq!(
+            Vault::<u8, u8>::module_id(),
+            ModuleId::new(
+                AccountAddress::from_str("0xcafe").unwrap(),
+                "vault".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_bcs_layout() {
+        let event_handle = |counter: u64, creation_num: u64| {
+            MoveValue::Struct(vec![
+                MoveValue::U64(counter),
+                MoveValue::Struct(vec![
+                    MoveValue::U64(creation_num),
+                    MoveValue::Address(AccountAddress::ONE),
+                ]),
+            ])
+        };
+        let bytes = MoveValue::Struct(vec![
+            MoveValue::Struct(vec![MoveValue::U64(1000)]),
+            MoveValue::Bool(false),
+            event_handle(3, 2),
+            event_handle(0, 3),
+        ])
+        .to_bcs();
+
+        let coin_store: CoinStore<AptosCoin> = aptos_bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(coin_store.coin.value, 1000);
+        assert_eq!(coin_store.deposit_events.counter, 3);
+        assert_eq!(coin_store.withdraw_events.guid.creation_num, 3);
+        assert_eq!(aptos_bcs::to_bytes(&coin_store).unwrap(), bytes);
+    }
+}
@@ -0,0 +1,624 @@
+use crate::api_types::address::AccountAddress;
+use crate::api_types::type_tag::{StructTag, TypeTag};
+use crate::api_types::u256::U256;
+use anyhow::{anyhow, bail, ensure};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A Move value, which can be BCS encoded as an entry function or view function argument, and
+/// decoded from BCS given its [`TypeTag`].
+///
+/// `0x1::string::String` and `0x1::option::Option<T>` have their own variants, and
+/// `0x1::object::Object<T>` is an [`MoveValue::Address`].  Any other struct is a list of its
+/// fields in declaration order, which can be encoded, but not decoded without knowing the fields.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MoveValue {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    U256(U256),
+    Address(AccountAddress),
+    Signer(AccountAddress),
+    Vector(Vec<MoveValue>),
+    Struct(Vec<MoveValue>),
+    String(String),
+    Option(Option<Box<MoveValue>>),
+}
+
+impl MoveValue {
+    /// The BCS encoding of the value
+    pub fn to_bcs(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        self.write_bcs(&mut bytes);
+        bytes
+    }
+
+    /// The BCS encoding of the value, after checking it has the type `type_tag`
+    pub fn to_bcs_checked(&self, type_tag: &TypeTag) -> anyhow::Result<Vec<u8>> {
+        self.check_type(type_tag)?;
+        Ok(self.to_bcs())
+    }
+
+    /// Decode a BCS encoded value of type `type_tag`, which must use up all the bytes
+    pub fn from_bcs(type_tag: &TypeTag, bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = BcsReader { bytes };
+        let value = reader.read_value(type_tag)?;
+        ensure!(reader.bytes.is_empty(), "Unexpected trailing bytes");
+        Ok(value)
+    }
+
+    /// Convert a JSON argument of type `type_tag`, in the format the node's JSON API accepts.
+    ///
+    /// Integers may be numbers or strings, `vector<u8>` may be a hex string, an `Option<T>` may
+    /// be `null`, the value, or `{"vec": [...]}`, and an `Object<T>` may be its address or
+    /// `{"inner": address}`.
+    pub fn from_json(type_tag: &TypeTag, value: &serde_json::Value) -> anyhow::Result<Self> {
+        use serde_json::Value;
+
+        let mismatch = || {
+            anyhow!(
+                "Expected a value of type {}, got {}",
+                type_tag.to_canonical_string(),
+                value
+            )
+        };
+        Ok(match type_tag {
+            TypeTag::Bool => Self::Bool(value.as_bool().ok_or_else(mismatch)?),
+            TypeTag::U8 => Self::U8(json_integer(value)?),
+            TypeTag::U16 => Self::U16(json_integer(value)?),
+            TypeTag::U32 => Self::U32(json_integer(value)?),
+            TypeTag::U64 => Self::U64(json_integer(value)?),
+            TypeTag::U128 => Self::U128(json_integer(value)?),
+            TypeTag::U256 => Self::U256(json_integer(value)?),
+            TypeTag::Address => Self::Address(json_address(value)?),
+            TypeTag::Signer => bail!("Signers can't be passed as arguments"),
+            TypeTag::Vector(inner) => match value {
+                Value::String(hex_bytes) if **inner == TypeTag::U8 => Self::Vector(
+                    hex::decode(hex_bytes.trim_start_matches("0x"))
+                        .map_err(|err| anyhow!("Invalid hex {}: {}", value, err))?
+                        .into_iter()
+                        .map(Self::U8)
+                        .collect(),
+                ),
+                Value::Array(values) => Self::Vector(
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, value)| {
+                            Self::from_json(inner, value)
+                                .map_err(|err| anyhow!("Element {}: {}", i, err))
+                        })
+                        .collect::<anyhow::Result<_>>()?,
+                ),
+                _ => return Err(mismatch()),
+            },
+            TypeTag::Struct(struct_tag) => match framework_struct(struct_tag) {
+                Some(("string", "String")) => {
+                    Self::String(value.as_str().ok_or_else(mismatch)?.to_string())
+                }
+                Some(("object", "Object")) => match value.get("inner") {
+                    Some(inner) => Self::Address(json_address(inner)?),
+                    None => Self::Address(json_address(value)?),
+                },
+                Some(("option", "Option")) => {
+                    let inner = struct_tag
+                        .type_args
+                        .first()
+                        .ok_or_else(|| anyhow!("Option is missing its type argument"))?;
+                    let value = match value.get("vec") {
+                        Some(Value::Array(values)) if values.len() <= 1 => values.first(),
+                        Some(_) => bail!("Invalid option {}", value),
+                        None => Some(value).filter(|value| !value.is_null()),
+                    };
+                    Self::Option(
+                        value
+                            .map(|value| Self::from_json(inner, value).map(Box::new))
+                            .transpose()?,
+                    )
+                }
+                _ => bail!(
+                    "Unsupported argument type {}",
+                    struct_tag.to_canonical_string()
+                ),
+            },
+        })
+    }
+
+    /// Check the value has the type `type_tag`.  Structs other than the ones with their own
+    /// variants can only be checked for being structs.
+    pub fn check_type(&self, type_tag: &TypeTag) -> anyhow::Result<()> {
+        let matches = match (self, type_tag) {
+            (Self::Bool(_), TypeTag::Bool)
+            | (Self::U8(_), TypeTag::U8)
+            | (Self::U16(_), TypeTag::U16)
+            | (Self::U32(_), TypeTag::U32)
+            | (Self::U64(_), TypeTag::U64)
+            | (Self::U128(_), TypeTag::U128)
+            | (Self::U256(_), TypeTag::U256)
+            | (Self::Address(_), TypeTag::Address)
+            | (Self::Signer(_), TypeTag::Signer) => true,
+            (Self::Vector(values), TypeTag::Vector(inner)) => {
+                return values.iter().try_for_each(|value| value.check_type(inner))
+            }
+            (value, TypeTag::Struct(struct_tag)) => match (value, framework_struct(struct_tag)) {
+                (Self::String(_), Some(("string", "String")))
+                | (Self::Address(_), Some(("object", "Object"))) => true,
+                (Self::Option(value), Some(("option", "Option"))) => {
+                    return match (value, struct_tag.type_args.first()) {
+                        (Some(value), Some(inner)) => value.check_type(inner),
+                        (None, Some(_)) => Ok(()),
+                        (_, None) => bail!("Option is missing its type argument"),
+                    }
+                }
+                (Self::Struct(_), None) => true,
+                _ => false,
+            },
+            _ => false,
+        };
+        ensure!(
+            matches,
+            "Expected a value of type {}, got {}",
+            type_tag.to_canonical_string(),
+            self
+        );
+        Ok(())
+    }
+
+    fn write_bcs(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Self::Bool(value) => bytes.push(*value as u8),
+            Self::U8(value) => bytes.push(*value),
+            Self::U16(value) => bytes.extend(value.to_le_bytes()),
+            Self::U32(value) => bytes.extend(value.to_le_bytes()),
+            Self::U64(value) => bytes.extend(value.to_le_bytes()),
+            Self::U128(value) => bytes.extend(value.to_le_bytes()),
+            Self::U256(value) => bytes.extend(value.to_le_bytes()),
+            Self::Address(address) | Self::Signer(address) => bytes.extend(address.to_bytes()),
+            Self::Vector(values) => {
+                write_uleb128(bytes, values.len());
+                values.iter().for_each(|value| value.write_bcs(bytes));
+            }
+            Self::Struct(fields) => fields.iter().for_each(|field| field.write_bcs(bytes)),
+            Self::String(string) => {
+                write_uleb128(bytes, string.len());
+                bytes.extend(string.as_bytes());
+            }
+            // An option is a vector of zero or one values
+            Self::Option(value) => {
+                write_uleb128(bytes, value.is_some() as usize);
+                if let Some(value) = value {
+                    value.write_bcs(bytes);
+                }
+            }
+        }
+    }
+}
+
+/// The module and name of a struct in the framework at `0x1`
+fn framework_struct(struct_tag: &StructTag) -> Option<(&str, &str)> {
+    (struct_tag.address == AccountAddress::ONE)
+        .then_some((struct_tag.module.as_str(), struct_tag.name.as_str()))
+}
+
+/// An integer given as a JSON number or a decimal string
+fn json_integer<T: FromStr>(value: &serde_json::Value) -> anyhow::Result<T> {
+    let parsed = match value {
+        serde_json::Value::Number(number) => number.to_string().parse().ok(),
+        serde_json::Value::String(string) => string.parse().ok(),
+        _ => None,
+    };
+    parsed.ok_or_else(|| anyhow!("Expected an integer in range, got {}", value))
+}
+
+fn json_address(value: &serde_json::Value) -> anyhow::Result<AccountAddress> {
+    value
+        .as_str()
+        .and_then(|address| AccountAddress::from_str(address).ok())
+        .ok_or_else(|| anyhow!("Expected an address, got {}", value))
+}
+
+fn write_uleb128(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Read a ULEB128 from the front of `bytes` the way BCS does for lengths and enum variants: it
+/// must be canonical, i.e. without trailing zero bytes, and at most `u32::MAX`
+pub(crate) fn read_uleb128(bytes: &mut &[u8]) -> anyhow::Result<usize> {
+    let mut value: u64 = 0;
+    for shift in (0..32).step_by(7) {
+        let (&byte, rest) = bytes
+            .split_first()
+            .ok_or_else(|| anyhow!("Unexpected end of uleb128"))?;
+        *bytes = rest;
+        let digit = byte & 0x7f;
+        value |= (digit as u64) << shift;
+        if byte & 0x80 == 0 {
+            ensure!(shift == 0 || digit != 0, "Non-canonical uleb128");
+            ensure!(
+                value <= u32::MAX as u64,
+                "uleb128 {} is out of range",
+                value
+            );
+            return Ok(usize::try_from(value)?);
+        }
+    }
+    bail!("uleb128 is out of range")
+}
+
+struct BcsReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BcsReader<'a> {
+    fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        ensure!(self.bytes.len() >= len, "Unexpected end of value");
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> anyhow::Result<[u8; N]> {
+        Ok(self.take(N)?.try_into()?)
+    }
+
+    fn read_uleb128(&mut self) -> anyhow::Result<usize> {
+        read_uleb128(&mut self.bytes)
+    }
+
+    fn read_value(&mut self, type_tag: &TypeTag) -> anyhow::Result<MoveValue> {
+        Ok(match type_tag {
+            TypeTag::Bool => match self.take(1)?[0] {
+                0 => MoveValue::Bool(false),
+                1 => MoveValue::Bool(true),
+                other => bail!("Invalid bool {}", other),
+            },
+            TypeTag::U8 => MoveValue::U8(self.take(1)?[0]),
+            TypeTag::U16 => MoveValue::U16(u16::from_le_bytes(self.take_array()?)),
+            TypeTag::U32 => MoveValue::U32(u32::from_le_bytes(self.take_array()?)),
+            TypeTag::U64 => MoveValue::U64(u64::from_le_bytes(self.take_array()?)),
+            TypeTag::U128 => MoveValue::U128(u128::from_le_bytes(self.take_array()?)),
+            TypeTag::U256 => MoveValue::U256(U256::from_le_bytes(&self.take_array()?)),
+            TypeTag::Address => MoveValue::Address(AccountAddress::new(self.take_array()?)),
+            TypeTag::Signer => MoveValue::Signer(AccountAddress::new(self.take_array()?)),
+            TypeTag::Vector(inner) => {
+                let len = self.read_uleb128()?;
+                MoveValue::Vector(
+                    (0..len)
+                        .map(|_| self.read_value(inner))
+                        .collect::<anyhow::Result<_>>()?,
+                )
+            }
+            TypeTag::Struct(struct_tag) => self.read_struct(struct_tag)?,
+        })
+    }
+
+    /// Only structs with their own variants can be decoded, as the fields of others are unknown
+    fn read_struct(&mut self, struct_tag: &StructTag) -> anyhow::Result<MoveValue> {
+        Ok(match framework_struct(struct_tag) {
+            Some(("string", "String")) => {
+                let len = self.read_uleb128()?;
+                MoveValue::String(std::str::from_utf8(self.take(len)?)?.to_string())
+            }
+            Some(("object", "Object")) => {
+                MoveValue::Address(AccountAddress::new(self.take_array()?))
+            }
+            Some(("option", "Option")) => {
+                let inner = struct_tag
+                    .type_args
+                    .first()
+                    .ok_or_else(|| anyhow!("Option is missing its type argument"))?;
+                match self.read_uleb128()? {
+                    0 => MoveValue::Option(None),
+                    1 => MoveValue::Option(Some(Box::new(self.read_value(inner)?))),
+                    _ => bail!("Invalid option"),
+                }
+            }
+            _ => bail!(
+                "Can't decode struct {} without its layout",
+                struct_tag.to_canonical_string()
+            ),
+        })
+    }
+}
+
+/// Formats the value readably: strings are JSON quoted, and non-empty byte vectors are hex
+impl Display for MoveValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::U8(value) => write!(f, "{}", value),
+            Self::U16(value) => write!(f, "{}", value),
+            Self::U32(value) => write!(f, "{}", value),
+            Self::U64(value) => write!(f, "{}", value),
+            Self::U128(value) => write!(f, "{}", value),
+            Self::U256(value) => write!(f, "{}", value),
+            Self::Address(address) | Self::Signer(address) => write!(f, "{}", address),
+            Self::Vector(values)
+                if !values.is_empty() && values.iter().all(|v| matches!(v, Self::U8(_))) =>
+            {
+                write!(f, "0x")?;
+                values.iter().try_for_each(|value| match value {
+                    Self::U8(byte) => write!(f, "{:02x}", byte),
+                    _ => unreachable!(),
+                })
+            }
+            Self::Vector(values) | Self::Struct(values) => {
+                let (open, close) = match self {
+                    Self::Vector(_) => ("[", "]"),
+                    _ => ("{", "}"),
+                };
+                write!(f, "{}", open)?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "{}", close)
+            }
+            Self::String(string) => write!(f, "{}", serde_json::Value::from(string.as_str())),
+            Self::Option(None) => write!(f, "none"),
+            Self::Option(Some(value)) => write!(f, "some({})", value),
+        }
+    }
+}
+
+macro_rules! impl_primitive_conversions {
+    ($($type:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$type> for MoveValue {
+                fn from(value: $type) -> Self {
+                    Self::$variant(value)
+                }
+            }
+
+            impl TryFrom<MoveValue> for $type {
+                type Error = anyhow::Error;
+
+                fn try_from(value: MoveValue) -> anyhow::Result<Self> {
+                    match value {
+                        MoveValue::$variant(value) => Ok(value),
+                        other => bail!(
+                            "Expected a {} value, got {}",
+                            stringify!($variant),
+                            other
+                        ),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_conversions!(
+    bool => Bool,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    U256 => U256,
+    AccountAddress => Address,
+    String => String,
+);
+
+impl From<&str> for MoveValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl<T: Into<MoveValue>> From<Vec<T>> for MoveValue {
+    fn from(values: Vec<T>) -> Self {
+        Self::Vector(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<MoveValue>> From<Option<T>> for MoveValue {
+    fn from(value: Option<T>) -> Self {
+        Self::Option(value.map(|value| Box::new(value.into())))
+    }
+}
+
+impl<T: TryFrom<MoveValue, Error = anyhow::Error>> TryFrom<MoveValue> for Vec<T> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: MoveValue) -> anyhow::Result<Self> {
+        match value {
+            MoveValue::Vector(values) => values.into_iter().map(T::try_from).collect(),
+            other => bail!("Expected a vector, got {}", other),
+        }
+    }
+}
+
+impl<T: TryFrom<MoveValue, Error = anyhow::Error>> TryFrom<MoveValue> for Option<T> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: MoveValue) -> anyhow::Result<Self> {
+        match value {
+            MoveValue::Option(value) => value.map(|value| T::try_from(*value)).transpose(),
+            other => bail!("Expected an option, got {}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn type_tag(s: &str) -> TypeTag {
+        TypeTag::from_str(s).unwrap()
+    }
+
+    /// Check the value encodes like serde BCS of `expected`, and decodes back
+    fn check<T: serde::Serialize>(value: MoveValue, type_: &str, expected: T) {
+        let type_tag = type_tag(type_);
+        let bytes = aptos_bcs::to_bytes(&expected).unwrap();
+        assert_eq!(value.to_bcs_checked(&type_tag).unwrap(), bytes, "{}", type_);
+        assert_eq!(
+            MoveValue::from_bcs(&type_tag, &bytes).unwrap(),
+            value,
+            "{}",
+            type_
+        );
+    }
+
+    #[test]
+    fn test_bcs_round_trip() {
+        check(true.into(), "bool", true);
+        check(7u8.into(), "u8", 7u8);
+        check(7u16.into(), "u16", 7u16);
+        check(7u32.into(), "u32", 7u32);
+        check(7u64.into(), "u64", 7u64);
+        check(u128::MAX.into(), "u128", u128::MAX);
+        check(U256::from(7u64).into(), "u256", U256::from(7u64));
+        check(AccountAddress::ONE.into(), "address", AccountAddress::ONE);
+        check(vec![1u64, 2].into(), "vector<u64>", vec![1u64, 2]);
+        check(
+            vec![vec![1u8], vec![]].into(),
+            "vector<vector<u8>>",
+            vec![vec![1u8], vec![]],
+        );
+        check("hello".into(), "0x1::string::String", "hello");
+        check(Some(5u64).into(), "0x1::option::Option<u64>", Some(5u64));
+        check(None::<u64>.into(), "0x1::option::Option<u64>", None::<u64>);
+        check(
+            AccountAddress::TWO.into(),
+            "0x1::object::Object<0x1::fungible_asset::Metadata>",
+            AccountAddress::TWO,
+        );
+
+        // Long vectors need a multi-byte length prefix
+        let long = vec![0u8; 300];
+        check(long.clone().into(), "vector<u8>", long);
+    }
+
+    #[test]
+    fn test_struct_encoding() {
+        let value = MoveValue::Struct(vec![1u64.into(), "name".into()]);
+        assert_eq!(
+            value.to_bcs(),
+            aptos_bcs::to_bytes(&(1u64, "name")).unwrap()
+        );
+        assert!(value
+            .check_type(&type_tag("0xcafe::module::Struct"))
+            .is_ok());
+        assert!(MoveValue::from_bcs(&type_tag("0xcafe::module::Struct"), &value.to_bcs()).is_err());
+    }
+
+    #[test]
+    fn test_type_checking() {
+        assert!(MoveValue::from(1u64).check_type(&type_tag("u8")).is_err());
+        assert!(MoveValue::from(vec![1u64, 2])
+            .check_type(&type_tag("vector<u8>"))
+            .is_err());
+        assert!(MoveValue::from("hi")
+            .check_type(&type_tag("vector<u8>"))
+            .is_err());
+        assert!(MoveValue::from(Some(1u8))
+            .check_type(&type_tag("0x1::option::Option<u64>"))
+            .is_err());
+        assert!(MoveValue::from(AccountAddress::ONE)
+            .check_type(&type_tag("0x1::string::String"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_invalid_bcs() {
+        assert!(MoveValue::from_bcs(&type_tag("bool"), &[2]).is_err());
+        assert!(MoveValue::from_bcs(&type_tag("u64"), &[1, 2]).is_err());
+        assert!(MoveValue::from_bcs(&type_tag("u8"), &[1, 2]).is_err());
+        assert!(MoveValue::from_bcs(&type_tag("0x1::string::String"), &[1, 0xff]).is_err());
+        assert!(MoveValue::from_bcs(&type_tag("0x1::option::Option<u8>"), &[2, 1, 1]).is_err());
+    }
+
+    #[test]
+    fn test_read_uleb128() {
+        let read = |mut bytes: &[u8]| read_uleb128(&mut bytes);
+        assert_eq!(read(&[0]).unwrap(), 0);
+        assert_eq!(read(&[0x80, 0x01]).unwrap(), 128);
+        assert_eq!(
+            read(&[0xff, 0xff, 0xff, 0xff, 0x0f]).unwrap(),
+            u32::MAX as usize
+        );
+
+        // Overlong, too large, or truncated
+        assert!(read(&[0x80, 0x00]).is_err());
+        assert!(read(&[0xff, 0x80, 0x00]).is_err());
+        assert!(read(&[0x80, 0x80, 0x80, 0x80, 0x10]).is_err());
+        assert!(read(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x01]).is_err());
+        assert!(read(&[0x80]).is_err());
+
+        // As a length, e.g. an empty vector with an overlong length
+        let vector = type_tag("vector<u8>");
+        assert_eq!(
+            MoveValue::from_bcs(&vector, &[0]).unwrap(),
+            MoveValue::Vector(vec![])
+        );
+        assert!(aptos_bcs::from_bytes::<Vec<u8>>(&[0x80, 0x00]).is_err());
+        assert!(MoveValue::from_bcs(&vector, &[0x80, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_rust_conversions() {
+        let value = MoveValue::from(vec![Some(1u64), None]);
+        assert_eq!(
+            Vec::<Option<u64>>::try_from(value).unwrap(),
+            vec![Some(1), None]
+        );
+        assert_eq!(String::try_from(MoveValue::from("hello")).unwrap(), "hello");
+        assert!(u64::try_from(MoveValue::from(1u8)).is_err());
+    }
+
+    #[test]
+    fn test_from_json() {
+        let from_json = |type_: &str, value| MoveValue::from_json(&type_tag(type_), &value);
+        assert_eq!(from_json("u64", json!("10")).unwrap(), 10u64.into());
+        assert_eq!(from_json("u8", json!(10)).unwrap(), 10u8.into());
+        assert_eq!(
+            from_json("u256", json!("10")).unwrap(),
+            U256::from(10u64).into()
+        );
+        assert!(from_json("u8", json!(256)).is_err());
+        assert!(from_json("u64", json!(-1)).is_err());
+        assert!(from_json("u64", json!(1.5)).is_err());
+        assert!(from_json("bool", json!("true")).is_err());
+        assert_eq!(
+            from_json("address", json!("0x1")).unwrap(),
+            AccountAddress::ONE.into()
+        );
+        assert_eq!(
+            from_json("vector<u8>", json!("0xab")).unwrap(),
+            vec![0xabu8].into()
+        );
+        assert_eq!(
+            from_json("vector<u8>", json!([1])).unwrap(),
+            vec![1u8].into()
+        );
+        assert!(from_json("vector<u64>", json!("0xab")).is_err());
+        assert!(from_json("signer", json!("0x1")).is_err());
+        assert!(from_json("0x1::option::Option<u8>", json!({"vec": [1, 2]})).is_err());
+        assert!(from_json("0xcafe::module::Struct", json!({})).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(MoveValue::from(vec![1u8, 0xab]).to_string(), "0x01ab");
+        assert_eq!(MoveValue::from(vec![1u64, 2]).to_string(), "[1, 2]");
+        assert_eq!(MoveValue::from("hi").to_string(), "\"hi\"");
+        assert_eq!(MoveValue::from(Some(7u64)).to_string(), "some(7)");
+        assert_eq!(MoveValue::from(None::<u64>).to_string(), "none");
+        assert_eq!(
+            MoveValue::Struct(vec![true.into(), 1u8.into()]).to_string(),
+            "{true, 1}"
+        );
+    }
+}
@@ -25,3 +25,71 @@ impl<'de> Deserialize<'de> for U64 {
             .map_err(|err| D::Error::custom(err.to_string()))
     }
 }
+
+impl U64 {
+    pub fn new(inner: u64) -> Self {
+        U64(inner)
+    }
+
+    pub fn inner(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for U64 {
+    fn from(inner: u64) -> Self {
+        U64(inner)
+    }
+}
+
+impl From<U64> for u64 {
+    fn from(value: U64) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialOrd, PartialEq, Ord, Hash)]
+pub struct U128(u128);
+
+impl Serialize for U128 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for U128 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        u128::from_str(&string)
+            .map(U128)
+            .map_err(|err| D::Error::custom(err.to_string()))
+    }
+}
+
+impl U128 {
+    pub fn new(inner: u128) -> Self {
+        U128(inner)
+    }
+
+    pub fn inner(&self) -> u128 {
+        self.0
+    }
+}
+
+impl From<u128> for U128 {
+    fn from(inner: u128) -> Self {
+        U128(inner)
+    }
+}
+
+impl From<U128> for u128 {
+    fn from(value: U128) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,115 @@
+use crate::api_types::account::AccountResource;
+use crate::api_types::address::AccountAddress;
+use crate::api_types::numbers::U64;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The resource type every Move object has
+pub const OBJECT_CORE_TYPE: &str = "0x1::object::ObjectCore";
+
+/// The `0x1::object::ObjectCore` resource, which makes an address an object
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ObjectCore {
+    /// The account or object owning the object
+    pub owner: AccountAddress,
+    /// Whether the owner can transfer the object without a `TransferRef`
+    pub allow_ungated_transfer: bool,
+    /// The creation number of the next GUID created by the object
+    pub guid_creation_num: U64,
+}
+
+/// Every resource stored at an object's address, e.g. its `ObjectCore` along with a token or
+/// fungible asset metadata
+#[derive(Clone, Debug)]
+pub struct ObjectResources {
+    pub address: AccountAddress,
+    pub core: ObjectCore,
+    /// The data of every resource, including `ObjectCore`, keyed by resource type
+    pub resources: BTreeMap<String, serde_json::Value>,
+}
+
+impl ObjectResources {
+    /// Group the resources at an address, which must include an `ObjectCore`
+    pub fn from_resources(
+        address: AccountAddress,
+        resources: Vec<AccountResource>,
+    ) -> anyhow::Result<Self> {
+        let resources: BTreeMap<_, _> = resources
+            .into_iter()
+            .map(|resource| (resource.type_, resource.data))
+            .collect();
+        let core = resources
+            .get(OBJECT_CORE_TYPE)
+            .ok_or_else(|| anyhow!("{} is not an object", address))?;
+        Ok(Self {
+            address,
+            core: serde_json::from_value(core.clone())?,
+            resources,
+        })
+    }
+
+    /// The data of a resource, by its type, e.g. `0x4::token::Token`
+    pub fn get(&self, resource_type: &str) -> Option<&serde_json::Value> {
+        self.resources.get(resource_type)
+    }
+
+    /// The types of every resource at the object
+    pub fn resource_types(&self) -> impl Iterator<Item = &str> {
+        self.resources.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::str::FromStr;
+
+    fn object_core_json() -> serde_json::Value {
+        json!({
+            "allow_ungated_transfer": true,
+            "guid_creation_num": "1125899906842625",
+            "owner": "0xcafe",
+            "transfer_events": {
+                "counter": "0",
+                "guid": {"id": {"addr": "0xb", "creation_num": "1125899906842624"}}
+            }
+        })
+    }
+
+    #[test]
+    fn test_object_core() {
+        let core: ObjectCore = serde_json::from_value(object_core_json()).unwrap();
+        assert_eq!(core.owner, AccountAddress::from_str("0xcafe").unwrap());
+        assert!(core.allow_ungated_transfer);
+        assert_eq!(core.guid_creation_num.inner(), 1125899906842625);
+    }
+
+    #[test]
+    fn test_object_resources() {
+        let address = AccountAddress::from_str("0xb").unwrap();
+        let resources = vec![
+            AccountResource::new("0x1::object::ObjectCore".to_string(), object_core_json()),
+            AccountResource::new("0x4::token::Token".to_string(), json!({"name": "Token #1"})),
+        ];
+        let object = ObjectResources::from_resources(address, resources).unwrap();
+        assert_eq!(object.address, address);
+        assert_eq!(
+            object.core.owner,
+            AccountAddress::from_str("0xcafe").unwrap()
+        );
+        assert_eq!(object.get("0x4::token::Token").unwrap()["name"], "Token #1");
+        assert_eq!(
+            object.resource_types().collect::<Vec<_>>(),
+            ["0x1::object::ObjectCore", "0x4::token::Token"]
+        );
+
+        // An account without an ObjectCore isn't an object
+        let account = vec![AccountResource::new(
+            "0x1::account::Account".to_string(),
+            json!({}),
+        )];
+        assert!(ObjectResources::from_resources(address, account).is_err());
+    }
+}
@@ -1,9 +1,10 @@
 use crate::api_types::address::AccountAddress;
+use crate::api_types::move_module::{MoveStructTag, MoveType};
 use crate::api_types::type_tag::{StructTag, TypeTag};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_while1},
-    character::complete::{char, hex_digit1},
+    character::complete::{char, digit1, hex_digit1},
     combinator::{all_consuming, map, map_res, opt},
     multi::separated_list0,
     sequence::{delimited, preceded, tuple},
@@ -27,6 +28,14 @@ pub fn parse_struct_tag(input: &str) -> Result<StructTag, anyhow::Error> {
     }
 }
 
+/// Parse a Move type as it appears in a module ABI, e.g. `&mut 0x1::object::Object<T0>`
+pub fn parse_move_type(input: &str) -> Result<MoveType, anyhow::Error> {
+    match all_consuming(move_type)(input) {
+        Ok((_, move_type)) => Ok(move_type),
+        Err(e) => Err(anyhow::anyhow!("Failed to parse move type: {}", e)),
+    }
+}
+
 fn type_tag(input: &str) -> IResult<&str, TypeTag> {
     alt((
         primitive_type,
@@ -92,6 +101,66 @@ fn struct_tag(input: &str) -> IResult<&str, StructTag> {
     )(input)
 }
 
+fn move_type(input: &str) -> IResult<&str, MoveType> {
+    alt((
+        map(preceded(tag("&mut "), move_type), |to| {
+            MoveType::Reference {
+                mutable: true,
+                to: Box::new(to),
+            }
+        }),
+        map(preceded(char('&'), move_type), |to| MoveType::Reference {
+            mutable: false,
+            to: Box::new(to),
+        }),
+        map_res(preceded(char('T'), digit1), |index: &str| {
+            index.parse().map(MoveType::GenericTypeParam)
+        }),
+        map(primitive_type, |primitive| MoveType::from(&primitive)),
+        map(
+            preceded(tag("vector"), delimited(char('<'), move_type, char('>'))),
+            |inner| MoveType::Vector(Box::new(inner)),
+        ),
+        map(move_struct_tag, MoveType::Struct),
+    ))(input)
+}
+
+fn move_struct_tag(input: &str) -> IResult<&str, MoveStructTag> {
+    map(
+        tuple((
+            account_address,
+            preceded(tag("::"), identifier),
+            preceded(tag("::"), identifier),
+            opt(delimited(
+                delimited(
+                    nom::character::complete::multispace0,
+                    char('<'),
+                    nom::character::complete::multispace0,
+                ),
+                separated_list0(
+                    delimited(
+                        nom::character::complete::multispace0,
+                        char(','),
+                        nom::character::complete::multispace0,
+                    ),
+                    move_type,
+                ),
+                delimited(
+                    nom::character::complete::multispace0,
+                    char('>'),
+                    nom::character::complete::multispace0,
+                ),
+            )),
+        )),
+        |(address, module, name, generic_type_params)| MoveStructTag {
+            address,
+            module,
+            name,
+            generic_type_params: generic_type_params.unwrap_or_default(),
+        },
+    )(input)
+}
+
 fn account_address(input: &str) -> IResult<&str, AccountAddress> {
     map_res(preceded(tag("0x"), hex_digit1), |hex_str: &str| {
         AccountAddress::from_str(&format!("0x{}", hex_str))
@@ -169,6 +238,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_move_type() {
+        assert_eq!(
+            parse_move_type("&signer").unwrap(),
+            MoveType::Reference {
+                mutable: false,
+                to: Box::new(MoveType::Signer)
+            }
+        );
+        assert_eq!(
+            parse_move_type("&mut vector<T1>").unwrap(),
+            MoveType::Reference {
+                mutable: true,
+                to: Box::new(MoveType::Vector(Box::new(MoveType::GenericTypeParam(1))))
+            }
+        );
+        let MoveType::Struct(table) =
+            parse_move_type("0x1::table::Table<T0, 0x1::string::String>").unwrap()
+        else {
+            panic!("Expected a struct");
+        };
+        assert_eq!(table.name, "Table");
+        assert_eq!(table.generic_type_params[0], MoveType::GenericTypeParam(0));
+        assert!(parse_move_type("Tx").is_err());
+        assert!(parse_move_type("|u64|bool").is_err());
+    }
+
     #[test]
     fn test_roundtrip_type_tag() {
         let test_cases = vec!["bool", "u8", "u64", "vector<u8>", "vector<vector<u64>>"];
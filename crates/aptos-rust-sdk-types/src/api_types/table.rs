@@ -0,0 +1,308 @@
+//! Rust mirrors of the Move tables and the collections built on them.  Only their handles are
+//! stored in a resource, and their items are read separately through the table item endpoints.
+//!
+//! Each type has the BCS layout of its Move type, so it can be read as part of a resource with
+//! `get_account_resource_bcs`.
+
+use crate::api_types::move_type_tag::MoveStruct;
+use crate::api_types::state_key::TableHandle;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// The node index standing for no node in a `BigOrderedMap`
+pub const BIG_ORDERED_MAP_NULL_INDEX: u64 = 0;
+/// The node index of the root of a `BigOrderedMap`, which is stored inline rather than in a slot
+pub const BIG_ORDERED_MAP_ROOT_INDEX: u64 = 1;
+
+/// `0x1::table::Table<K, V>`, whose items are keyed by the BCS encoding of `K`
+#[derive(Debug, Serialize, Deserialize, MoveStruct)]
+#[move_struct(address = "0x1", module = "table")]
+pub struct Table<K, V> {
+    pub handle: TableHandle,
+    #[serde(default)]
+    phantom: PhantomData<(K, V)>,
+}
+
+impl<K, V> Table<K, V> {
+    pub fn new(handle: TableHandle) -> Self {
+        Self {
+            handle,
+            phantom: PhantomData,
+        }
+    }
+}
+
+// Derived impls would needlessly require `K` and `V` to implement the traits
+impl<K, V> Clone for Table<K, V> {
+    fn clone(&self) -> Self {
+        Self::new(self.handle)
+    }
+}
+
+impl<K, V> PartialEq for Table<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl<K, V> Eq for Table<K, V> {}
+
+/// `0x1::table_with_length::TableWithLength<K, V>`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(address = "0x1", module = "table_with_length")]
+pub struct TableWithLength<K, V> {
+    pub inner: Table<K, V>,
+    pub length: u64,
+}
+
+/// `0x1::smart_table::SmartTable<K, V>`, whose entries are spread over buckets `0` to
+/// `num_buckets - 1`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(address = "0x1", module = "smart_table")]
+pub struct SmartTable<K, V> {
+    pub buckets: TableWithLength<u64, Vec<SmartTableEntry<K, V>>>,
+    pub num_buckets: u64,
+    pub level: u8,
+    /// The number of entries in the table
+    pub size: u64,
+    pub split_load_threshold: u8,
+    pub target_bucket_size: u64,
+}
+
+/// `0x1::smart_table::Entry<K, V>`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(address = "0x1", module = "smart_table", name = "Entry")]
+pub struct SmartTableEntry<K, V> {
+    /// The SipHash of the BCS encoded key
+    pub hash: u64,
+    pub key: K,
+    pub value: V,
+}
+
+/// `0x1::big_ordered_map::BigOrderedMap<K, V>`, a B+ tree whose root is stored inline and whose
+/// other nodes are stored in table slots
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BigOrderedMap<K, V> {
+    BPlusTreeMap {
+        root: BigOrderedMapNode<K, V>,
+        nodes: StorageSlotsAllocator<BigOrderedMapNode<K, V>>,
+        /// The node index of the leftmost leaf
+        min_leaf_index: u64,
+        /// The node index of the rightmost leaf
+        max_leaf_index: u64,
+        constant_kv_size: bool,
+        inner_max_degree: u16,
+        leaf_max_degree: u16,
+    },
+}
+
+impl<K, V> BigOrderedMap<K, V> {
+    pub fn root(&self) -> &BigOrderedMapNode<K, V> {
+        let BigOrderedMap::BPlusTreeMap { root, .. } = self;
+        root
+    }
+
+    /// The table holding the nodes other than the root, keyed by node index, if any were created
+    pub fn nodes(&self) -> Option<&Table<u64, StorageSlot<BigOrderedMapNode<K, V>>>> {
+        let BigOrderedMap::BPlusTreeMap { nodes, .. } = self;
+        let StorageSlotsAllocator::V1 { slots, .. } = nodes;
+        slots.as_ref()
+    }
+
+    pub fn min_leaf_index(&self) -> u64 {
+        let BigOrderedMap::BPlusTreeMap { min_leaf_index, .. } = self;
+        *min_leaf_index
+    }
+}
+
+/// `0x1::big_ordered_map::Node<K, V>`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BigOrderedMapNode<K, V> {
+    V1 {
+        is_leaf: bool,
+        /// For an inner node, the largest key of each child's subtree, and for a leaf, its
+        /// entries
+        children: OrderedMap<K, BigOrderedMapChild<V>>,
+        /// The node index of the previous node at the same level
+        prev: u64,
+        /// The node index of the next node at the same level
+        next: u64,
+    },
+}
+
+impl<K, V> BigOrderedMapNode<K, V> {
+    pub fn is_leaf(&self) -> bool {
+        let BigOrderedMapNode::V1 { is_leaf, .. } = self;
+        *is_leaf
+    }
+
+    /// The node index of the next node at the same level, if there is one
+    pub fn next(&self) -> Option<u64> {
+        let BigOrderedMapNode::V1 { next, .. } = self;
+        (*next != BIG_ORDERED_MAP_NULL_INDEX).then_some(*next)
+    }
+
+    /// The entries of a leaf, in key order
+    pub fn into_leaf_entries(self) -> impl Iterator<Item = (K, V)> {
+        let BigOrderedMapNode::V1 { children, .. } = self;
+        let OrderedMap::SortedVectorMap { entries } = children;
+        entries
+            .into_iter()
+            .filter_map(|OrderedMapEntry { key, value }| match value {
+                BigOrderedMapChild::Leaf { value } => Some((key, value)),
+                BigOrderedMapChild::Inner { .. } => None,
+            })
+    }
+}
+
+/// `0x1::big_ordered_map::Child<V>`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BigOrderedMapChild<V> {
+    Inner { node_index: StoredSlot },
+    Leaf { value: V },
+}
+
+/// `0x1::ordered_map::OrderedMap<K, V>`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderedMap<K, V> {
+    SortedVectorMap { entries: Vec<OrderedMapEntry<K, V>> },
+}
+
+/// `0x1::ordered_map::Entry<K, V>`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderedMapEntry<K, V> {
+    pub key: K,
+    pub value: V,
+}
+
+/// `0x1::storage_slots_allocator::StorageSlotsAllocator<T>`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StorageSlotsAllocator<T> {
+    V1 {
+        /// Created on the first allocation
+        slots: Option<Table<u64, StorageSlot<T>>>,
+        new_slot_index: u64,
+        should_reuse: bool,
+        reuse_head_index: u64,
+        reuse_spare_count: u32,
+    },
+}
+
+/// `0x1::storage_slots_allocator::Link<T>`, the value of a slot
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StorageSlot<T> {
+    Some { value: T },
+    Empty { next: u64 },
+}
+
+/// `0x1::storage_slots_allocator::StoredSlot`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(address = "0x1", module = "storage_slots_allocator")]
+pub struct StoredSlot {
+    pub slot_index: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_types::address::AccountAddress;
+    use crate::api_types::move_type_tag::MoveStructType;
+    use crate::api_types::move_value::MoveValue;
+    use crate::api_types::type_tag::StructTag;
+    use std::str::FromStr;
+
+    fn handle(address: &str) -> MoveValue {
+        MoveValue::Address(AccountAddress::from_str(address).unwrap())
+    }
+
+    #[test]
+    fn test_smart_table_layout() {
+        let bytes = MoveValue::Struct(vec![
+            MoveValue::Struct(vec![
+                MoveValue::Struct(vec![handle("0xa")]),
+                MoveValue::U64(2),
+            ]),
+            MoveValue::U64(2),
+            MoveValue::U8(1),
+            MoveValue::U64(5),
+            MoveValue::U8(75),
+            MoveValue::U64(0),
+        ])
+        .to_bcs();
+
+        let table: SmartTable<AccountAddress, u64> = aptos_bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            table.buckets.inner.handle,
+            TableHandle(AccountAddress::from_str("0xa").unwrap())
+        );
+        assert_eq!(table.num_buckets, 2);
+        assert_eq!(table.size, 5);
+        assert_eq!(aptos_bcs::to_bytes(&table).unwrap(), bytes);
+        assert_eq!(
+            SmartTable::<AccountAddress, u64>::struct_tag(),
+            StructTag::from_str("0x1::smart_table::SmartTable<address, u64>").unwrap()
+        );
+
+        let bucket = MoveValue::Vector(vec![MoveValue::Struct(vec![
+            MoveValue::U64(7),
+            handle("0xb"),
+            MoveValue::U64(100),
+        ])])
+        .to_bcs();
+        let bucket: Vec<SmartTableEntry<AccountAddress, u64>> =
+            aptos_bcs::from_bytes(&bucket).unwrap();
+        assert_eq!(bucket[0].key, AccountAddress::from_str("0xb").unwrap());
+        assert_eq!(bucket[0].value, 100);
+    }
+
+    #[test]
+    fn test_big_ordered_map_layout() {
+        // Enum values are encoded as their variant index followed by their fields
+        let leaf = |entries: Vec<(u64, u64)>, next: u64| {
+            let mut bytes = vec![0, 1, 0, entries.len() as u8];
+            for (key, value) in entries {
+                bytes.extend(MoveValue::U64(key).to_bcs());
+                bytes.push(1);
+                bytes.extend(MoveValue::U64(value).to_bcs());
+            }
+            bytes.extend(MoveValue::U64(0).to_bcs());
+            bytes.extend(MoveValue::U64(next).to_bcs());
+            bytes
+        };
+
+        let mut bytes = vec![0];
+        bytes.extend(leaf(vec![(1, 10), (2, 20)], 0));
+        bytes.push(0);
+        bytes.extend(MoveValue::Vector(vec![handle("0xc")]).to_bcs());
+        bytes.extend(MoveValue::U64(2).to_bcs());
+        bytes.extend(MoveValue::Bool(false).to_bcs());
+        bytes.extend(MoveValue::U64(0).to_bcs());
+        bytes.extend(MoveValue::U32(0).to_bcs());
+        bytes.extend(MoveValue::U64(1).to_bcs());
+        bytes.extend(MoveValue::U64(1).to_bcs());
+        bytes.extend(MoveValue::Bool(true).to_bcs());
+        bytes.extend(MoveValue::U16(64).to_bcs());
+        bytes.extend(MoveValue::U16(64).to_bcs());
+
+        let map: BigOrderedMap<u64, u64> = aptos_bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(map.min_leaf_index(), BIG_ORDERED_MAP_ROOT_INDEX);
+        assert_eq!(
+            map.nodes().unwrap().handle,
+            TableHandle(AccountAddress::from_str("0xc").unwrap())
+        );
+        assert!(map.root().is_leaf());
+        assert_eq!(map.root().next(), None);
+        assert_eq!(
+            map.root().clone().into_leaf_entries().collect::<Vec<_>>(),
+            vec![(1, 10), (2, 20)]
+        );
+        assert_eq!(aptos_bcs::to_bytes(&map).unwrap(), bytes);
+
+        let slot: StorageSlot<BigOrderedMapNode<u64, u64>> =
+            aptos_bcs::from_bytes(&[vec![0], leaf(vec![(3, 30)], 4)].concat()).unwrap();
+        let StorageSlot::Some { value: node } = slot else {
+            panic!("Expected a node");
+        };
+        assert_eq!(node.next(), Some(4));
+    }
+}
@@ -1,15 +1,17 @@
 use std::fmt;
 
 use crate::api_types::chain_id::ChainId;
-use crate::api_types::event::ContractEvent;
+use crate::api_types::event::{ContractEvent, Event};
 use crate::api_types::hash::HashValue;
 use crate::api_types::module_id::ModuleId;
+use crate::api_types::move_module::MoveModule;
+use crate::api_types::move_value::MoveValue;
 use crate::api_types::numbers::U64;
 use crate::api_types::transaction_authenticator::TransactionAuthenticator;
 use crate::api_types::type_tag::TypeTag;
 use crate::api_types::write_set::WriteSet;
 use crate::api_types::{address::AccountAddress, u256};
-use anyhow::Error;
+use anyhow::{anyhow, ensure, Error};
 use aptos_crypto::compat::Sha3_256;
 use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
 use ed25519_dalek::Digest;
@@ -101,6 +103,34 @@ pub struct TransactionInfo {
     pub epoch: Option<U64>,
 }
 
+/// A committed user transaction as returned in JSON, e.g. by `/accounts/{address}/transactions`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommittedUserTransaction {
+    pub version: U64,
+    pub hash: HashValue,
+    pub state_change_hash: HashValue,
+    pub event_root_hash: HashValue,
+    pub state_checkpoint_hash: Option<HashValue>,
+    pub gas_used: U64,
+    /// Whether the transaction was successful
+    pub success: bool,
+    /// The VM status of the transaction, can tell useful information in a failure
+    pub vm_status: String,
+    pub accumulator_root_hash: HashValue,
+    /// Changes made to the state by the transaction
+    pub changes: Vec<serde_json::Value>,
+    pub sender: AccountAddress,
+    pub sequence_number: U64,
+    pub max_gas_amount: U64,
+    pub gas_unit_price: U64,
+    pub expiration_timestamp_secs: U64,
+    pub payload: serde_json::Value,
+    pub signature: Option<serde_json::Value>,
+    pub events: Vec<Event>,
+    /// The timestamp of the block the transaction was committed in, in microseconds
+    pub timestamp: U64,
+}
+
 /// RawTransaction is the portion of a transaction that a client signs.
 #[derive(
     Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize, CryptoHasher, BCSCryptoHash,
@@ -145,6 +175,127 @@ pub enum TransactionPayload {
     /// A multisig transaction that allows an owner of a multisig account to execute a pre-approved
     /// transaction as the multisig account.
     Multisig(Multisig),
+    /// A versioned payload with extra configuration, e.g. a replay protection nonce for
+    /// orderless transactions.
+    Payload(TransactionPayloadInner),
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionPayloadInner {
+    V1 {
+        executable: TransactionExecutable,
+        extra_config: TransactionExtraConfig,
+    },
+}
+
+/// The code a [`TransactionPayloadInner`] executes
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionExecutable {
+    Script(Script),
+    EntryFunction(EntryFunction),
+    /// Nothing to execute, e.g. for a multisig transaction whose payload is stored on chain
+    Empty,
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionExtraConfig {
+    V1 {
+        /// Execute the payload as this multisig account
+        multisig_address: Option<AccountAddress>,
+        /// Makes the transaction orderless, using the nonce instead of the sequence number for
+        /// replay protection
+        replay_protection_nonce: Option<u64>,
+    },
+}
+
+impl TransactionPayload {
+    /// The entry function the payload calls, if any
+    pub fn entry_function(&self) -> Option<&EntryFunction> {
+        match self {
+            Self::EntryFunction(entry_function) => Some(entry_function),
+            Self::Multisig(Multisig {
+                transaction_payload: Some(MultisigTransactionPayload::EntryFunction(entry_function)),
+                ..
+            }) => Some(entry_function),
+            Self::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::EntryFunction(entry_function),
+                ..
+            }) => Some(entry_function),
+            _ => None,
+        }
+    }
+
+    /// The multisig account the payload is executed as, if any
+    pub fn multisig_address(&self) -> Option<AccountAddress> {
+        match self {
+            Self::Multisig(multisig) => Some(multisig.multisig_address),
+            Self::Payload(TransactionPayloadInner::V1 {
+                extra_config:
+                    TransactionExtraConfig::V1 {
+                        multisig_address, ..
+                    },
+                ..
+            }) => *multisig_address,
+            _ => None,
+        }
+    }
+
+    /// The nonce of an orderless transaction
+    pub fn replay_protection_nonce(&self) -> Option<u64> {
+        match self {
+            Self::Payload(TransactionPayloadInner::V1 {
+                extra_config:
+                    TransactionExtraConfig::V1 {
+                        replay_protection_nonce,
+                        ..
+                    },
+                ..
+            }) => *replay_protection_nonce,
+            _ => None,
+        }
+    }
+
+    /// Convert the payload to the [`TransactionPayload::Payload`] form with a replay protection
+    /// nonce, making it orderless
+    pub fn with_replay_protection_nonce(self, nonce: u64) -> Result<Self, Error> {
+        let (executable, multisig_address) = match self {
+            Self::Script(script) => (TransactionExecutable::Script(script), None),
+            Self::EntryFunction(entry_function) => {
+                (TransactionExecutable::EntryFunction(entry_function), None)
+            }
+            Self::Multisig(Multisig {
+                multisig_address,
+                transaction_payload,
+            }) => (
+                match transaction_payload {
+                    Some(MultisigTransactionPayload::EntryFunction(entry_function)) => {
+                        TransactionExecutable::EntryFunction(entry_function)
+                    }
+                    None => TransactionExecutable::Empty,
+                },
+                Some(multisig_address),
+            ),
+            Self::Payload(TransactionPayloadInner::V1 {
+                executable,
+                extra_config:
+                    TransactionExtraConfig::V1 {
+                        multisig_address, ..
+                    },
+            }) => (executable, multisig_address),
+            Self::ModuleBundle => {
+                return Err(anyhow::anyhow!(
+                    "Module bundles are deprecated and can't be orderless"
+                ))
+            }
+        };
+        Ok(Self::Payload(TransactionPayloadInner::V1 {
+            executable,
+            extra_config: TransactionExtraConfig::V1 {
+                multisig_address,
+                replay_protection_nonce: Some(nonce),
+            },
+        }))
+    }
 }
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -250,6 +401,28 @@ impl RawTransactionWithData {
             Self::MultiAgentWithFeePayer { raw_txn, .. } => raw_txn,
         }
     }
+
+    pub fn secondary_signer_addresses(&self) -> &[AccountAddress] {
+        match self {
+            Self::MultiAgent {
+                secondary_signer_addresses,
+                ..
+            } => secondary_signer_addresses,
+            Self::MultiAgentWithFeePayer {
+                secondary_signer_addresses,
+                ..
+            } => secondary_signer_addresses,
+        }
+    }
+
+    pub fn fee_payer_address(&self) -> Option<AccountAddress> {
+        match self {
+            Self::MultiAgent { .. } => None,
+            Self::MultiAgentWithFeePayer {
+                fee_payer_address, ..
+            } => Some(*fee_payer_address),
+        }
+    }
 }
 
 impl GenerateSigningMessage for RawTransactionWithData {
@@ -287,9 +460,28 @@ impl SignedTransaction {
         aptos_bcs::serialize_into(&mut bytes, self).unwrap();
         bytes
     }
+
+    /// The hash the transaction will have once committed, as a 0x-prefixed hex string.  This is
+    /// the hash of the on-chain `Transaction::UserTransaction` wrapping it.
+    pub fn committed_hash(&self) -> String {
+        let mut sha3 = Sha3_256::new();
+        sha3.update("APTOS::Transaction".as_bytes());
+        let prefix = sha3.finalize();
+
+        let mut sha3 = Sha3_256::new();
+        sha3.update(prefix);
+        // `UserTransaction` is the first variant of the on-chain `Transaction` enum
+        sha3.update([0u8]);
+        sha3.update(self.to_vec());
+        format!("0x{}", hex::encode(sha3.finalize()))
+    }
 }
 
 impl RawTransaction {
+    /// The sequence number orderless transactions carry, matching the other Aptos SDKs.  It's
+    /// ignored on chain in favor of the replay protection nonce.
+    pub const ORDERLESS_SEQUENCE_NUMBER: u64 = 0xdeadbeef;
+
     pub fn new(
         sender: AccountAddress,
         sequence_number: u64,
@@ -310,6 +502,61 @@ impl RawTransaction {
         }
     }
 
+    /// Create an orderless transaction, which uses `nonce` for replay protection instead of the
+    /// sender's sequence number
+    pub fn new_orderless(
+        sender: AccountAddress,
+        nonce: u64,
+        payload: TransactionPayload,
+        max_gas_amount: u64,
+        gas_unit_price: u64,
+        expiration_timestamp_secs: u64,
+        chain_id: ChainId,
+    ) -> Result<Self, Error> {
+        Ok(Self::new(
+            sender,
+            Self::ORDERLESS_SEQUENCE_NUMBER,
+            payload.with_replay_protection_nonce(nonce)?,
+            max_gas_amount,
+            gas_unit_price,
+            expiration_timestamp_secs,
+            chain_id,
+        ))
+    }
+
+    pub fn sender(&self) -> AccountAddress {
+        self.sender
+    }
+
+    /// The nonce of an orderless transaction, `None` for a sequence number based one
+    pub fn replay_protection_nonce(&self) -> Option<u64> {
+        self.payload.replay_protection_nonce()
+    }
+
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    pub fn payload(&self) -> &TransactionPayload {
+        &self.payload
+    }
+
+    pub fn max_gas_amount(&self) -> u64 {
+        self.max_gas_amount
+    }
+
+    pub fn gas_unit_price(&self) -> u64 {
+        self.gas_unit_price
+    }
+
+    pub fn expiration_timestamp_secs(&self) -> u64 {
+        self.expiration_timestamp_secs
+    }
+
+    pub fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
     pub fn to_vec(&self) -> Vec<u8> {
         let mut bytes = vec![];
         aptos_bcs::serialize_into(&mut bytes, self).unwrap();
@@ -331,6 +578,20 @@ impl GenerateSigningMessage for RawTransaction {
     }
 }
 
+impl Script {
+    pub fn code(&self) -> &[u8] {
+        &self.code
+    }
+
+    pub fn ty_args(&self) -> &[TypeTag] {
+        &self.ty_args
+    }
+
+    pub fn args(&self) -> &[TransactionArgument] {
+        &self.args
+    }
+}
+
 impl EntryFunction {
     pub fn new(
         module: ModuleId,
@@ -345,4 +606,128 @@ impl EntryFunction {
             args,
         }
     }
+
+    pub fn module(&self) -> &ModuleId {
+        &self.module
+    }
+
+    pub fn function(&self) -> &str {
+        &self.function
+    }
+
+    pub fn ty_args(&self) -> &[TypeTag] {
+        &self.ty_args
+    }
+
+    /// The BCS encoded arguments
+    pub fn args(&self) -> &[Vec<u8>] {
+        &self.args
+    }
+
+    /// An entry function call with arguments encoded from Move values
+    pub fn from_values(
+        module: ModuleId,
+        function: String,
+        ty_args: Vec<TypeTag>,
+        args: Vec<MoveValue>,
+    ) -> Self {
+        Self::new(
+            module,
+            function,
+            ty_args,
+            args.iter().map(MoveValue::to_bcs).collect(),
+        )
+    }
+
+    /// An entry function call with JSON arguments, which are checked and encoded using the
+    /// function's signature in the module ABI.  See [`MoveValue::from_json`] for the accepted
+    /// JSON formats.
+    pub fn from_json_args(
+        abi: &MoveModule,
+        function: &str,
+        ty_args: Vec<TypeTag>,
+        json_args: &[serde_json::Value],
+    ) -> Result<Self, Error> {
+        let move_function = abi
+            .function(function)
+            .ok_or_else(|| anyhow!("{}::{} has no function {}", abi.address, abi.name, function))?;
+        ensure!(
+            move_function.is_entry,
+            "{}::{}::{} is not an entry function",
+            abi.address,
+            abi.name,
+            function
+        );
+        let arg_types = move_function.argument_types(&ty_args)?;
+        ensure!(
+            arg_types.len() == json_args.len(),
+            "{} expects {} arguments, got {}",
+            function,
+            arg_types.len(),
+            json_args.len()
+        );
+        let args = arg_types
+            .iter()
+            .zip(json_args)
+            .enumerate()
+            .map(|(i, (arg_type, json_arg))| {
+                MoveValue::from_json(arg_type, json_arg).map_err(|err| {
+                    anyhow!(
+                        "Argument {} ({}): {}",
+                        i,
+                        arg_type.to_canonical_string(),
+                        err
+                    )
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok(Self::from_values(
+            ModuleId::new(abi.address, abi.name.clone()),
+            function.to_string(),
+            ty_args,
+            args,
+        ))
+    }
+}
+
+/// A view function call, which is submitted as BCS to the view endpoint
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ViewFunction {
+    module: ModuleId,
+    function: String,
+    ty_args: Vec<TypeTag>,
+    args: Vec<Vec<u8>>,
+}
+
+impl ViewFunction {
+    pub fn new(
+        module: ModuleId,
+        function: String,
+        ty_args: Vec<TypeTag>,
+        args: Vec<MoveValue>,
+    ) -> Self {
+        Self {
+            module,
+            function,
+            ty_args,
+            args: args.iter().map(MoveValue::to_bcs).collect(),
+        }
+    }
+
+    pub fn module(&self) -> &ModuleId {
+        &self.module
+    }
+
+    pub fn function(&self) -> &str {
+        &self.function
+    }
+
+    pub fn ty_args(&self) -> &[TypeTag] {
+        &self.ty_args
+    }
+
+    /// The BCS encoded arguments
+    pub fn args(&self) -> &[Vec<u8>] {
+        &self.args
+    }
 }
@@ -6,7 +6,7 @@ use crate::api_types::address::AccountAddress;
 use crate::api_types::transaction::RawTransaction;
 use anyhow::ensure;
 use aptos_crypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
-use aptos_crypto::HashValue;
+use aptos_crypto::{HashValue, Signature};
 use rand::rngs::OsRng;
 use rand::RngCore;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -501,6 +501,29 @@ impl AccountAuthenticator {
         }
     }
 
+    /// Return Ok if the authenticator's signature is valid for the raw signing `message`, Err
+    /// otherwise
+    pub fn verify_arbitrary_msg(&self, message: &[u8]) -> Result<(), anyhow::Error> {
+        match self {
+            Self::Ed25519 {
+                public_key,
+                signature,
+            } => signature.verify_arbitrary_msg(message, public_key),
+            Self::SingleKey { authenticator } => {
+                match (authenticator.public_key(), authenticator.signature()) {
+                    (AnyPublicKey::Ed25519 { public_key }, AnySignature::Ed25519 { signature }) => {
+                        signature.verify_arbitrary_msg(message, public_key)
+                    }
+                }
+            }
+            Self::MultiEd25519 { .. } | Self::MultiKey { .. } => Err(anyhow::anyhow!(
+                "Verification is not supported for {}",
+                self.scheme()
+            )),
+            Self::NoAuthenticator {} => Err(anyhow::anyhow!("No authenticator, no signature.")),
+        }
+    }
+
     /// Return the raw bytes of `self.public_key`
     pub fn public_key_bytes(&self) -> Vec<u8> {
         match self {
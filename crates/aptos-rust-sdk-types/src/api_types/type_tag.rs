@@ -179,3 +179,20 @@ impl FromStr for StructTag {
         parser::parse_struct_tag(s)
     }
 }
+
+/// (De)serializes a `TypeTag` as a string, e.g. `vector<u8>`, as the JSON API does
+pub mod type_tag_string {
+    use super::TypeTag;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(type_tag: &TypeTag, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&type_tag.to_canonical_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TypeTag, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        TypeTag::from_str(&string).map_err(D::Error::custom)
+    }
+}
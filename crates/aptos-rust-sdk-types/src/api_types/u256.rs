@@ -15,7 +15,7 @@ use rand::{
     },
     Rng,
 };
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
     mem::size_of,
@@ -129,9 +129,15 @@ impl<'de> Deserialize<'de> for U256 {
     where
         D: Deserializer<'de>,
     {
-        Ok(U256::from_le_bytes(
-            &(<[u8; U256_NUM_BYTES]>::deserialize(deserializer)?),
-        ))
+        if deserializer.is_human_readable() {
+            // The API encodes a `u256` as a decimal string
+            let s = <String>::deserialize(deserializer)?;
+            s.parse().map_err(D::Error::custom)
+        } else {
+            Ok(U256::from_le_bytes(
+                &(<[u8; U256_NUM_BYTES]>::deserialize(deserializer)?),
+            ))
+        }
     }
 }
 
@@ -140,7 +146,11 @@ impl Serialize for U256 {
     where
         S: Serializer,
     {
-        self.to_le_bytes().serialize(serializer)
+        if serializer.is_human_readable() {
+            self.to_string().serialize(serializer)
+        } else {
+            self.to_le_bytes().serialize(serializer)
+        }
     }
 }
 
@@ -652,3 +662,17 @@ fn wrapping_add() {
 
     assert!(a.wrapping_add(b) == U256::from(99u8));
 }
+
+#[test]
+fn serde_formats() {
+    // JSON uses the decimal string the API returns, and BCS the 32 little endian bytes
+    let value = U256::from(258u32);
+    let json = serde_json::to_value(value).unwrap();
+    assert_eq!(json, serde_json::json!("258"));
+    assert_eq!(serde_json::from_value::<U256>(json).unwrap(), value);
+    assert!(serde_json::from_value::<U256>(serde_json::json!("0x102")).is_err());
+
+    let bytes = aptos_bcs::to_bytes(&value).unwrap();
+    assert_eq!(bytes, value.to_le_bytes());
+    assert_eq!(aptos_bcs::from_bytes::<U256>(&bytes).unwrap(), value);
+}
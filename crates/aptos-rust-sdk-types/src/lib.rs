@@ -1,3 +1,6 @@
+// Lets `#[derive(MoveStruct)]` refer to this crate by name from within it
+extern crate self as aptos_rust_sdk_types;
+
 pub mod api_types;
 // Replaced with other crypto
 //pub mod crypto;
@@ -0,0 +1,223 @@
+use crate::account::account_key::AccountKey;
+use crate::account::signer::LocalAccount;
+use crate::client::config::AptosNetwork;
+use anyhow::{anyhow, Context};
+use aptos_crypto::ed25519::Ed25519PrivateKey;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use url::Url;
+
+/// The folder the Aptos CLI keeps its configuration in
+pub const CONFIG_FOLDER: &str = ".aptos";
+/// The name of the Aptos CLI configuration file
+pub const CONFIG_FILE: &str = "config.yaml";
+/// The profile used by the Aptos CLI when none is given
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// AIP-80 prefix for Ed25519 private keys
+const ED25519_PRIVATE_KEY_PREFIX: &str = "ed25519-priv-";
+
+/// The profiles of an Aptos CLI `.aptos/config.yaml`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CliConfig {
+    #[serde(default)]
+    profiles: BTreeMap<String, CliProfile>,
+}
+
+impl CliConfig {
+    /// Load the config the Aptos CLI would use from the current working directory
+    pub fn load() -> anyhow::Result<Self> {
+        let current_dir = std::env::current_dir()?;
+        Self::load_from(&current_dir)
+    }
+
+    /// Load the config the Aptos CLI would use from `dir`.
+    ///
+    /// Searches `dir` and its parents for a workspace config first, and falls back to the global
+    /// config in the home directory.
+    pub fn load_from(dir: &Path) -> anyhow::Result<Self> {
+        let path = Self::find_config_file(dir)
+            .ok_or_else(|| anyhow!("No Aptos CLI config found from {}", dir.display()))?;
+        Self::from_file(&path)
+    }
+
+    /// Find the config file the Aptos CLI would use from `dir`
+    pub fn find_config_file(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|ancestor| ancestor.join(CONFIG_FOLDER).join(CONFIG_FILE))
+            .find(|path| path.is_file())
+            .or_else(|| Self::global_config_file().filter(|path| path.is_file()))
+    }
+
+    /// The location of the global config in the home directory
+    pub fn global_config_file() -> Option<PathBuf> {
+        std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(|home| PathBuf::from(home).join(CONFIG_FOLDER).join(CONFIG_FILE))
+    }
+
+    /// Load a config from a specific file
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read Aptos CLI config {}", path.display()))?;
+        contents
+            .parse()
+            .with_context(|| format!("Failed to parse Aptos CLI config {}", path.display()))
+    }
+
+    /// Retrieve a profile by name
+    pub fn profile(&self, name: &str) -> Option<&CliProfile> {
+        self.profiles.get(name)
+    }
+
+    /// Retrieve the profile used when none is specified
+    pub fn default_profile(&self) -> Option<&CliProfile> {
+        self.profile(DEFAULT_PROFILE)
+    }
+
+    /// All profiles by name
+    pub fn profiles(&self) -> &BTreeMap<String, CliProfile> {
+        &self.profiles
+    }
+}
+
+impl FromStr for CliConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(serde_yaml::from_str(s)?)
+    }
+}
+
+/// The network a profile was initialized for
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize)]
+pub enum CliNetwork {
+    Mainnet,
+    Testnet,
+    Devnet,
+    Local,
+    Custom,
+}
+
+/// A single named profile of the Aptos CLI
+#[derive(Clone, Default, Deserialize)]
+pub struct CliProfile {
+    network: Option<CliNetwork>,
+    private_key: Option<String>,
+    public_key: Option<String>,
+    account: Option<AccountAddress>,
+    rest_url: Option<String>,
+    faucet_url: Option<String>,
+}
+
+impl CliProfile {
+    pub fn cli_network(&self) -> Option<CliNetwork> {
+        self.network
+    }
+
+    pub fn account(&self) -> Option<AccountAddress> {
+        self.account
+    }
+
+    pub fn public_key(&self) -> Option<&str> {
+        self.public_key.as_deref()
+    }
+
+    /// The REST URL of the profile, without the `/v1` suffix the CLI may store
+    pub fn rest_url(&self) -> anyhow::Result<Option<Url>> {
+        let Some(rest_url) = &self.rest_url else {
+            return Ok(None);
+        };
+        let trimmed = rest_url.trim_end_matches('/');
+        let trimmed = trimmed.strip_suffix("/v1").unwrap_or(trimmed);
+        Ok(Some(Url::parse(trimmed)?))
+    }
+
+    pub fn faucet_url(&self) -> anyhow::Result<Option<Url>> {
+        Ok(self.faucet_url.as_deref().map(Url::parse).transpose()?)
+    }
+
+    /// The network for the profile, using the profile's REST URL in place of the default.
+    ///
+    /// There's no known indexer for a custom network, so use [`Self::network_with_indexer_url`]
+    /// for those instead.
+    pub fn network(&self) -> anyhow::Result<AptosNetwork> {
+        let network = self.known_network().ok_or_else(|| {
+            anyhow!("Profile has a custom network, whose indexer URL must be given explicitly")
+        })?;
+        self.network_with_indexer_url(network.indexer_url().clone())
+    }
+
+    /// The network for the profile, using the profile's REST URL in place of the default, and
+    /// `indexer_url` as the indexer
+    pub fn network_with_indexer_url(&self, indexer_url: Url) -> anyhow::Result<AptosNetwork> {
+        let rest_url = self.rest_url()?;
+        let (name, rest_url) = match self.known_network() {
+            Some(network) => (
+                network.name(),
+                rest_url.unwrap_or_else(|| network.rest_url().clone()),
+            ),
+            None => (
+                "custom",
+                rest_url.ok_or_else(|| anyhow!("Profile has no REST URL"))?,
+            ),
+        };
+        Ok(AptosNetwork::new(name, rest_url, indexer_url))
+    }
+
+    /// The default configuration of the profile's network, unless it's a custom one
+    fn known_network(&self) -> Option<AptosNetwork> {
+        match self.network {
+            Some(CliNetwork::Mainnet) => Some(AptosNetwork::mainnet()),
+            Some(CliNetwork::Testnet) => Some(AptosNetwork::testnet()),
+            Some(CliNetwork::Devnet) => Some(AptosNetwork::devnet()),
+            Some(CliNetwork::Local) => Some(AptosNetwork::localnet()),
+            Some(CliNetwork::Custom) | None => None,
+        }
+    }
+
+    /// The private key of the profile, if it has one stored in the config
+    pub fn private_key(&self) -> anyhow::Result<Ed25519PrivateKey> {
+        let private_key = self
+            .private_key
+            .as_deref()
+            .ok_or_else(|| anyhow!("Profile has no private key"))?;
+        let hex_key = match private_key.split_once("-priv-") {
+            Some(_) => private_key
+                .strip_prefix(ED25519_PRIVATE_KEY_PREFIX)
+                .ok_or_else(|| anyhow!("Only Ed25519 private keys are supported"))?,
+            None => private_key,
+        };
+        let bytes = hex::decode(hex_key.strip_prefix("0x").unwrap_or(hex_key))?;
+        Ok(Ed25519PrivateKey::try_from(bytes.as_slice())?)
+    }
+
+    /// A signer for the profile's account
+    pub fn signer(&self) -> anyhow::Result<LocalAccount> {
+        let key = AccountKey::from_private_key(self.private_key()?);
+        Ok(match self.account {
+            Some(address) => LocalAccount::new(address, key),
+            None => LocalAccount::from(key),
+        })
+    }
+}
+
+impl fmt::Debug for CliProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CliProfile")
+            .field("network", &self.network)
+            .field(
+                "private_key",
+                &self.private_key.as_ref().map(|_| "REDACTED_PRIVATE_KEY"),
+            )
+            .field("public_key", &self.public_key)
+            .field("account", &self.account)
+            .field("rest_url", &self.rest_url)
+            .field("faucet_url", &self.faucet_url)
+            .finish()
+    }
+}
@@ -1 +1,3 @@
 pub mod account_key;
+pub mod cli_config;
+pub mod signer;
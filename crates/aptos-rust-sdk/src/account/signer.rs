@@ -0,0 +1,86 @@
+use crate::account::account_key::AccountKey;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::{
+    GenerateSigningMessage, RawTransaction, SignedTransaction,
+};
+use aptos_rust_sdk_types::api_types::transaction_authenticator::{
+    AccountAuthenticator, TransactionAuthenticator,
+};
+
+/// Anything that can sign transactions on behalf of an account
+pub trait Signer {
+    /// The address of the account being signed for
+    fn address(&self) -> AccountAddress;
+
+    /// Sign an arbitrary message, returning the authenticator for the account
+    fn sign_message(&self, message: &[u8]) -> AccountAuthenticator;
+
+    /// Sign a single sender transaction
+    fn sign_transaction(&self, raw_txn: RawTransaction) -> anyhow::Result<SignedTransaction> {
+        anyhow::ensure!(
+            raw_txn.sender() == self.address(),
+            "Transaction sender {} does not match signer {}",
+            raw_txn.sender(),
+            self.address()
+        );
+        let message = raw_txn.generate_signing_message()?;
+        let authenticator = match self.sign_message(&message) {
+            AccountAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => TransactionAuthenticator::ed25519(public_key, signature),
+            authenticator => TransactionAuthenticator::single_sender(authenticator),
+        };
+        Ok(SignedTransaction::new(raw_txn, authenticator))
+    }
+}
+
+impl Signer for AccountKey {
+    /// The address derived from the key, this is only correct if the key was never rotated
+    fn address(&self) -> AccountAddress {
+        self.authentication_key().account_address()
+    }
+
+    fn sign_message(&self, message: &[u8]) -> AccountAuthenticator {
+        AccountAuthenticator::ed25519(
+            self.public_key().clone(),
+            self.private_key().sign_message(message),
+        )
+    }
+}
+
+/// An account with its private key held locally
+///
+/// Unlike an [`AccountKey`] on its own, the address is stored explicitly, which allows for
+/// accounts that have had their key rotated.
+#[derive(Debug)]
+pub struct LocalAccount {
+    address: AccountAddress,
+    key: AccountKey,
+}
+
+impl LocalAccount {
+    pub fn new(address: AccountAddress, key: AccountKey) -> Self {
+        Self { address, key }
+    }
+
+    pub fn key(&self) -> &AccountKey {
+        &self.key
+    }
+}
+
+impl From<AccountKey> for LocalAccount {
+    fn from(key: AccountKey) -> Self {
+        Self::new(key.address(), key)
+    }
+}
+
+impl Signer for LocalAccount {
+    fn address(&self) -> AccountAddress {
+        self.address
+    }
+
+    fn sign_message(&self, message: &[u8]) -> AccountAuthenticator {
+        self.key.sign_message(message)
+    }
+}
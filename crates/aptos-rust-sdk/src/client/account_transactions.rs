@@ -0,0 +1,120 @@
+use crate::client::response::FullnodeResponse;
+use crate::client::rest_api::{with_query_params, AptosFullnodeClient};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::CommittedUserTransaction;
+use aptos_rust_sdk_types::AptosResult;
+use futures_util::stream::{self, Stream, TryStreamExt};
+
+/// A page of the transactions sent by an account, as of a ledger version
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountTransactionsPage {
+    pub transactions: Vec<CommittedUserTransaction>,
+    /// The ledger version the page is pinned to.  Transactions committed after it are left out.
+    pub ledger_version: u64,
+    /// The sequence number to start the next page from, if there may be more transactions
+    pub next_start: Option<u64>,
+}
+
+impl AptosFullnodeClient {
+    /// The committed transactions sent by an account, in sequence number order.  `start` is the
+    /// sequence number of the first transaction, and defaults to the latest transactions.
+    ///
+    /// The response's state holds the ledger version the transactions were read at, along with
+    /// the cursor the node returned, if any.
+    pub async fn get_account_transactions(
+        &self,
+        address: AccountAddress,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> AptosResult<FullnodeResponse<Vec<CommittedUserTransaction>>> {
+        let url = self.build_rest_path(&format!("v1/accounts/{}/transactions", address))?;
+        let url = with_query_params(url, &[("start", start), ("limit", limit.map(u64::from))]);
+        self.rest_get(url).await
+    }
+
+    /// A page of the transactions sent by an account, from sequence number `start`.  The page is
+    /// pinned to `ledger_version` if given, and otherwise to the ledger version it is read at;
+    /// pass the page's `ledger_version` back to read the next page as of the same version.
+    pub async fn get_account_transactions_page(
+        &self,
+        address: AccountAddress,
+        start: u64,
+        limit: u16,
+        ledger_version: Option<u64>,
+    ) -> AptosResult<FullnodeResponse<AccountTransactionsPage>> {
+        let response = self
+            .get_account_transactions(address, Some(start), Some(limit))
+            .await?;
+        let ledger_version = ledger_version.unwrap_or(response.state().version);
+        Ok(response.map(|transactions| pin_page(transactions, ledger_version, limit)))
+    }
+
+    /// Streams the transactions sent by an account from sequence number `start`, reading pages
+    /// of `page_size` transactions.  All the pages are pinned to the ledger version of the first
+    /// one, so the stream is a consistent view of the account's history and ends once it's read.
+    pub fn stream_account_transactions(
+        &self,
+        address: AccountAddress,
+        start: u64,
+        page_size: u16,
+    ) -> impl Stream<Item = AptosResult<CommittedUserTransaction>> {
+        let client = self.clone();
+        stream::try_unfold((Some(start), None), move |(start, ledger_version)| {
+            let client = client.clone();
+            async move {
+                let Some(start) = start else {
+                    return Ok(None);
+                };
+                let page = client
+                    .get_account_transactions_page(address, start, page_size, ledger_version)
+                    .await?
+                    .into_inner();
+                let next = (page.next_start, Some(page.ledger_version));
+                let transactions = stream::iter(page.transactions.into_iter().map(Ok));
+                AptosResult::Ok(Some((transactions, next)))
+            }
+        })
+        .try_flatten()
+    }
+
+    /// The committed transaction sent by an account with a sequence number, if there is one
+    pub async fn get_account_transaction_by_sequence_number(
+        &self,
+        address: AccountAddress,
+        sequence_number: u64,
+    ) -> AptosResult<FullnodeResponse<Option<CommittedUserTransaction>>> {
+        let response = self
+            .get_account_transactions(address, Some(sequence_number), Some(1))
+            .await?;
+        Ok(response.map(|transactions| {
+            transactions
+                .into_iter()
+                .find(|transaction| transaction.sequence_number.inner() == sequence_number)
+        }))
+    }
+}
+
+/// Pins a page of an account's transactions to a ledger version, leaving out the transactions
+/// committed after it.  There's no next page once one of them is left out, or the page is short.
+pub(crate) fn pin_page(
+    transactions: Vec<CommittedUserTransaction>,
+    ledger_version: u64,
+    limit: u16,
+) -> AccountTransactionsPage {
+    let returned = transactions.len();
+    let transactions: Vec<_> = transactions
+        .into_iter()
+        .take_while(|transaction| transaction.version.inner() <= ledger_version)
+        .collect();
+    let next_start = match transactions.last() {
+        Some(last) if transactions.len() == returned && returned >= limit as usize => {
+            Some(last.sequence_number.inner() + 1)
+        }
+        _ => None,
+    };
+    AccountTransactionsPage {
+        transactions,
+        ledger_version,
+        next_start,
+    }
+}
@@ -0,0 +1,179 @@
+use crate::client::rest_api::AptosFullnodeClient;
+use anyhow::{anyhow, Context};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::amount::Amount;
+use aptos_rust_sdk_types::api_types::asset::{Asset, AssetMetadata};
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use aptos_rust_sdk_types::error::{AptosErrorCode, RestError};
+use aptos_rust_sdk_types::AptosResult;
+use serde_json::json;
+use std::str::FromStr;
+
+const FUNGIBLE_ASSET_METADATA: &str = "0x1::fungible_asset::Metadata";
+
+impl AptosFullnodeClient {
+    /// The balance of an asset held by an account.
+    ///
+    /// A coin's balance is the sum of its `CoinStore` and the primary fungible store of its
+    /// paired fungible asset, and the same goes for a fungible asset paired with a coin.  The
+    /// amount has the asset's decimals and symbol.
+    pub async fn balance(&self, address: AccountAddress, asset: &Asset) -> AptosResult<Amount> {
+        let metadata = self.asset_metadata(asset).await?;
+        let (coin_type, fungible_asset) = match asset {
+            Asset::Coin(coin_type) => (
+                Some(coin_type.clone()),
+                self.paired_fungible_asset(coin_type).await?,
+            ),
+            Asset::FungibleAsset(metadata) => (self.paired_coin(*metadata).await?, Some(*metadata)),
+        };
+
+        let mut value = 0u128;
+        if let Some(coin_type) = coin_type {
+            value += u128::from(self.coin_store_balance(address, &coin_type).await?);
+        }
+        if let Some(fungible_asset) = fungible_asset {
+            value += u128::from(
+                self.primary_fungible_store_balance(address, fungible_asset)
+                    .await?,
+            );
+        }
+        Ok(Amount::new(value, metadata.decimals).with_symbol(metadata.symbol))
+    }
+
+    /// The name, symbol, and decimals of an asset
+    pub async fn asset_metadata(&self, asset: &Asset) -> AptosResult<AssetMetadata> {
+        let (address, resource_type) = match asset {
+            Asset::Coin(coin_type) => {
+                let TypeTag::Struct(coin_struct) = coin_type else {
+                    return Err(anyhow!(
+                        "Coin type {} is not a struct",
+                        coin_type.to_canonical_string()
+                    )
+                    .into());
+                };
+                (
+                    coin_struct.address,
+                    format!("0x1::coin::CoinInfo<{}>", coin_type.to_canonical_string()),
+                )
+            }
+            Asset::FungibleAsset(metadata) => (*metadata, FUNGIBLE_ASSET_METADATA.to_string()),
+        };
+        let resource = self
+            .get_account_resource(address.to_string(), resource_type)
+            .await?
+            .into_inner();
+        Ok(serde_json::from_value(resource.data)?)
+    }
+
+    /// The balance in an account's `CoinStore`, which is 0 if it doesn't have one
+    async fn coin_store_balance(
+        &self,
+        address: AccountAddress,
+        coin_type: &TypeTag,
+    ) -> AptosResult<u64> {
+        let resource_type = format!("0x1::coin::CoinStore<{}>", coin_type.to_canonical_string());
+        match self
+            .get_account_resource(address.to_string(), resource_type)
+            .await
+        {
+            Ok(resource) => Ok(parse_u64(&resource.inner().data["coin"]["value"])?),
+            Err(RestError::Api(response))
+                if matches!(
+                    response.error.error_code,
+                    AptosErrorCode::ResourceNotFound | AptosErrorCode::AccountNotFound
+                ) =>
+            {
+                Ok(0)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// The balance in an account's primary store for a fungible asset, which is 0 if the store
+    /// doesn't exist
+    async fn primary_fungible_store_balance(
+        &self,
+        address: AccountAddress,
+        metadata: AccountAddress,
+    ) -> AptosResult<u64> {
+        let response = self
+            .get_view_function(
+                "0x1::primary_fungible_store::balance",
+                vec![FUNGIBLE_ASSET_METADATA],
+                vec![json!(address.to_string()), json!(metadata.to_string())],
+            )
+            .await?;
+        Ok(parse_u64(&response.inner()[0])?)
+    }
+
+    /// The fungible asset a coin was migrated to, if any
+    async fn paired_fungible_asset(
+        &self,
+        coin_type: &TypeTag,
+    ) -> AptosResult<Option<AccountAddress>> {
+        let coin_type = coin_type.to_canonical_string();
+        let response = self
+            .get_view_function("0x1::coin::paired_metadata", vec![&coin_type], vec![])
+            .await?;
+        let Some(metadata) = move_option(&response.inner()[0])? else {
+            return Ok(None);
+        };
+        let address = metadata["inner"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Malformed object {}", metadata))?;
+        Ok(Some(
+            AccountAddress::from_str(address).map_err(anyhow::Error::from)?,
+        ))
+    }
+
+    /// The coin a fungible asset is paired with, if any
+    async fn paired_coin(&self, metadata: AccountAddress) -> AptosResult<Option<TypeTag>> {
+        let response = self
+            .get_view_function(
+                "0x1::coin::paired_coin",
+                vec![],
+                vec![json!(metadata.to_string())],
+            )
+            .await?;
+        match move_option(&response.inner()[0])? {
+            Some(type_info) => Ok(Some(type_info_to_type_tag(type_info)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The value inside a Move `Option` returned as JSON, e.g. `{"vec": [value]}`
+pub(crate) fn move_option(value: &serde_json::Value) -> anyhow::Result<Option<&serde_json::Value>> {
+    let vec = value["vec"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Malformed option {}", value))?;
+    Ok(vec.first())
+}
+
+/// Convert a `0x1::type_info::TypeInfo` returned as JSON, whose names are hex encoded, to a type
+pub(crate) fn type_info_to_type_tag(type_info: &serde_json::Value) -> anyhow::Result<TypeTag> {
+    let name = |field: &str| -> anyhow::Result<String> {
+        let hex_name = type_info[field]
+            .as_str()
+            .ok_or_else(|| anyhow!("Malformed type info {}", type_info))?;
+        let bytes = hex::decode(hex_name.trim_start_matches("0x"))
+            .with_context(|| format!("Malformed {} in type info", field))?;
+        Ok(String::from_utf8(bytes)?)
+    };
+    let address = type_info["account_address"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Malformed type info {}", type_info))?;
+    TypeTag::from_str(&format!(
+        "{}::{}::{}",
+        address,
+        name("module_name")?,
+        name("struct_name")?
+    ))
+}
+
+fn parse_u64(value: &serde_json::Value) -> anyhow::Result<u64> {
+    value
+        .as_str()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| anyhow!("Expected a u64 string, got {}", value))
+}
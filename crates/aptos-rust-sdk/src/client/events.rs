@@ -0,0 +1,109 @@
+use crate::client::response::FullnodeResponse;
+use crate::client::rest_api::{with_query_params, AptosFullnodeClient};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::event::{
+    Event, EventWithVersion, ModuleEventFilter, VersionedEvent,
+};
+use aptos_rust_sdk_types::api_types::numbers::U64;
+use aptos_rust_sdk_types::api_types::type_tag::StructTag;
+use aptos_rust_sdk_types::AptosResult;
+
+impl AptosFullnodeClient {
+    /// Events emitted to an event handle, by the creation number of the handle's GUID.
+    /// `start` is the sequence number of the first event, and defaults to the latest events.
+    pub async fn get_events_by_creation_number(
+        &self,
+        address: AccountAddress,
+        creation_number: u64,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> AptosResult<FullnodeResponse<Vec<EventWithVersion>>> {
+        let url = self.build_rest_path(&format!(
+            "v1/accounts/{}/events/{}",
+            address, creation_number
+        ))?;
+        let url = with_query_params(url, &[("start", start), ("limit", limit.map(u64::from))]);
+        self.rest_get_bcs(url).await
+    }
+
+    /// Events emitted to an event handle, by the resource holding the handle and the handle's
+    /// field, e.g. `0x1::account::Account` and `coin_register_events`
+    pub async fn get_events_by_event_handle(
+        &self,
+        address: AccountAddress,
+        event_handle: &StructTag,
+        field_name: &str,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> AptosResult<FullnodeResponse<Vec<EventWithVersion>>> {
+        let url = self.build_rest_path(&format!(
+            "v1/accounts/{}/events/{}/{}",
+            address,
+            event_handle.to_canonical_string(),
+            field_name
+        ))?;
+        let url = with_query_params(url, &[("start", start), ("limit", limit.map(u64::from))]);
+        self.rest_get_bcs(url).await
+    }
+
+    /// Committed transactions in ledger version order.  `start` is the first version, and
+    /// defaults to the latest transactions.
+    pub async fn get_transactions(
+        &self,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> AptosResult<FullnodeResponse<Vec<serde_json::Value>>> {
+        let url = self.build_rest_path("v1/transactions")?;
+        let url = with_query_params(url, &[("start", start), ("limit", limit.map(u64::from))]);
+        self.rest_get(url).await
+    }
+
+    /// The module events matching a filter, among a page of transactions starting at version
+    /// `start`.  Continue from the version after the last transaction of the page.
+    pub async fn scan_module_events(
+        &self,
+        filter: &ModuleEventFilter,
+        start: u64,
+        limit: u16,
+    ) -> AptosResult<FullnodeResponse<Vec<VersionedEvent>>> {
+        let response = self.get_transactions(Some(start), Some(limit)).await?;
+        Ok(response.and_then(|transactions| filter_module_events(filter, &transactions))?)
+    }
+}
+
+/// The module events matching a filter among transactions returned in JSON, with the versions
+/// of the transactions emitting them
+pub(crate) fn filter_module_events(
+    filter: &ModuleEventFilter,
+    transactions: &[serde_json::Value],
+) -> anyhow::Result<Vec<VersionedEvent>> {
+    Ok(filter_indexed_module_events(filter, transactions)?
+        .into_iter()
+        .map(|(_, event)| event)
+        .collect())
+}
+
+/// Like `filter_module_events`, along with the index of each event among all the events of its
+/// transaction
+pub(crate) fn filter_indexed_module_events(
+    filter: &ModuleEventFilter,
+    transactions: &[serde_json::Value],
+) -> anyhow::Result<Vec<(u64, VersionedEvent)>> {
+    let mut matching = vec![];
+    for transaction in transactions {
+        // Pending transactions have neither a version nor events
+        let (Some(version), Some(events)) = (transaction.get("version"), transaction.get("events"))
+        else {
+            continue;
+        };
+        let version: U64 = serde_json::from_value(version.clone())?;
+        let events: Vec<Event> = serde_json::from_value(events.clone())?;
+        matching.extend(
+            (0..)
+                .zip(events)
+                .filter(|(_, event)| filter.matches_json(event))
+                .map(|(index, event)| (index, VersionedEvent { version, event })),
+        );
+    }
+    Ok(matching)
+}
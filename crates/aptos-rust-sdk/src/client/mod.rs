@@ -1,7 +1,13 @@
 // TODO: Re-export and simplify paths
+pub mod account_transactions;
+pub mod balance;
 pub mod builder;
 pub mod client;
 pub mod config;
+pub mod events;
 pub mod indexer;
+pub mod object;
 pub mod response;
 pub mod rest_api;
+pub mod subscription;
+pub mod table;
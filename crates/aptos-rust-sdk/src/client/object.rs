@@ -0,0 +1,61 @@
+use crate::client::rest_api::AptosFullnodeClient;
+use anyhow::anyhow;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::object::{ObjectCore, ObjectResources, OBJECT_CORE_TYPE};
+use aptos_rust_sdk_types::error::{AptosErrorCode, RestError};
+use aptos_rust_sdk_types::AptosResult;
+use std::collections::BTreeSet;
+
+impl AptosFullnodeClient {
+    /// The `ObjectCore` of an object, failing with [`AptosErrorCode::ResourceNotFound`] if the
+    /// address isn't an object
+    pub async fn get_object_core(&self, address: AccountAddress) -> AptosResult<ObjectCore> {
+        let resource = self
+            .get_account_resource(address.to_string(), OBJECT_CORE_TYPE.to_string())
+            .await?
+            .into_inner();
+        Ok(serde_json::from_value(resource.data)?)
+    }
+
+    /// The direct owner of an object, which may itself be an object
+    pub async fn get_object_owner(&self, address: AccountAddress) -> AptosResult<AccountAddress> {
+        Ok(self.get_object_core(address).await?.owner)
+    }
+
+    /// The account at the top of an object's ownership, following owners that are objects
+    /// themselves
+    pub async fn get_object_root_owner(
+        &self,
+        address: AccountAddress,
+    ) -> AptosResult<AccountAddress> {
+        let mut owner = self.get_object_owner(address).await?;
+        let mut visited = BTreeSet::from([address]);
+        loop {
+            if !visited.insert(owner) {
+                return Err(anyhow!("Object ownership of {} loops at {}", address, owner).into());
+            }
+            match self.get_object_owner(owner).await {
+                Ok(next) => owner = next,
+                // The owner isn't an object, so it's the root
+                Err(RestError::Api(response))
+                    if matches!(
+                        response.error.error_code,
+                        AptosErrorCode::ResourceNotFound | AptosErrorCode::AccountNotFound
+                    ) =>
+                {
+                    return Ok(owner)
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Every resource stored at an object's address, along with its parsed `ObjectCore`
+    pub async fn object_resources(&self, address: AccountAddress) -> AptosResult<ObjectResources> {
+        let resources = self
+            .get_account_resources(address.to_string())
+            .await?
+            .into_inner();
+        Ok(ObjectResources::from_resources(address, resources)?)
+    }
+}
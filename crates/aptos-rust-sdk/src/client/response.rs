@@ -93,6 +93,27 @@ impl ParsableResponse {
         }
     }
 
+    /// Checks the responses headers, and parses the state and a BCS body out of the response
+    pub(crate) async fn parse_bcs_response<T: DeserializeOwned>(
+        self,
+    ) -> AptosResult<FullnodeResponse<T>> {
+        Ok(self
+            .parse_bytes_response()
+            .await?
+            .and_then(|inner| aptos_bcs::from_bytes(&inner))?)
+    }
+
+    /// Checks the responses headers, and parses the state and the raw body out of the response
+    pub(crate) async fn parse_bytes_response(self) -> AptosResult<FullnodeResponse<Vec<u8>>> {
+        if !self.status().is_success() {
+            Err(self.parse_error().await)
+        } else {
+            let state = self.state()?;
+
+            Ok(FullnodeResponse::new(self.0.bytes().await?.to_vec(), state))
+        }
+    }
+
     /// Parses an error if it was an error state
     async fn parse_error(self) -> RestError {
         let status_code = self.status();
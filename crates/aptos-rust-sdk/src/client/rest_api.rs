@@ -1,16 +1,29 @@
 use crate::client::builder::AptosClientBuilder;
 use crate::client::config::AptosNetwork;
 use crate::client::response::{FullnodeResponse, ParsableResponse};
-use aptos_rust_sdk_types::api_types::account::AccountResource;
-use aptos_rust_sdk_types::api_types::transaction::SignedTransaction;
-use aptos_rust_sdk_types::mime_types::{ACCEPT_BCS, BCS_SIGNED_TRANSACTION, JSON};
+use crate::transaction::simulation::SimulationSigners;
+use aptos_rust_sdk_types::api_types::account::{AccountData, AccountResource};
+use aptos_rust_sdk_types::api_types::move_module::MoveModule;
+use aptos_rust_sdk_types::api_types::move_type_tag::MoveStructType;
+use aptos_rust_sdk_types::api_types::move_value::MoveValue;
+use aptos_rust_sdk_types::api_types::transaction::{
+    RawTransaction, SignedTransaction, ViewFunction,
+};
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use aptos_rust_sdk_types::error::{AptosErrorCode, RestError};
+use aptos_rust_sdk_types::mime_types::{
+    ACCEPT_BCS, BCS_SIGNED_TRANSACTION, BCS_VIEW_FUNCTION, JSON,
+};
 use aptos_rust_sdk_types::state::State;
 use aptos_rust_sdk_types::AptosResult;
 use reqwest::header::{ACCEPT, CONTENT_TYPE};
 use reqwest::Client as ReqwestClient;
 use serde::de::DeserializeOwned;
+use std::time::{Duration, Instant};
 use url::Url;
 
+const WAIT_FOR_TRANSACTION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 #[derive(Debug, Clone, Default)]
 pub struct AccountResourcesQuoteConfig {
     /// The ledger version to query at
@@ -102,6 +115,15 @@ impl AptosFullnodeClient {
         self.rest_get(url).await
     }
 
+    /// Retrieves the transaction by hash in JSON, pending or committed
+    pub async fn get_transaction_by_hash_json(
+        &self,
+        hash: &str,
+    ) -> AptosResult<FullnodeResponse<serde_json::Value>> {
+        let url = self.build_rest_path(&format!("v1/transactions/by_hash/{}", hash))?;
+        self.rest_get(url).await
+    }
+
     /// Retrieves the transaction by ledger version.  Note that transactions are always committed
     /// with a ledger version
     pub async fn get_transaction_by_version(
@@ -112,6 +134,43 @@ impl AptosFullnodeClient {
         self.rest_get(url).await
     }
 
+    /// Retrieves the transaction by ledger version in JSON
+    pub async fn get_transaction_by_version_json(
+        &self,
+        version: u64,
+    ) -> AptosResult<FullnodeResponse<serde_json::Value>> {
+        let url = self.build_rest_path(&format!("v1/transactions/by_version/{}", version))?;
+        self.rest_get(url).await
+    }
+
+    /// Waits for a transaction to be committed, polling it by hash until `timeout` passes.
+    /// Returns the committed transaction, which may still have failed in execution.
+    pub async fn wait_for_transaction(
+        &self,
+        hash: &str,
+        timeout: Duration,
+    ) -> AptosResult<FullnodeResponse<serde_json::Value>> {
+        let start = Instant::now();
+        loop {
+            match self.get_transaction_by_hash_json(hash).await {
+                Ok(response) if !is_pending(response.inner()) => return Ok(response),
+                Ok(_) => {}
+                // Not yet seen by this node
+                Err(RestError::Api(response))
+                    if matches!(
+                        response.error.error_code,
+                        AptosErrorCode::TransactionNotFound
+                    ) => {}
+                Err(err) => return Err(err),
+            }
+
+            if start.elapsed() >= timeout {
+                return Err(RestError::Timeout("to be committed"));
+            }
+            tokio::time::sleep(WAIT_FOR_TRANSACTION_POLL_INTERVAL).await;
+        }
+    }
+
     pub async fn get_view_function(
         &self,
         function: &str,
@@ -137,6 +196,48 @@ impl AptosFullnodeClient {
         parsable_response.parse_response().await
     }
 
+    /// Call a view function with BCS encoded arguments, decoding each returned value with the
+    /// matching type in `return_types`
+    pub async fn view(
+        &self,
+        view_function: &ViewFunction,
+        return_types: &[TypeTag],
+    ) -> AptosResult<FullnodeResponse<Vec<MoveValue>>> {
+        let response = self.view_values(view_function).await?;
+        Ok(response.and_then(|values| decode_view_values(return_types, &values))?)
+    }
+
+    /// Call a view function with BCS encoded arguments, deserializing the returned values as
+    /// `T`.  `T` is a tuple when the function returns several values, and `()` when it returns
+    /// none.
+    pub async fn view_bcs<T: DeserializeOwned>(
+        &self,
+        view_function: &ViewFunction,
+    ) -> AptosResult<FullnodeResponse<T>> {
+        let response = self.view_values(view_function).await?;
+        // A tuple is encoded as its values one after another
+        Ok(response.and_then(|values| aptos_bcs::from_bytes(&values.concat()))?)
+    }
+
+    /// The BCS encoded values returned by a view function
+    async fn view_values(
+        &self,
+        view_function: &ViewFunction,
+    ) -> AptosResult<FullnodeResponse<Vec<Vec<u8>>>> {
+        let url = self.build_rest_path("v1/view")?;
+        let response = self
+            .rest_client
+            .post(url)
+            .header(ACCEPT, ACCEPT_BCS)
+            .header(CONTENT_TYPE, BCS_VIEW_FUNCTION)
+            .body(aptos_bcs::to_bytes(view_function)?)
+            .send()
+            .await?;
+
+        let parsable_response = ParsableResponse(response);
+        parsable_response.parse_bcs_response().await
+    }
+
     /// Retrieve the blockchain state
     pub async fn get_state(&self) -> AptosResult<State> {
         let url = self.build_rest_path("v1")?;
@@ -157,6 +258,12 @@ impl AptosFullnodeClient {
         self.rest_get(url).await
     }
 
+    /// Account sequence number and authentication key
+    pub async fn get_account(&self, address: String) -> AptosResult<FullnodeResponse<AccountData>> {
+        let url = self.build_rest_path(&format!("v1/accounts/{}", address))?;
+        self.rest_get(url).await
+    }
+
     /// Account Resources
     pub async fn get_account_resources(
         &self,
@@ -166,6 +273,33 @@ impl AptosFullnodeClient {
         self.rest_get(url).await
     }
 
+    /// A single account resource, by its type, e.g. `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`
+    pub async fn get_account_resource(
+        &self,
+        address: String,
+        resource_type: String,
+    ) -> AptosResult<FullnodeResponse<AccountResource>> {
+        let url = self.build_rest_path(&format!(
+            "v1/accounts/{}/resource/{}",
+            address, resource_type
+        ))?;
+        self.rest_get(url).await
+    }
+
+    /// A single account resource, deserialized from BCS as a Rust struct mirroring the Move
+    /// resource, e.g. `get_account_resource_bcs::<CoinStore<AptosCoin>>(address)`
+    pub async fn get_account_resource_bcs<T: MoveStructType + DeserializeOwned>(
+        &self,
+        address: String,
+    ) -> AptosResult<FullnodeResponse<T>> {
+        let url = self.build_rest_path(&format!(
+            "v1/accounts/{}/resource/{}",
+            address,
+            T::struct_tag().to_canonical_string()
+        ))?;
+        self.rest_get_bcs(url).await
+    }
+
     pub async fn get_account_resources_with_config(
         &self,
         address: String,
@@ -186,6 +320,33 @@ impl AptosFullnodeClient {
         self.rest_get(url).await
     }
 
+    /// Retrieve a published module, with its bytecode and ABI
+    pub async fn get_account_module(
+        &self,
+        address: String,
+        module_name: String,
+    ) -> AptosResult<FullnodeResponse<serde_json::Value>> {
+        let url =
+            self.build_rest_path(&format!("v1/accounts/{}/module/{}", address, module_name))?;
+        self.rest_get(url).await
+    }
+
+    /// The ABI of a module, e.g. for `EntryFunction::from_json_args`
+    pub async fn get_account_module_abi(
+        &self,
+        address: String,
+        module_name: String,
+    ) -> AptosResult<FullnodeResponse<MoveModule>> {
+        let response = self.get_account_module(address, module_name).await?;
+        Ok(response.and_then(|module| {
+            let abi = module
+                .get("abi")
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Module has no ABI"))?;
+            serde_json::from_value(abi).map_err(anyhow::Error::from)
+        })?)
+    }
+
     /// submit a transaction to the network.  This is a blocking call and will wait for the
     pub async fn submit_transaction(
         &self,
@@ -224,8 +385,21 @@ impl AptosFullnodeClient {
         parsable_response.parse_response().await
     }
 
+    /// Simulate a transaction without any private keys, see [`SimulationSigners`]
+    pub async fn simulate_unsigned(
+        &self,
+        raw_txn: RawTransaction,
+        signers: &SimulationSigners,
+    ) -> AptosResult<FullnodeResponse<serde_json::Value>> {
+        self.simulate_transaction(signers.simulation_transaction(raw_txn)?)
+            .await
+    }
+
     /// Private function that handles BCS underneath
-    async fn rest_get<T: DeserializeOwned>(&self, url: Url) -> AptosResult<FullnodeResponse<T>> {
+    pub(crate) async fn rest_get<T: DeserializeOwned>(
+        &self,
+        url: Url,
+    ) -> AptosResult<FullnodeResponse<T>> {
         let response = self
             .rest_client
             .get(url)
@@ -239,9 +413,59 @@ impl AptosFullnodeClient {
         parsable_response.parse_response().await
     }
 
+    /// Like `rest_get`, but asks for and parses a BCS body
+    pub(crate) async fn rest_get_bcs<T: DeserializeOwned>(
+        &self,
+        url: Url,
+    ) -> AptosResult<FullnodeResponse<T>> {
+        let response = self
+            .rest_client
+            .get(url)
+            .header(ACCEPT, ACCEPT_BCS)
+            .send()
+            .await?;
+
+        let parsable_response = ParsableResponse(response);
+        parsable_response.parse_bcs_response().await
+    }
+
     /// Helper function to build the REST path on the current URL
-    fn build_rest_path(&self, path: &str) -> AptosResult<Url> {
+    pub(crate) fn build_rest_path(&self, path: &str) -> AptosResult<Url> {
         let out = self.network.rest_url().join(path)?;
         Ok(out)
     }
 }
+
+/// Add each of the query parameters that is given, e.g. `start` and `limit` on a paginated
+/// endpoint, or `ledger_version` on a versioned one
+pub(crate) fn with_query_params(mut url: Url, params: &[(&str, Option<u64>)]) -> Url {
+    for (name, value) in params {
+        if let Some(value) = value {
+            url.query_pairs_mut().append_pair(name, &value.to_string());
+        }
+    }
+    url
+}
+
+/// Whether a transaction returned by the API is still in mempool
+pub(crate) fn is_pending(transaction: &serde_json::Value) -> bool {
+    transaction["type"] == "pending_transaction"
+}
+
+/// Decode the BCS encoded values returned by a view function, given their types
+pub(crate) fn decode_view_values(
+    return_types: &[TypeTag],
+    values: &[Vec<u8>],
+) -> anyhow::Result<Vec<MoveValue>> {
+    anyhow::ensure!(
+        return_types.len() == values.len(),
+        "Expected {} return values, got {}",
+        return_types.len(),
+        values.len()
+    );
+    return_types
+        .iter()
+        .zip(values)
+        .map(|(type_tag, bytes)| MoveValue::from_bcs(type_tag, bytes))
+        .collect()
+}
@@ -0,0 +1,296 @@
+use crate::client::events::filter_indexed_module_events;
+use crate::client::response::FullnodeResponse;
+use crate::client::rest_api::AptosFullnodeClient;
+use aptos_rust_sdk_types::api_types::event::{ModuleEventFilter, VersionedEvent};
+use aptos_rust_sdk_types::api_types::numbers::U64;
+use aptos_rust_sdk_types::error::{AptosErrorCode, RestError};
+use aptos_rust_sdk_types::AptosResult;
+use futures_util::stream::{self, Stream};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+const DEFAULT_PAGE_SIZE: u16 = 100;
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A position in the ledger's events: the version of a transaction, and the index of an event
+/// among that transaction's events.  A subscription started from a checkpoint only delivers the
+/// events at or after it, so persisting the checkpoint of each handled item lets a subscriber
+/// resume where it left off without seeing an event twice.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct EventCheckpoint {
+    pub version: u64,
+    pub event_index: u64,
+}
+
+impl EventCheckpoint {
+    pub fn new(version: u64, event_index: u64) -> Self {
+        Self {
+            version,
+            event_index,
+        }
+    }
+
+    /// The checkpoint before all the events of the transaction at `version`
+    pub fn at_version(version: u64) -> Self {
+        Self::new(version, 0)
+    }
+}
+
+/// An item delivered by an event subscription
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubscriptionItem {
+    /// An event matching the subscription's filter
+    Event {
+        event: VersionedEvent,
+        /// The index of the event among all the events of its transaction
+        event_index: u64,
+    },
+    /// Versions `start..end` were pruned by the node before they were scanned, so any matching
+    /// events in them were missed.  The subscription carries on from `end`.
+    Gap { start: u64, end: u64 },
+}
+
+impl SubscriptionItem {
+    /// The checkpoint to resume from once this item is handled
+    pub fn checkpoint(&self) -> EventCheckpoint {
+        match self {
+            SubscriptionItem::Event { event, event_index } => {
+                EventCheckpoint::new(event.version.inner(), event_index + 1)
+            }
+            SubscriptionItem::Gap { end, .. } => EventCheckpoint::at_version(*end),
+        }
+    }
+}
+
+/// How an event subscription polls the node
+#[derive(Debug, Clone)]
+pub struct EventSubscriptionConfig {
+    /// The number of transactions to request at a time
+    pub page_size: u16,
+    /// How long to wait before polling again, once caught up with the ledger or after an error
+    pub poll_interval: Duration,
+}
+
+impl Default for EventSubscriptionConfig {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+impl EventSubscriptionConfig {
+    /// Create a new config with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of transactions to request at a time
+    pub fn with_page_size(mut self, page_size: u16) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Set how long to wait between polls
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+impl AptosFullnodeClient {
+    /// Subscribe to the module events matching a filter, scanning committed transactions from
+    /// `from` onwards and then polling for new ones.
+    ///
+    /// The stream never ends.  Errors are yielded as they happen, and polling then retries from
+    /// the same position, so no event is skipped or repeated.
+    pub fn subscribe_events(
+        &self,
+        filter: ModuleEventFilter,
+        from: EventCheckpoint,
+    ) -> impl Stream<Item = AptosResult<SubscriptionItem>> {
+        self.subscribe_events_with_config(filter, from, EventSubscriptionConfig::default())
+    }
+
+    pub fn subscribe_events_with_config(
+        &self,
+        filter: ModuleEventFilter,
+        from: EventCheckpoint,
+        config: EventSubscriptionConfig,
+    ) -> impl Stream<Item = AptosResult<SubscriptionItem>> {
+        let subscription = Subscription {
+            client: self.clone(),
+            filter,
+            from,
+            next_version: from.version,
+            pending: VecDeque::new(),
+            wait: false,
+            config,
+        };
+        stream::unfold(subscription, |mut subscription| async move {
+            let item = subscription.next().await;
+            Some((item, subscription))
+        })
+    }
+}
+
+struct Subscription {
+    client: AptosFullnodeClient,
+    filter: ModuleEventFilter,
+    from: EventCheckpoint,
+    /// The version of the next transaction to scan
+    next_version: u64,
+    /// Items scanned but not yet delivered
+    pending: VecDeque<SubscriptionItem>,
+    /// Whether to wait before the next poll
+    wait: bool,
+    config: EventSubscriptionConfig,
+}
+
+impl Subscription {
+    async fn next(&mut self) -> AptosResult<SubscriptionItem> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Ok(item);
+            }
+            if self.wait {
+                tokio::time::sleep(self.config.poll_interval).await;
+            }
+
+            let result = match self
+                .client
+                .get_transactions(Some(self.next_version), Some(self.config.page_size))
+                .await
+            {
+                // The oldest version is needed to know where the gap ends
+                Err(RestError::Api(mut response))
+                    if matches!(response.error.error_code, AptosErrorCode::VersionPruned)
+                        && response.state.is_none() =>
+                {
+                    match self.client.get_state().await {
+                        Ok(state) => {
+                            response.state = Some(state);
+                            Err(RestError::Api(response))
+                        }
+                        // Fails like any other error, so the next poll waits too
+                        Err(err) => Err(err),
+                    }
+                }
+                result => result,
+            };
+
+            match poll_action(
+                &self.filter,
+                self.from,
+                self.next_version,
+                self.config.page_size,
+                result,
+            ) {
+                PollAction::Deliver {
+                    items,
+                    next_version,
+                    wait,
+                } => {
+                    self.pending.extend(items);
+                    self.next_version = next_version;
+                    self.wait = wait;
+                }
+                PollAction::Wait => self.wait = true,
+                PollAction::Fail(err) => {
+                    self.wait = true;
+                    return Err(err);
+                }
+            }
+        }
+    }
+}
+
+/// What a subscription does after polling for the transactions from a version
+#[derive(Debug)]
+pub(crate) enum PollAction {
+    /// Deliver the items, and poll again from `next_version`, waiting first if `wait`
+    Deliver {
+        items: Vec<SubscriptionItem>,
+        next_version: u64,
+        wait: bool,
+    },
+    /// Caught up with the ledger, so wait and poll again from the same version
+    Wait,
+    /// Yield the error, then wait and poll again from the same version
+    Fail(RestError),
+}
+
+/// Decide what to do with the result of polling a page of `page_size` transactions from
+/// `next_version`.  Versions pruned by the node are skipped over with a gap, which needs the
+/// error's state to know the oldest version left.
+pub(crate) fn poll_action(
+    filter: &ModuleEventFilter,
+    from: EventCheckpoint,
+    next_version: u64,
+    page_size: u16,
+    result: AptosResult<FullnodeResponse<Vec<serde_json::Value>>>,
+) -> PollAction {
+    let transactions = match result {
+        Ok(response) => response.into_inner(),
+        Err(RestError::Api(response))
+            if matches!(response.error.error_code, AptosErrorCode::VersionPruned) =>
+        {
+            return match response.state.as_ref() {
+                Some(state) if state.oldest_ledger_version > next_version => PollAction::Deliver {
+                    items: vec![SubscriptionItem::Gap {
+                        start: next_version,
+                        end: state.oldest_ledger_version,
+                    }],
+                    next_version: state.oldest_ledger_version,
+                    wait: false,
+                },
+                _ => PollAction::Fail(RestError::Api(response)),
+            };
+        }
+        Err(RestError::Api(response))
+            if matches!(response.error.error_code, AptosErrorCode::VersionNotFound) =>
+        {
+            return PollAction::Wait;
+        }
+        Err(err) => return PollAction::Fail(err),
+    };
+
+    match subscription_items(filter, from, &transactions) {
+        Ok((items, Some(last_version))) => PollAction::Deliver {
+            items,
+            next_version: last_version + 1,
+            // Poll again right away unless caught up with the ledger
+            wait: transactions.len() < page_size as usize,
+        },
+        Ok((_, None)) => PollAction::Wait,
+        Err(err) => PollAction::Fail(err.into()),
+    }
+}
+
+/// The items for the matching events among a page of transactions returned in JSON, leaving out
+/// those before `from`, along with the version of the last transaction of the page
+pub(crate) fn subscription_items(
+    filter: &ModuleEventFilter,
+    from: EventCheckpoint,
+    transactions: &[serde_json::Value],
+) -> anyhow::Result<(Vec<SubscriptionItem>, Option<u64>)> {
+    let items = filter_indexed_module_events(filter, transactions)?
+        .into_iter()
+        .filter(|(event_index, event)| {
+            EventCheckpoint::new(event.version.inner(), *event_index) >= from
+        })
+        .map(|(event_index, event)| SubscriptionItem::Event { event, event_index })
+        .collect();
+    let last_version = transactions
+        .iter()
+        .rev()
+        .find_map(|transaction| transaction.get("version"))
+        .map(|version| serde_json::from_value::<U64>(version.clone()))
+        .transpose()?
+        .map(|version| version.inner());
+    Ok((items, last_version))
+}
@@ -0,0 +1,190 @@
+use crate::client::response::{FullnodeResponse, ParsableResponse};
+use crate::client::rest_api::{with_query_params, AptosFullnodeClient};
+use anyhow::anyhow;
+use aptos_rust_sdk_types::api_types::state_key::TableHandle;
+use aptos_rust_sdk_types::api_types::table::{
+    BigOrderedMap, SmartTable, SmartTableEntry, StorageSlot, Table, BIG_ORDERED_MAP_ROOT_INDEX,
+};
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use aptos_rust_sdk_types::mime_types::{ACCEPT_BCS, JSON};
+use aptos_rust_sdk_types::state::State;
+use aptos_rust_sdk_types::AptosResult;
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+impl AptosFullnodeClient {
+    /// A table item in JSON, given the Move types of the table's keys and values, and the key in
+    /// JSON, e.g. `"5"` for a `u64` key
+    pub async fn get_table_item(
+        &self,
+        handle: TableHandle,
+        key_type: TypeTag,
+        value_type: TypeTag,
+        key: serde_json::Value,
+        ledger_version: Option<u64>,
+    ) -> AptosResult<FullnodeResponse<serde_json::Value>> {
+        let url = self.build_rest_path(&format!("v1/tables/{}/item", handle.0))?;
+        let url = with_query_params(url, &[("ledger_version", ledger_version)]);
+        let response = self
+            .rest_client
+            .post(url)
+            .header(ACCEPT, JSON)
+            .header(CONTENT_TYPE, JSON)
+            .json(&serde_json::json!({
+                "key_type": key_type.to_canonical_string(),
+                "value_type": value_type.to_canonical_string(),
+                "key": key,
+            }))
+            .send()
+            .await?;
+
+        let parsable_response = ParsableResponse(response);
+        parsable_response.parse_response().await
+    }
+
+    /// The BCS encoded value of a table item, given its BCS encoded key
+    pub async fn get_raw_table_item(
+        &self,
+        handle: TableHandle,
+        key: &[u8],
+        ledger_version: Option<u64>,
+    ) -> AptosResult<FullnodeResponse<Vec<u8>>> {
+        let url = self.build_rest_path(&format!("v1/tables/{}/raw_item", handle.0))?;
+        let url = with_query_params(url, &[("ledger_version", ledger_version)]);
+        let response = self
+            .rest_client
+            .post(url)
+            .header(ACCEPT, ACCEPT_BCS)
+            .header(CONTENT_TYPE, JSON)
+            .json(&serde_json::json!({ "key": format!("0x{}", hex::encode(key)) }))
+            .send()
+            .await?;
+
+        let parsable_response = ParsableResponse(response);
+        parsable_response.parse_bytes_response().await
+    }
+
+    /// The value of a table's item, read in BCS.  Fails with `TableItemNotFound` if the table has
+    /// no item with the key.
+    pub async fn get_table_value<K: Serialize, V: DeserializeOwned>(
+        &self,
+        table: &Table<K, V>,
+        key: &K,
+        ledger_version: Option<u64>,
+    ) -> AptosResult<FullnodeResponse<V>> {
+        let response = self
+            .get_raw_table_item(table.handle, &aptos_bcs::to_bytes(key)?, ledger_version)
+            .await?;
+        Ok(response.and_then(|bytes| aptos_bcs::from_bytes(&bytes))?)
+    }
+
+    /// The entries of one of a `SmartTable`'s buckets, from `0` to `num_buckets - 1`
+    pub async fn get_smart_table_bucket<K: DeserializeOwned, V: DeserializeOwned>(
+        &self,
+        table: &SmartTable<K, V>,
+        index: u64,
+        ledger_version: Option<u64>,
+    ) -> AptosResult<FullnodeResponse<Vec<SmartTableEntry<K, V>>>> {
+        self.get_table_value(&table.buckets.inner, &index, ledger_version)
+            .await
+    }
+
+    /// All the entries of a `SmartTable`, bucket by bucket.  The buckets are all read at
+    /// `ledger_version`, which should be the version the table itself was read at, or if not
+    /// given at the version the first bucket is read at.
+    pub async fn get_smart_table_entries<K: DeserializeOwned, V: DeserializeOwned>(
+        &self,
+        table: &SmartTable<K, V>,
+        ledger_version: Option<u64>,
+    ) -> AptosResult<FullnodeResponse<Vec<(K, V)>>> {
+        let mut pinned = PinnedReads::new(ledger_version);
+        let mut entries = vec![];
+        for index in 0..table.num_buckets {
+            let response = self
+                .get_smart_table_bucket(table, index, pinned.ledger_version)
+                .await?;
+            let bucket = pinned.read(response);
+            entries.extend(bucket.into_iter().map(|entry| (entry.key, entry.value)));
+        }
+        pinned.into_response(self, entries).await
+    }
+
+    /// All the entries of a `BigOrderedMap` in key order, following its leaves from the leftmost
+    /// one.  The nodes are all read at `ledger_version`, which should be the version the map
+    /// itself was read at, or if not given at the version the first node is read at.
+    pub async fn get_big_ordered_map_entries<K, V>(
+        &self,
+        map: &BigOrderedMap<K, V>,
+        ledger_version: Option<u64>,
+    ) -> AptosResult<FullnodeResponse<Vec<(K, V)>>>
+    where
+        K: Clone + DeserializeOwned,
+        V: Clone + DeserializeOwned,
+    {
+        let mut pinned = PinnedReads::new(ledger_version);
+        let mut entries = vec![];
+        let mut index = map.min_leaf_index();
+        loop {
+            let node = if index == BIG_ORDERED_MAP_ROOT_INDEX {
+                map.root().clone()
+            } else {
+                let nodes = map
+                    .nodes()
+                    .ok_or_else(|| anyhow!("BigOrderedMap has no slot for node {}", index))?;
+                let response = self
+                    .get_table_value(nodes, &index, pinned.ledger_version)
+                    .await?;
+                match pinned.read(response) {
+                    StorageSlot::Some { value } => value,
+                    StorageSlot::Empty { .. } => {
+                        return Err(anyhow!("BigOrderedMap node {} is empty", index).into())
+                    }
+                }
+            };
+
+            let next = node.next();
+            entries.extend(node.into_leaf_entries());
+            match next {
+                Some(next) => index = next,
+                None => break,
+            }
+        }
+        pinned.into_response(self, entries).await
+    }
+}
+
+/// Keeps a series of reads at the same ledger version, the one of the first read unless given
+struct PinnedReads {
+    ledger_version: Option<u64>,
+    state: Option<State>,
+}
+
+impl PinnedReads {
+    fn new(ledger_version: Option<u64>) -> Self {
+        Self {
+            ledger_version,
+            state: None,
+        }
+    }
+
+    fn read<T>(&mut self, response: FullnodeResponse<T>) -> T {
+        let (inner, state) = response.into_parts();
+        self.ledger_version.get_or_insert(state.version);
+        self.state = Some(state);
+        inner
+    }
+
+    /// The result of the reads, with the state of the last one
+    async fn into_response<T>(
+        self,
+        client: &AptosFullnodeClient,
+        inner: T,
+    ) -> AptosResult<FullnodeResponse<T>> {
+        let state = match self.state {
+            Some(state) => state,
+            None => client.get_state().await?,
+        };
+        Ok(FullnodeResponse::new(inner, state))
+    }
+}
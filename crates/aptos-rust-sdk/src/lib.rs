@@ -2,3 +2,4 @@ pub mod account;
 pub mod client;
 #[cfg(test)]
 mod tests;
+pub mod transaction;
@@ -0,0 +1,122 @@
+use crate::account::account_key::AccountKey;
+use crate::account::cli_config::{CliConfig, CliNetwork, CONFIG_FILE, CONFIG_FOLDER};
+use crate::account::signer::Signer;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use std::str::FromStr;
+use url::Url;
+
+const PRIVATE_KEY: &str = "4aeeeb3f286caa91984d4a16d424786c7aa26947050b00e84ab7033f2aab0c2d";
+const ACCOUNT: &str = "0x94bd6fa34dba07f935ea2288ba36d74aa5dda6ae541137844cc2f0af8b6b73f3";
+
+fn config_yaml() -> String {
+    format!(
+        r#"---
+profiles:
+  default:
+    network: Testnet
+    private_key: "ed25519-priv-0x{PRIVATE_KEY}"
+    account: {account}
+    rest_url: "https://fullnode.testnet.aptoslabs.com/v1"
+    faucet_url: "https://faucet.testnet.aptoslabs.com"
+  derived:
+    network: Local
+    private_key: "0x{PRIVATE_KEY}"
+    rest_url: "http://localhost:8080"
+  custom:
+    network: Custom
+    rest_url: "https://example.com"
+"#,
+        account = ACCOUNT.trim_start_matches("0x"),
+    )
+}
+
+#[test]
+fn test_parse_profiles() {
+    let config = CliConfig::from_str(&config_yaml()).unwrap();
+    assert_eq!(config.profiles().len(), 3);
+
+    let profile = config.default_profile().unwrap();
+    assert_eq!(profile.cli_network(), Some(CliNetwork::Testnet));
+    assert_eq!(
+        profile.account(),
+        Some(AccountAddress::from_str(ACCOUNT).unwrap())
+    );
+    assert_eq!(
+        profile.faucet_url().unwrap().unwrap().as_str(),
+        "https://faucet.testnet.aptoslabs.com/"
+    );
+    assert!(!format!("{:?}", profile).contains(PRIVATE_KEY));
+}
+
+#[test]
+fn test_profile_network() {
+    let config = CliConfig::from_str(&config_yaml()).unwrap();
+
+    let network = config.default_profile().unwrap().network().unwrap();
+    assert_eq!(network.name(), "testnet");
+    assert_eq!(
+        network.rest_url().as_str(),
+        "https://fullnode.testnet.aptoslabs.com/"
+    );
+
+    let network = config.profile("derived").unwrap().network().unwrap();
+    assert_eq!(network.name(), "localnet");
+    assert_eq!(network.rest_url().as_str(), "http://localhost:8080/");
+
+    // A custom network has no known indexer, so it must be given
+    let profile = config.profile("custom").unwrap();
+    profile
+        .network()
+        .expect_err("Profile has a custom network, whose indexer URL must be given explicitly");
+    let indexer_url = Url::parse("https://indexer.example.com").unwrap();
+    let network = profile
+        .network_with_indexer_url(indexer_url.clone())
+        .unwrap();
+    assert_eq!(network.name(), "custom");
+    assert_eq!(network.rest_url().as_str(), "https://example.com/");
+    assert_eq!(network.indexer_url(), &indexer_url);
+
+    let network = config
+        .default_profile()
+        .unwrap()
+        .network_with_indexer_url(indexer_url.clone())
+        .unwrap();
+    assert_eq!(network.name(), "testnet");
+    assert_eq!(network.indexer_url(), &indexer_url);
+}
+
+#[test]
+fn test_profile_signer() {
+    let config = CliConfig::from_str(&config_yaml()).unwrap();
+    let expected_key = AccountKey::from_ed25519_private_key(PRIVATE_KEY);
+
+    let signer = config.default_profile().unwrap().signer().unwrap();
+    assert_eq!(signer.address(), AccountAddress::from_str(ACCOUNT).unwrap());
+    assert_eq!(signer.key().public_key(), expected_key.public_key());
+
+    // Without an account, the address is derived from the key
+    let signer = config.profile("derived").unwrap().signer().unwrap();
+    assert_eq!(signer.address(), expected_key.address());
+
+    config
+        .profile("custom")
+        .unwrap()
+        .signer()
+        .expect_err("Profile has no private key");
+}
+
+#[test]
+fn test_find_config_in_parent() {
+    let root = std::env::temp_dir().join(format!("aptos-cli-config-{}", std::process::id()));
+    let nested = root.join("a").join("b");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::create_dir_all(root.join(CONFIG_FOLDER)).unwrap();
+    std::fs::write(root.join(CONFIG_FOLDER).join(CONFIG_FILE), config_yaml()).unwrap();
+
+    let found = CliConfig::find_config_file(&nested);
+    let config = CliConfig::load_from(&nested);
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(found, Some(root.join(CONFIG_FOLDER).join(CONFIG_FILE)));
+    assert!(config.unwrap().profile("custom").is_some());
+}
@@ -0,0 +1,182 @@
+use super::{mock_fullnode, MockResponse};
+use crate::client::account_transactions::pin_page;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::CommittedUserTransaction;
+use futures_util::TryStreamExt;
+use serde_json::json;
+use std::str::FromStr;
+
+fn transaction_json(version: u64, sequence_number: u64) -> serde_json::Value {
+    json!({
+        "type": "user_transaction",
+        "version": version.to_string(),
+        "hash": "0x01",
+        "state_change_hash": "0x02",
+        "event_root_hash": "0x03",
+        "state_checkpoint_hash": null,
+        "gas_used": "10",
+        "success": true,
+        "vm_status": "Executed successfully",
+        "accumulator_root_hash": "0x04",
+        "changes": [],
+        "sender": "0xa",
+        "sequence_number": sequence_number.to_string(),
+        "max_gas_amount": "200000",
+        "gas_unit_price": "100",
+        "expiration_timestamp_secs": "1700000000",
+        "payload": {
+            "type": "entry_function_payload",
+            "function": "0x1::aptos_account::transfer",
+            "type_arguments": [],
+            "arguments": ["0xb", "100"]
+        },
+        "signature": {"type": "ed25519_signature", "public_key": "0x05", "signature": "0x06"},
+        "events": [{
+            "guid": {"creation_number": "0", "account_address": "0x0"},
+            "sequence_number": "0",
+            "type": "0x1::transaction_fee::FeeStatement",
+            "data": {"total_charge_gas_units": "10"}
+        }],
+        "timestamp": "1699999990000000"
+    })
+}
+
+fn transaction(version: u64, sequence_number: u64) -> CommittedUserTransaction {
+    serde_json::from_value(transaction_json(version, sequence_number)).unwrap()
+}
+
+/// A page of transactions as returned by the node, each given as `(version, sequence_number)`
+fn page(transactions: &[(u64, u64)], ledger_version: u64) -> MockResponse {
+    MockResponse::json(
+        transactions
+            .iter()
+            .map(|(version, sequence_number)| transaction_json(*version, *sequence_number))
+            .collect(),
+    )
+    .at_version(ledger_version)
+}
+
+fn sequence_numbers(transactions: &[CommittedUserTransaction]) -> Vec<u64> {
+    transactions
+        .iter()
+        .map(|transaction| transaction.sequence_number.inner())
+        .collect()
+}
+
+#[test]
+fn test_committed_user_transaction() {
+    let transaction = transaction(100, 3);
+    assert_eq!(transaction.version.inner(), 100);
+    assert_eq!(transaction.sender, AccountAddress::from_str("0xa").unwrap());
+    assert!(transaction.success);
+    assert_eq!(transaction.events.len(), 1);
+}
+
+#[test]
+fn test_pin_page() {
+    // A full page within the ledger version continues after its last transaction
+    let page = pin_page(vec![transaction(100, 3), transaction(120, 4)], 150, 2);
+    assert_eq!(sequence_numbers(&page.transactions), vec![3, 4]);
+    assert_eq!(page.ledger_version, 150);
+    assert_eq!(page.next_start, Some(5));
+
+    // A short page is the last one
+    let page = pin_page(vec![transaction(100, 3)], 150, 2);
+    assert_eq!(page.next_start, None);
+
+    // Transactions committed after the ledger version are left out, and end the pages
+    let page = pin_page(vec![transaction(100, 3), transaction(160, 4)], 150, 2);
+    assert_eq!(sequence_numbers(&page.transactions), vec![3]);
+    assert_eq!(page.next_start, None);
+
+    let page = pin_page(vec![], 150, 2);
+    assert!(page.transactions.is_empty());
+    assert_eq!(page.next_start, None);
+}
+
+#[tokio::test]
+async fn test_stream_account_transactions() {
+    let address = AccountAddress::from_str("0xa").unwrap();
+    // The second page is short, so it's the last one
+    let (client, requests) = mock_fullnode(vec![
+        page(&[(100, 3), (120, 4)], 150),
+        page(&[(130, 5)], 160),
+    ])
+    .await;
+
+    let transactions: Vec<_> = client
+        .stream_account_transactions(address, 3, 2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(sequence_numbers(&transactions), vec![3, 4, 5]);
+
+    let requests = requests.lock().unwrap();
+    let targets: Vec<_> = requests
+        .iter()
+        .map(|request| request.target.as_str())
+        .collect();
+    assert_eq!(
+        targets,
+        [
+            format!("/v1/accounts/{}/transactions?start=3&limit=2", address),
+            format!("/v1/accounts/{}/transactions?start=5&limit=2", address),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_stream_account_transactions_pinned() {
+    let address = AccountAddress::from_str("0xa").unwrap();
+    // A transaction committed after the first page's ledger version ends the stream, even with
+    // a full page
+    let (client, requests) = mock_fullnode(vec![
+        page(&[(100, 3), (120, 4)], 150),
+        page(&[(140, 5), (160, 6)], 170),
+    ])
+    .await;
+
+    let transactions: Vec<_> = client
+        .stream_account_transactions(address, 3, 2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(sequence_numbers(&transactions), vec![3, 4, 5]);
+    assert_eq!(requests.lock().unwrap().len(), 2);
+
+    // An account without transactions from the start has an empty stream
+    let (client, _) = mock_fullnode(vec![page(&[], 150)]).await;
+    let transactions: Vec<_> = client
+        .stream_account_transactions(address, 0, 2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(transactions.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_account_transaction_by_sequence_number() {
+    let address = AccountAddress::from_str("0xa").unwrap();
+    let (client, requests) = mock_fullnode(vec![page(&[(100, 7)], 150), page(&[], 150)]).await;
+
+    let transaction = client
+        .get_account_transaction_by_sequence_number(address, 7)
+        .await
+        .unwrap();
+    assert_eq!(transaction.state().version, 150);
+    assert_eq!(transaction.into_inner().unwrap().version.inner(), 100);
+
+    // Not committed yet
+    let transaction = client
+        .get_account_transaction_by_sequence_number(address, 8)
+        .await
+        .unwrap();
+    assert_eq!(transaction.into_inner(), None);
+
+    let request = &requests.lock().unwrap()[0];
+    assert_eq!(request.method, "GET");
+    assert_eq!(
+        request.target,
+        format!("/v1/accounts/{}/transactions?start=7&limit=1", address)
+    );
+}
@@ -0,0 +1,32 @@
+use crate::client::balance::{move_option, type_info_to_type_tag};
+use aptos_rust_sdk_types::api_types::asset::Asset;
+use serde_json::json;
+
+#[test]
+fn test_move_option() {
+    let some = json!({"vec": [{"inner": "0xa"}]});
+    assert_eq!(move_option(&some).unwrap(), Some(&json!({"inner": "0xa"})));
+    assert_eq!(move_option(&json!({"vec": []})).unwrap(), None);
+    assert!(move_option(&json!("0xa")).is_err());
+}
+
+#[test]
+fn test_type_info_to_type_tag() {
+    let type_info = json!({
+        "account_address": "0x1",
+        "module_name": format!("0x{}", hex::encode("aptos_coin")),
+        "struct_name": format!("0x{}", hex::encode("AptosCoin")),
+    });
+    let Asset::Coin(apt) = Asset::apt() else {
+        unreachable!()
+    };
+    assert_eq!(type_info_to_type_tag(&type_info).unwrap(), apt);
+    assert_eq!(apt.to_canonical_string(), "0x1::aptos_coin::AptosCoin");
+
+    let malformed = json!({
+        "account_address": "0x1",
+        "module_name": "not hex",
+        "struct_name": "0x00",
+    });
+    assert!(type_info_to_type_tag(&malformed).is_err());
+}
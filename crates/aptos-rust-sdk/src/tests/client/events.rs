@@ -0,0 +1,55 @@
+use crate::client::events::filter_module_events;
+use aptos_rust_sdk_types::api_types::event::ModuleEventFilter;
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use serde_json::json;
+use std::str::FromStr;
+
+fn deposit(creation_number: &str, account_address: &str) -> serde_json::Value {
+    json!({
+        "guid": {"creation_number": creation_number, "account_address": account_address},
+        "sequence_number": "0",
+        "type": "0x1::fungible_asset::Deposit",
+        "data": {"store": "0xa", "amount": "50"}
+    })
+}
+
+#[test]
+fn test_filter_module_events() {
+    let transactions = vec![
+        json!({
+            "type": "user_transaction",
+            "version": "10",
+            "events": [
+                deposit("0", "0x0"),
+                {
+                    "guid": {"creation_number": "0", "account_address": "0x0"},
+                    "sequence_number": "0",
+                    "type": "0x1::transaction_fee::FeeStatement",
+                    "data": {"total_charge_gas_units": "5"}
+                }
+            ]
+        }),
+        // A V1 event of the same type isn't a module event
+        json!({"type": "user_transaction", "version": "11", "events": [deposit("2", "0xb")]}),
+        json!({"type": "pending_transaction", "hash": "0x1"}),
+        json!({"type": "block_metadata_transaction", "version": "12", "events": [deposit("0", "0x0")]}),
+    ];
+
+    let filter = ModuleEventFilter::new()
+        .with_type(TypeTag::from_str("0x1::fungible_asset::Deposit").unwrap());
+    let events = filter_module_events(&filter, &transactions).unwrap();
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| event.version.inner())
+            .collect::<Vec<_>>(),
+        vec![10, 12]
+    );
+    assert_eq!(events[0].event.data["amount"], "50");
+
+    let events = filter_module_events(&ModuleEventFilter::new(), &transactions).unwrap();
+    assert_eq!(events.len(), 3);
+
+    let malformed = vec![json!({"version": "13", "events": [{"type": "u64"}]})];
+    assert!(filter_module_events(&filter, &malformed).is_err());
+}
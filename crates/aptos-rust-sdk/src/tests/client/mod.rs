@@ -1 +1,163 @@
+use crate::client::config::AptosNetwork;
+use crate::client::rest_api::AptosFullnodeClient;
+use aptos_rust_sdk_types::headers::{
+    X_APTOS_BLOCK_HEIGHT, X_APTOS_CHAIN_ID, X_APTOS_EPOCH, X_APTOS_LEDGER_OLDEST_VERSION,
+    X_APTOS_LEDGER_TIMESTAMP, X_APTOS_LEDGER_VERSION, X_APTOS_OLDEST_BLOCK_HEIGHT,
+};
+use aptos_rust_sdk_types::mime_types::{ACCEPT_BCS, JSON};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use url::Url;
+
+mod account_transactions;
+mod balance;
+mod events;
 mod rest_api;
+mod subscription;
+mod table;
+mod view;
+
+/// A canned response from a [`mock_fullnode`]
+pub(super) struct MockResponse {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+    ledger_version: u64,
+}
+
+impl MockResponse {
+    pub(super) fn json(body: serde_json::Value) -> Self {
+        Self {
+            status: 200,
+            content_type: JSON,
+            body: body.to_string().into_bytes(),
+            ledger_version: 1000,
+        }
+    }
+
+    pub(super) fn bcs(body: Vec<u8>) -> Self {
+        Self {
+            content_type: ACCEPT_BCS,
+            body,
+            ..Self::json(serde_json::Value::Null)
+        }
+    }
+
+    pub(super) fn at_version(mut self, ledger_version: u64) -> Self {
+        self.ledger_version = ledger_version;
+        self
+    }
+}
+
+/// A request received by a [`mock_fullnode`]
+#[derive(Debug)]
+pub(super) struct MockRequest {
+    pub method: String,
+    /// The path and query, e.g. `/v1/transactions?start=5`
+    pub target: String,
+    /// Keyed by lowercase header name
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    pub(super) fn json_body(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).unwrap()
+    }
+}
+
+/// A client for a local node answering each request with the next of `responses`, along with the
+/// requests the node received.  Connections are refused once the responses run out.
+pub(super) async fn mock_fullnode(
+    responses: Vec<MockResponse>,
+) -> (AptosFullnodeClient, Arc<Mutex<Vec<MockRequest>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+    let requests = Arc::new(Mutex::new(vec![]));
+
+    let received = requests.clone();
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut stream).await;
+            received.lock().unwrap().push(request);
+            write_response(&mut stream, response).await;
+        }
+    });
+
+    let client = AptosFullnodeClient::builder(AptosNetwork::new("mock", url.clone(), url)).build();
+    (client, requests)
+}
+
+async fn read_request(stream: &mut TcpStream) -> MockRequest {
+    let mut bytes = vec![];
+    let header_end = loop {
+        let mut buf = [0; 1024];
+        let read = stream.read(&mut buf).await.unwrap();
+        assert_ne!(read, 0, "Connection closed mid-request");
+        bytes.extend(&buf[..read]);
+        if let Some(end) = bytes.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end;
+        }
+    };
+
+    let head = String::from_utf8(bytes[..header_end].to_vec()).unwrap();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap().split(' ');
+    let method = request_line.next().unwrap().to_string();
+    let target = request_line.next().unwrap().to_string();
+    let headers: HashMap<_, _> = lines
+        .filter_map(|line| line.split_once(": "))
+        .map(|(name, value)| (name.to_lowercase(), value.to_string()))
+        .collect();
+
+    let content_length = headers
+        .get("content-length")
+        .map(|length| length.parse().unwrap())
+        .unwrap_or(0);
+    let mut body = bytes[header_end + 4..].to_vec();
+    while body.len() < content_length {
+        let mut buf = [0; 1024];
+        let read = stream.read(&mut buf).await.unwrap();
+        body.extend(&buf[..read]);
+    }
+
+    MockRequest {
+        method,
+        target,
+        headers,
+        body,
+    }
+}
+
+async fn write_response(stream: &mut TcpStream, response: MockResponse) {
+    let head = format!(
+        "HTTP/1.1 {} Mock\r\n\
+         Content-Type: {}\r\n\
+         Content-Length: {}\r\n\
+         {}: 2\r\n\
+         {}: 1\r\n\
+         {}: {}\r\n\
+         {}: 0\r\n\
+         {}: 1700000000000000\r\n\
+         {}: 1\r\n\
+         {}: 0\r\n\
+         Connection: close\r\n\r\n",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        X_APTOS_CHAIN_ID,
+        X_APTOS_EPOCH,
+        X_APTOS_LEDGER_VERSION,
+        response.ledger_version,
+        X_APTOS_LEDGER_OLDEST_VERSION,
+        X_APTOS_LEDGER_TIMESTAMP,
+        X_APTOS_BLOCK_HEIGHT,
+        X_APTOS_OLDEST_BLOCK_HEIGHT,
+    );
+    stream.write_all(head.as_bytes()).await.unwrap();
+    stream.write_all(&response.body).await.unwrap();
+    stream.shutdown().await.unwrap();
+}
@@ -0,0 +1,183 @@
+use crate::client::response::FullnodeResponse;
+use crate::client::subscription::{
+    poll_action, subscription_items, EventCheckpoint, PollAction, SubscriptionItem,
+};
+use aptos_rust_sdk_types::api_types::event::ModuleEventFilter;
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use aptos_rust_sdk_types::error::{AptosError, AptosErrorCode, AptosErrorResponse, RestError};
+use aptos_rust_sdk_types::state::State;
+use reqwest::StatusCode;
+use serde_json::json;
+use std::str::FromStr;
+
+fn event(type_: &str, amount: &str) -> serde_json::Value {
+    json!({
+        "guid": {"creation_number": "0", "account_address": "0x0"},
+        "sequence_number": "0",
+        "type": type_,
+        "data": {"store": "0xa", "amount": amount}
+    })
+}
+
+fn transactions() -> Vec<serde_json::Value> {
+    vec![
+        json!({
+            "type": "user_transaction",
+            "version": "10",
+            "events": [
+                event("0x1::fungible_asset::Withdraw", "5"),
+                event("0x1::fungible_asset::Deposit", "5"),
+                event("0x1::fungible_asset::Deposit", "6"),
+            ]
+        }),
+        json!({"type": "state_checkpoint_transaction", "version": "11", "events": []}),
+        json!({
+            "type": "user_transaction",
+            "version": "12",
+            "events": [event("0x1::fungible_asset::Deposit", "7")]
+        }),
+        json!({"type": "state_checkpoint_transaction", "version": "13", "events": []}),
+    ]
+}
+
+fn state(oldest_ledger_version: u64) -> State {
+    State {
+        chain_id: 2,
+        epoch: 1,
+        version: 1000,
+        timestamp_usecs: 1_700_000_000_000_000,
+        oldest_ledger_version,
+        oldest_block_height: 0,
+        block_height: 1,
+        cursor: None,
+    }
+}
+
+fn api_error(error_code: AptosErrorCode, state: Option<State>) -> RestError {
+    RestError::Api(AptosErrorResponse {
+        error: AptosError {
+            message: format!("{:?}", error_code),
+            error_code,
+            vm_error_code: None,
+        },
+        state,
+        status_code: StatusCode::NOT_FOUND,
+    })
+}
+
+fn amounts(items: &[SubscriptionItem]) -> Vec<&str> {
+    items
+        .iter()
+        .map(|item| match item {
+            SubscriptionItem::Event { event, .. } => event.event.data["amount"].as_str().unwrap(),
+            SubscriptionItem::Gap { .. } => panic!("Unexpected gap"),
+        })
+        .collect()
+}
+
+#[test]
+fn test_subscription_items() {
+    let filter = ModuleEventFilter::new()
+        .with_type(TypeTag::from_str("0x1::fungible_asset::Deposit").unwrap());
+
+    let (items, last_version) =
+        subscription_items(&filter, EventCheckpoint::at_version(10), &transactions()).unwrap();
+    assert_eq!(last_version, Some(13));
+    assert_eq!(amounts(&items), vec!["5", "6", "7"]);
+    // Event indexes count every event of the transaction, not only the matching ones
+    assert_eq!(items[0].checkpoint(), EventCheckpoint::new(10, 2));
+    assert_eq!(items[1].checkpoint(), EventCheckpoint::new(10, 3));
+    assert_eq!(items[2].checkpoint(), EventCheckpoint::new(12, 1));
+
+    // Resuming from the checkpoint of a handled event doesn't deliver it again
+    let (items, _) = subscription_items(&filter, items[0].checkpoint(), &transactions()).unwrap();
+    assert_eq!(amounts(&items), vec!["6", "7"]);
+
+    let (items, last_version) =
+        subscription_items(&filter, EventCheckpoint::at_version(14), &[]).unwrap();
+    assert!(items.is_empty());
+    assert_eq!(last_version, None);
+
+    let gap = SubscriptionItem::Gap { start: 5, end: 20 };
+    assert_eq!(gap.checkpoint(), EventCheckpoint::at_version(20));
+}
+
+#[test]
+fn test_poll_action() {
+    let filter = ModuleEventFilter::new()
+        .with_type(TypeTag::from_str("0x1::fungible_asset::Deposit").unwrap());
+    let from = EventCheckpoint::at_version(10);
+    let poll = |page_size, result| poll_action(&filter, from, 10, page_size, result);
+    let page = || Ok(FullnodeResponse::new(transactions(), state(0)));
+
+    // A full page is followed by another poll right away
+    let PollAction::Deliver {
+        items,
+        next_version,
+        wait,
+    } = poll(4, page())
+    else {
+        panic!("Expected items");
+    };
+    assert_eq!(amounts(&items), vec!["5", "6", "7"]);
+    assert_eq!(next_version, 14);
+    assert!(!wait);
+
+    // A short page means the subscription caught up
+    let PollAction::Deliver { wait, .. } = poll(100, page()) else {
+        panic!("Expected items");
+    };
+    assert!(wait);
+
+    let empty = Ok(FullnodeResponse::new(vec![], state(0)));
+    assert!(matches!(poll(100, empty), PollAction::Wait));
+}
+
+#[test]
+fn test_poll_action_errors() {
+    let filter = ModuleEventFilter::new();
+    let poll = |result| poll_action(&filter, EventCheckpoint::at_version(10), 10, 100, result);
+
+    // Pruned versions are skipped over with a gap, without waiting
+    let PollAction::Deliver {
+        items,
+        next_version,
+        wait,
+    } = poll(Err(api_error(
+        AptosErrorCode::VersionPruned,
+        Some(state(50)),
+    )))
+    else {
+        panic!("Expected a gap");
+    };
+    assert_eq!(items, vec![SubscriptionItem::Gap { start: 10, end: 50 }]);
+    assert_eq!(next_version, 50);
+    assert!(!wait);
+
+    // A node that claims the version is both pruned and kept, or doesn't say what it kept
+    assert!(matches!(
+        poll(Err(api_error(
+            AptosErrorCode::VersionPruned,
+            Some(state(5))
+        ))),
+        PollAction::Fail(_)
+    ));
+    assert!(matches!(
+        poll(Err(api_error(AptosErrorCode::VersionPruned, None))),
+        PollAction::Fail(_)
+    ));
+
+    // Polling past the ledger's latest version waits for it to grow
+    assert!(matches!(
+        poll(Err(api_error(
+            AptosErrorCode::VersionNotFound,
+            Some(state(0))
+        ))),
+        PollAction::Wait
+    ));
+
+    assert!(matches!(
+        poll(Err(RestError::Timeout("test"))),
+        PollAction::Fail(RestError::Timeout(_))
+    ));
+}
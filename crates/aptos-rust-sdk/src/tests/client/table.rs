@@ -0,0 +1,234 @@
+use super::{mock_fullnode, MockResponse};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::state_key::TableHandle;
+use aptos_rust_sdk_types::api_types::table::{
+    BigOrderedMap, BigOrderedMapChild, BigOrderedMapNode, OrderedMap, OrderedMapEntry, SmartTable,
+    SmartTableEntry, StorageSlot, StorageSlotsAllocator, StoredSlot, Table, TableWithLength,
+};
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use serde_json::json;
+use std::str::FromStr;
+
+fn handle(address: &str) -> TableHandle {
+    TableHandle(AccountAddress::from_str(address).unwrap())
+}
+
+fn leaf(entries: &[(u64, u64)], next: u64) -> BigOrderedMapNode<u64, u64> {
+    BigOrderedMapNode::V1 {
+        is_leaf: true,
+        children: OrderedMap::SortedVectorMap {
+            entries: entries
+                .iter()
+                .map(|(key, value)| OrderedMapEntry {
+                    key: *key,
+                    value: BigOrderedMapChild::Leaf { value: *value },
+                })
+                .collect(),
+        },
+        prev: 0,
+        next,
+    }
+}
+
+/// A map whose root points at the leaves in slots 2 and 3
+fn big_ordered_map() -> BigOrderedMap<u64, u64> {
+    let child = |key, slot_index| OrderedMapEntry {
+        key,
+        value: BigOrderedMapChild::Inner {
+            node_index: StoredSlot { slot_index },
+        },
+    };
+    BigOrderedMap::BPlusTreeMap {
+        root: BigOrderedMapNode::V1 {
+            is_leaf: false,
+            children: OrderedMap::SortedVectorMap {
+                entries: vec![child(20, 2), child(40, 3)],
+            },
+            prev: 0,
+            next: 0,
+        },
+        nodes: StorageSlotsAllocator::V1 {
+            slots: Some(Table::new(handle("0xc"))),
+            new_slot_index: 4,
+            should_reuse: false,
+            reuse_head_index: 0,
+            reuse_spare_count: 0,
+        },
+        min_leaf_index: 2,
+        max_leaf_index: 3,
+        constant_kv_size: true,
+        inner_max_degree: 64,
+        leaf_max_degree: 64,
+    }
+}
+
+fn slot(slot: StorageSlot<BigOrderedMapNode<u64, u64>>, ledger_version: u64) -> MockResponse {
+    MockResponse::bcs(aptos_bcs::to_bytes(&slot).unwrap()).at_version(ledger_version)
+}
+
+#[tokio::test]
+async fn test_get_table_item() {
+    let (client, requests) = mock_fullnode(vec![MockResponse::json(json!("100"))]).await;
+    let item = client
+        .get_table_item(
+            handle("0xa"),
+            TypeTag::from_str("address").unwrap(),
+            TypeTag::from_str("u64").unwrap(),
+            json!("0xb"),
+            Some(5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(item.into_inner(), json!("100"));
+
+    let request = &requests.lock().unwrap()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(
+        request.target,
+        format!("/v1/tables/{}/item?ledger_version=5", handle("0xa").0)
+    );
+    assert_eq!(request.headers["content-type"], "application/json");
+    assert_eq!(request.headers["accept"], "application/json");
+    assert_eq!(
+        request.json_body(),
+        json!({"key_type": "address", "value_type": "u64", "key": "0xb"})
+    );
+}
+
+#[tokio::test]
+async fn test_get_raw_table_item() {
+    let (client, requests) = mock_fullnode(vec![MockResponse::bcs(vec![7, 8])]).await;
+    let item = client
+        .get_raw_table_item(handle("0xa"), &[1, 2], None)
+        .await
+        .unwrap();
+    assert_eq!(item.into_inner(), vec![7, 8]);
+
+    let request = &requests.lock().unwrap()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(
+        request.target,
+        format!("/v1/tables/{}/raw_item", handle("0xa").0)
+    );
+    assert_eq!(request.headers["accept"], "application/x-bcs");
+    assert_eq!(request.json_body(), json!({"key": "0x0102"}));
+}
+
+#[tokio::test]
+async fn test_get_smart_table_entries() {
+    let table = SmartTable::<u64, u64> {
+        buckets: TableWithLength {
+            inner: Table::new(handle("0xa")),
+            length: 2,
+        },
+        num_buckets: 2,
+        level: 1,
+        size: 3,
+        split_load_threshold: 75,
+        target_bucket_size: 0,
+    };
+    let bucket = |entries: &[(u64, u64)]| {
+        let entries: Vec<_> = entries
+            .iter()
+            .map(|(key, value)| SmartTableEntry {
+                hash: 0,
+                key: *key,
+                value: *value,
+            })
+            .collect();
+        aptos_bcs::to_bytes(&entries).unwrap()
+    };
+    let (client, requests) = mock_fullnode(vec![
+        MockResponse::bcs(bucket(&[(1, 10)])).at_version(700),
+        MockResponse::bcs(bucket(&[(2, 20), (3, 30)])).at_version(710),
+    ])
+    .await;
+
+    let entries = client.get_smart_table_entries(&table, None).await.unwrap();
+    assert_eq!(entries.state().version, 710);
+    assert_eq!(entries.into_inner(), vec![(1, 10), (2, 20), (3, 30)]);
+
+    // The buckets after the first are read at the first one's version
+    let requests = requests.lock().unwrap();
+    let targets: Vec<_> = requests
+        .iter()
+        .map(|request| request.target.as_str())
+        .collect();
+    assert_eq!(
+        targets,
+        [
+            format!("/v1/tables/{}/raw_item", handle("0xa").0),
+            format!("/v1/tables/{}/raw_item?ledger_version=700", handle("0xa").0),
+        ]
+    );
+    assert_eq!(
+        requests[0].json_body(),
+        json!({"key": "0x0000000000000000"})
+    );
+    assert_eq!(
+        requests[1].json_body(),
+        json!({"key": "0x0100000000000000"})
+    );
+}
+
+#[tokio::test]
+async fn test_get_big_ordered_map_entries() {
+    let (client, requests) = mock_fullnode(vec![
+        slot(
+            StorageSlot::Some {
+                value: leaf(&[(10, 1), (20, 2)], 3),
+            },
+            500,
+        ),
+        slot(
+            StorageSlot::Some {
+                value: leaf(&[(30, 3), (40, 4)], 0),
+            },
+            510,
+        ),
+    ])
+    .await;
+
+    // The leaves are followed from the leftmost one until there's no next leaf
+    let entries = client
+        .get_big_ordered_map_entries(&big_ordered_map(), Some(400))
+        .await
+        .unwrap();
+    assert_eq!(
+        entries.into_inner(),
+        vec![(10, 1), (20, 2), (30, 3), (40, 4)]
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    for (request, key) in requests
+        .iter()
+        .zip(["0x0200000000000000", "0x0300000000000000"])
+    {
+        assert_eq!(
+            request.target,
+            format!("/v1/tables/{}/raw_item?ledger_version=400", handle("0xc").0)
+        );
+        assert_eq!(request.json_body(), json!({ "key": key }));
+    }
+}
+
+#[tokio::test]
+async fn test_get_big_ordered_map_entries_empty_slot() {
+    let (client, _) = mock_fullnode(vec![
+        slot(
+            StorageSlot::Some {
+                value: leaf(&[(10, 1)], 3),
+            },
+            500,
+        ),
+        slot(StorageSlot::Empty { next: 0 }, 500),
+    ])
+    .await;
+
+    let error = client
+        .get_big_ordered_map_entries(&big_ordered_map(), None)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("node 3 is empty"));
+}
@@ -0,0 +1,61 @@
+use crate::client::rest_api::decode_view_values;
+use crate::tests::transaction::unreachable_client;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::module_id::ModuleId;
+use aptos_rust_sdk_types::api_types::move_value::MoveValue;
+use aptos_rust_sdk_types::api_types::transaction::ViewFunction;
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use aptos_rust_sdk_types::error::RestError;
+use std::str::FromStr;
+
+fn balance_view() -> ViewFunction {
+    ViewFunction::new(
+        ModuleId::new(AccountAddress::ONE, "coin".to_string()),
+        "balance".to_string(),
+        vec![TypeTag::from_str("0x1::aptos_coin::AptosCoin").unwrap()],
+        vec![AccountAddress::ONE.into()],
+    )
+}
+
+#[test]
+fn test_view_function_bcs() {
+    let view_function = balance_view();
+    assert_eq!(view_function.args(), &[AccountAddress::ONE.to_vec()]);
+
+    // Module, function, type arguments, then length prefixed arguments
+    let bytes = aptos_bcs::to_bytes(&view_function).unwrap();
+    let expected_args = [vec![1u8, 32], AccountAddress::ONE.to_vec()].concat();
+    assert!(bytes.ends_with(&expected_args));
+    assert_eq!(
+        aptos_bcs::from_bytes::<ViewFunction>(&bytes).unwrap(),
+        view_function
+    );
+}
+
+#[test]
+fn test_decode_view_values() {
+    let return_types = [
+        TypeTag::U64,
+        TypeTag::from_str("0x1::option::Option<0x1::string::String>").unwrap(),
+    ];
+    let values = vec![
+        aptos_bcs::to_bytes(&100u64).unwrap(),
+        aptos_bcs::to_bytes(&Some("APT")).unwrap(),
+    ];
+    assert_eq!(
+        decode_view_values(&return_types, &values).unwrap(),
+        vec![MoveValue::U64(100), Some("APT").into()]
+    );
+
+    // The number of values must match the return types
+    assert!(decode_view_values(&return_types[..1], &values).is_err());
+    assert!(decode_view_values(&return_types[1..], &values[..1]).is_err());
+}
+
+#[tokio::test]
+async fn test_view_unreachable() {
+    let result = unreachable_client()
+        .view(&balance_view(), &[TypeTag::U64])
+        .await;
+    assert!(matches!(result, Err(RestError::Unknown(_))));
+}
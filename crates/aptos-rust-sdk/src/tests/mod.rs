@@ -1,2 +1,4 @@
+mod account;
 mod client;
 mod crypto;
+mod transaction;
@@ -0,0 +1,227 @@
+use super::{random_account, transfer_transaction};
+use crate::account::signer::Signer;
+use crate::transaction::explain::{PayloadExplanation, SignerRole, TransactionExplanation};
+use crate::transaction::partially_signed::PartiallySignedTransaction;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::chain_id::ChainId;
+use aptos_rust_sdk_types::api_types::module_id::ModuleId;
+use aptos_rust_sdk_types::api_types::transaction::{
+    EntryFunction, Multisig, MultisigTransactionPayload, RawTransaction, RawTransactionWithData,
+    TransactionPayload,
+};
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use serde_json::json;
+use std::str::FromStr;
+
+fn aptos_account_abi() -> serde_json::Value {
+    json!({
+        "address": "0x1",
+        "name": "aptos_account",
+        "friends": [],
+        "exposed_functions": [{
+            "name": "transfer",
+            "visibility": "public",
+            "is_entry": true,
+            "is_view": false,
+            "generic_type_params": [],
+            "params": ["&signer", "address", "u64"],
+            "return": []
+        }],
+        "structs": []
+    })
+}
+
+#[test]
+fn test_explain_transfer() {
+    let sender = random_account();
+    let raw_txn = transfer_transaction(sender.address(), 5);
+
+    let explanation = TransactionExplanation::new(&raw_txn, Some(&aptos_account_abi()));
+    assert_eq!(explanation.sender, sender.address());
+    assert_eq!(explanation.sequence_number, 5);
+    assert_eq!(explanation.chain, "testnet (2)");
+    assert_eq!(explanation.expiration, "2023-11-14 22:13:20 UTC");
+    assert_eq!(explanation.max_fee, "0.002 APT");
+    let PayloadExplanation::EntryFunction(entry_function) = &explanation.payload else {
+        panic!("Expected entry function, got {:?}", explanation.payload);
+    };
+    assert_eq!(entry_function.function, "0x1::aptos_account::transfer");
+    assert_eq!(
+        entry_function.arguments[0].type_.as_deref(),
+        Some("address")
+    );
+    assert_eq!(
+        entry_function.arguments[0].value,
+        AccountAddress::ONE.to_string()
+    );
+    assert_eq!(entry_function.arguments[1].type_.as_deref(), Some("u64"));
+    assert_eq!(entry_function.arguments[1].value, "1");
+
+    let text = explanation.to_string();
+    assert!(text.contains("[1] u64: 1"));
+    assert!(text.contains("Max fee:          0.002 APT"));
+}
+
+#[test]
+fn test_explain_without_abi() {
+    let sender = random_account();
+    let explanation = TransactionExplanation::new(&transfer_transaction(sender.address(), 5), None);
+    let PayloadExplanation::EntryFunction(entry_function) = &explanation.payload else {
+        panic!("Expected entry function, got {:?}", explanation.payload);
+    };
+    assert_eq!(entry_function.arguments[0].type_, None);
+    assert_eq!(entry_function.arguments[1].value, "0x0100000000000000");
+}
+
+#[test]
+fn test_explain_generic_arguments() {
+    let sender = random_account();
+    let metadata = TypeTag::from_str("0x1::fungible_asset::Metadata").unwrap();
+    let raw_txn = RawTransaction::new(
+        sender.address(),
+        0,
+        TransactionPayload::EntryFunction(EntryFunction::new(
+            ModuleId::new(
+                AccountAddress::from_str("0xcafe").unwrap(),
+                "vault".to_string(),
+            ),
+            "deposit".to_string(),
+            vec![metadata],
+            vec![
+                AccountAddress::ONE.to_vec(),
+                aptos_bcs::to_bytes(&vec![AccountAddress::ONE, AccountAddress::TWO]).unwrap(),
+                aptos_bcs::to_bytes("hello").unwrap(),
+                aptos_bcs::to_bytes(&Some(7u128)).unwrap(),
+                aptos_bcs::to_bytes(&true).unwrap(),
+            ],
+        )),
+        100_000,
+        150,
+        0,
+        ChainId::Other(42),
+    );
+    let abi = json!({
+        "exposed_functions": [{
+            "name": "deposit",
+            "params": [
+                "signer",
+                "0x1::object::Object<T0>",
+                "vector<address>",
+                "0x1::string::String",
+                "0x1::option::Option<u128>",
+                "bool"
+            ]
+        }]
+    });
+
+    let explanation = TransactionExplanation::new(&raw_txn, Some(&abi));
+    assert_eq!(explanation.chain, "chain 42");
+    assert_eq!(explanation.expiration, "1970-01-01 00:00:00 UTC");
+    assert_eq!(explanation.max_fee, "0.15 APT");
+    let PayloadExplanation::EntryFunction(entry_function) = &explanation.payload else {
+        panic!("Expected entry function, got {:?}", explanation.payload);
+    };
+    assert_eq!(
+        entry_function.type_arguments,
+        vec!["0x1::fungible_asset::Metadata"]
+    );
+    let values: Vec<_> = entry_function
+        .arguments
+        .iter()
+        .map(|argument| argument.value.as_str())
+        .collect();
+    assert_eq!(
+        entry_function.arguments[0].type_.as_deref(),
+        Some("0x1::object::Object<0x1::fungible_asset::Metadata>")
+    );
+    assert_eq!(values[0], AccountAddress::ONE.to_string());
+    assert_eq!(
+        values[1],
+        format!("[{}, {}]", AccountAddress::ONE, AccountAddress::TWO)
+    );
+    assert_eq!(values[2], "\"hello\"");
+    assert_eq!(values[3], "some(7)");
+    assert_eq!(values[4], "true");
+}
+
+#[test]
+fn test_explain_multisig() {
+    let sender = random_account();
+    let multisig_address = AccountAddress::from_str("0xcafe").unwrap();
+    let TransactionPayload::EntryFunction(entry_function) =
+        transfer_transaction(sender.address(), 0).payload().clone()
+    else {
+        unreachable!()
+    };
+    let multisig_transaction = |transaction_payload| {
+        RawTransaction::new(
+            sender.address(),
+            0,
+            TransactionPayload::Multisig(Multisig {
+                multisig_address,
+                transaction_payload,
+            }),
+            100_000,
+            150,
+            0,
+            ChainId::Testnet,
+        )
+    };
+
+    let raw_txn = multisig_transaction(Some(MultisigTransactionPayload::EntryFunction(
+        entry_function,
+    )));
+    let explanation = TransactionExplanation::new(&raw_txn, Some(&aptos_account_abi()));
+    let PayloadExplanation::Multisig {
+        multisig_address: address,
+        entry_function: Some(entry_function),
+    } = &explanation.payload
+    else {
+        panic!("Expected multisig, got {:?}", explanation.payload);
+    };
+    assert_eq!(*address, multisig_address);
+    assert_eq!(entry_function.function, "0x1::aptos_account::transfer");
+    assert!(explanation
+        .to_string()
+        .contains(&format!("Multisig account: {}", multisig_address)));
+
+    // Executing a transaction already stored on the multisig account
+    let explanation = TransactionExplanation::new(&multisig_transaction(None), None);
+    assert!(matches!(
+        explanation.payload,
+        PayloadExplanation::Multisig {
+            entry_function: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_explain_signers() {
+    let sender = random_account();
+    let fee_payer = random_account();
+    let mut txn =
+        PartiallySignedTransaction::new(RawTransactionWithData::new_multi_agent_with_fee_payer(
+            transfer_transaction(sender.address(), 5),
+            vec![],
+            fee_payer.address(),
+        ))
+        .unwrap();
+    txn.sign(&sender).unwrap();
+    txn.sign(&fee_payer).unwrap();
+
+    let explanation = TransactionExplanation::from_signed_txn(&txn.finalize().unwrap(), None);
+    assert_eq!(explanation.signers.len(), 2);
+    assert_eq!(explanation.signers[0].role, SignerRole::Sender);
+    assert_eq!(explanation.signers[0].address, sender.address());
+    assert_eq!(explanation.signers[1].role, SignerRole::FeePayer);
+    assert_eq!(explanation.signers[1].address, fee_payer.address());
+    assert_eq!(
+        explanation.signers[1].public_key,
+        Some(format!(
+            "0x{}",
+            hex::encode(fee_payer.public_key().to_bytes())
+        ))
+    );
+    assert!(explanation.to_string().contains("fee payer"));
+}
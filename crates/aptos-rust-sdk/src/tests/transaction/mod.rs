@@ -0,0 +1,50 @@
+use crate::account::account_key::AccountKey;
+use crate::client::config::AptosNetwork;
+use crate::client::rest_api::AptosFullnodeClient;
+use aptos_crypto::ed25519::Ed25519PrivateKey;
+use aptos_crypto::Uniform;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::chain_id::ChainId;
+use aptos_rust_sdk_types::api_types::module_id::ModuleId;
+use aptos_rust_sdk_types::api_types::transaction::{
+    EntryFunction, RawTransaction, TransactionPayload,
+};
+use url::Url;
+
+mod builder;
+mod explain;
+mod offline;
+mod partially_signed;
+mod payloads;
+mod pipeline;
+mod replacement;
+mod sequence_number;
+mod simulation;
+mod sponsored;
+
+fn random_account() -> AccountKey {
+    AccountKey::from_private_key(Ed25519PrivateKey::generate(&mut rand::thread_rng()))
+}
+
+fn transfer_transaction(sender: AccountAddress, sequence_number: u64) -> RawTransaction {
+    RawTransaction::new(
+        sender,
+        sequence_number,
+        TransactionPayload::EntryFunction(EntryFunction::new(
+            ModuleId::new(AccountAddress::ONE, "aptos_account".to_string()),
+            "transfer".to_string(),
+            vec![],
+            vec![AccountAddress::ONE.to_vec(), 1u64.to_le_bytes().to_vec()],
+        )),
+        2000,
+        100,
+        1_700_000_000,
+        ChainId::Testnet,
+    )
+}
+
+/// A client for a node that refuses every connection
+pub(super) fn unreachable_client() -> AptosFullnodeClient {
+    let url = Url::parse("http://127.0.0.1:1").unwrap();
+    AptosFullnodeClient::builder(AptosNetwork::new("unreachable", url.clone(), url)).build()
+}
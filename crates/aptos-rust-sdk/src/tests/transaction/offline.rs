@@ -0,0 +1,82 @@
+use super::{random_account, transfer_transaction};
+use crate::account::signer::Signer;
+use crate::transaction::offline::{SignedTransactionFile, UnsignedTransactionFile};
+
+#[test]
+fn test_offline_flow() {
+    let sender = random_account();
+    let raw_txn = transfer_transaction(sender.address(), 12);
+
+    // Online machine exports the transaction
+    let unsigned = UnsignedTransactionFile::new(&raw_txn);
+    let json = unsigned.to_json().unwrap();
+    assert!(json.contains("::aptos_account::transfer"));
+    assert!(json.contains("\"sequence_number\": 12"));
+
+    // Air-gapped machine signs it
+    let unsigned = UnsignedTransactionFile::from_json(&json).unwrap();
+    assert_eq!(unsigned.summary().max_gas_amount, 2000);
+    let signed_json = unsigned.sign(&sender).unwrap().to_json().unwrap();
+
+    // Online machine validates it before submitting
+    let signed = SignedTransactionFile::from_json(&signed_json).unwrap();
+    signed.validate(2, 1_600_000_000).unwrap();
+    assert_eq!(signed.signed_txn().unwrap().raw_txn(), &raw_txn);
+}
+
+#[test]
+fn test_offline_files() {
+    let sender = random_account();
+    let dir = std::env::temp_dir().join(format!("aptos-offline-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    UnsignedTransactionFile::new(&transfer_transaction(sender.address(), 0))
+        .write_to(&dir.join("unsigned.json"))
+        .unwrap();
+    let signed = UnsignedTransactionFile::read_from(&dir.join("unsigned.json"))
+        .unwrap()
+        .sign(&sender)
+        .unwrap();
+    signed.write_to(&dir.join("signed.json")).unwrap();
+    let loaded = SignedTransactionFile::read_from(&dir.join("signed.json"));
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(loaded.unwrap(), signed);
+}
+
+#[test]
+fn test_offline_rejects_tampered_summary() {
+    let sender = random_account();
+    let json = UnsignedTransactionFile::new(&transfer_transaction(sender.address(), 12))
+        .to_json()
+        .unwrap()
+        .replace("\"max_gas_amount\": 2000", "\"max_gas_amount\": 20");
+    UnsignedTransactionFile::from_json(&json).expect_err("Summary does not match");
+}
+
+#[test]
+fn test_offline_validation() {
+    let sender = random_account();
+    let signed = UnsignedTransactionFile::new(&transfer_transaction(sender.address(), 12))
+        .sign(&sender)
+        .unwrap();
+
+    signed.validate(1, 1_600_000_000).expect_err("Wrong chain");
+    signed.validate(2, 1_700_000_000).expect_err("Expired");
+
+    // The sender's signature over a different transaction, spliced onto this one
+    let other_signed = UnsignedTransactionFile::new(&transfer_transaction(sender.address(), 13))
+        .sign(&sender)
+        .unwrap();
+    let mut json: serde_json::Value = serde_json::from_str(&signed.to_json().unwrap()).unwrap();
+    let other_json: serde_json::Value =
+        serde_json::from_str(&other_signed.to_json().unwrap()).unwrap();
+    let bytes = hex::decode(&json["signed_transaction"].as_str().unwrap()[2..]).unwrap();
+    let other_bytes =
+        hex::decode(&other_json["signed_transaction"].as_str().unwrap()[2..]).unwrap();
+    let raw_txn_len = transfer_transaction(sender.address(), 12).to_vec().len();
+    let mut spliced = bytes[..raw_txn_len].to_vec();
+    spliced.extend_from_slice(&other_bytes[raw_txn_len..]);
+    json["signed_transaction"] = format!("0x{}", hex::encode(spliced)).into();
+    SignedTransactionFile::from_json(&json.to_string()).expect_err("Signature does not match");
+}
@@ -0,0 +1,148 @@
+use super::{random_account, transfer_transaction};
+use crate::account::signer::Signer;
+use crate::transaction::partially_signed::PartiallySignedTransaction;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::{
+    GenerateSigningMessage, RawTransactionWithData,
+};
+use aptos_rust_sdk_types::api_types::transaction_authenticator::TransactionAuthenticator;
+
+#[test]
+fn test_multi_agent_flow() {
+    let sender = random_account();
+    let secondary = random_account();
+    let raw_txn = RawTransactionWithData::new_multi_agent(
+        transfer_transaction(sender.address(), 3),
+        vec![secondary.address()],
+    );
+
+    let mut txn = PartiallySignedTransaction::new(raw_txn.clone()).unwrap();
+    assert_eq!(
+        txn.required_signers(),
+        vec![sender.address(), secondary.address()]
+    );
+
+    // Each signer only sees the text form of the transaction
+    txn.sign(&secondary).unwrap();
+    assert!(!txn.is_complete());
+    assert_eq!(txn.missing_signers(), vec![sender.address()]);
+    let hex = txn.to_hex().unwrap();
+
+    let mut txn = PartiallySignedTransaction::from_hex(&hex).unwrap();
+    txn.sign(&sender).unwrap();
+    assert!(txn.is_complete());
+    let base64 = txn.to_base64().unwrap();
+
+    let txn = PartiallySignedTransaction::from_base64(&base64).unwrap();
+    let signed = txn.finalize().unwrap();
+    assert_eq!(signed.raw_txn(), raw_txn.raw_txn());
+    match signed.authenticator() {
+        TransactionAuthenticator::MultiAgent {
+            sender: sender_authenticator,
+            secondary_signer_addresses,
+            secondary_signers,
+        } => {
+            let message = raw_txn.generate_signing_message().unwrap();
+            sender_authenticator.verify_arbitrary_msg(&message).unwrap();
+            assert_eq!(secondary_signer_addresses, &vec![secondary.address()]);
+            secondary_signers[0].verify_arbitrary_msg(&message).unwrap();
+        }
+        other => panic!("Expected multi-agent authenticator, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_fee_payer_flow() {
+    let sender = random_account();
+    let secondary = random_account();
+    let fee_payer = random_account();
+    let raw_txn = RawTransactionWithData::new_multi_agent_with_fee_payer(
+        transfer_transaction(sender.address(), 3),
+        vec![secondary.address()],
+        fee_payer.address(),
+    );
+
+    let mut txn = PartiallySignedTransaction::new(raw_txn).unwrap();
+    txn.sign(&fee_payer).unwrap();
+    txn.sign(&sender).unwrap();
+    txn.sign(&secondary).unwrap();
+
+    match txn.finalize().unwrap().authenticator() {
+        TransactionAuthenticator::FeePayer {
+            secondary_signer_addresses,
+            secondary_signers,
+            fee_payer_address,
+            ..
+        } => {
+            assert_eq!(secondary_signer_addresses, &vec![secondary.address()]);
+            assert_eq!(secondary_signers.len(), 1);
+            assert_eq!(*fee_payer_address, fee_payer.address());
+        }
+        other => panic!("Expected fee payer authenticator, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_rejects_unknown_and_invalid_signatures() {
+    let sender = random_account();
+    let secondary = random_account();
+    let raw_txn = RawTransactionWithData::new_multi_agent(
+        transfer_transaction(sender.address(), 3),
+        vec![secondary.address()],
+    );
+    let mut txn = PartiallySignedTransaction::new(raw_txn).unwrap();
+
+    txn.sign(&random_account())
+        .expect_err("Signer is not part of the transaction");
+
+    // A signature over a different transaction is rejected
+    let other_message = RawTransactionWithData::new_multi_agent(
+        transfer_transaction(sender.address(), 4),
+        vec![secondary.address()],
+    )
+    .generate_signing_message()
+    .unwrap();
+    txn.add_signature(secondary.address(), secondary.sign_message(&other_message))
+        .expect_err("Signature is for another transaction");
+
+    txn.sign(&sender).unwrap();
+    txn.finalize().expect_err("Secondary signer has not signed");
+}
+
+#[test]
+fn test_finalize_verifies_loaded_signatures() {
+    let sender = random_account();
+    let secondary = random_account();
+    let mut txn = PartiallySignedTransaction::new(RawTransactionWithData::new_multi_agent(
+        transfer_transaction(sender.address(), 3),
+        vec![secondary.address()],
+    ))
+    .unwrap();
+    txn.sign(&sender).unwrap();
+    txn.sign(&secondary).unwrap();
+
+    // The secondary signer's signature is last, so this corrupts it
+    let mut bytes = txn.to_bytes().unwrap();
+    *bytes.last_mut().unwrap() ^= 1;
+    let corrupted = PartiallySignedTransaction::from_bytes(&bytes).unwrap();
+    assert!(corrupted.is_complete());
+    corrupted
+        .finalize()
+        .expect_err("Secondary signature was corrupted");
+
+    PartiallySignedTransaction::from_bytes(&txn.to_bytes().unwrap())
+        .unwrap()
+        .finalize()
+        .unwrap();
+}
+
+#[test]
+fn test_rejects_unknown_fee_payer() {
+    let sender = random_account();
+    PartiallySignedTransaction::new(RawTransactionWithData::new_multi_agent_with_fee_payer(
+        transfer_transaction(sender.address(), 3),
+        vec![],
+        AccountAddress::ZERO,
+    ))
+    .expect_err("Fee payer address is unknown");
+}
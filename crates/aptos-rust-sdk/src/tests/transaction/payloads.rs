@@ -0,0 +1,124 @@
+use crate::transaction::payloads::{
+    batch_transfer, object_core, transfer, transfer_coins, transfer_fungible_asset,
+    transfer_object, transfer_object_call,
+};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::{EntryFunction, TransactionPayload};
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use std::str::FromStr;
+
+fn entry_function(payload: &TransactionPayload) -> &EntryFunction {
+    payload
+        .entry_function()
+        .expect("Transfers are entry functions")
+}
+
+fn function_name(entry_function: &EntryFunction) -> String {
+    format!(
+        "0x{}::{}::{}",
+        entry_function.module().address().short_str_lossless(),
+        entry_function.module().name(),
+        entry_function.function()
+    )
+}
+
+#[test]
+fn test_transfer() {
+    let payload = transfer(AccountAddress::TWO, 1);
+    let entry_function = entry_function(&payload);
+    assert_eq!(
+        function_name(entry_function),
+        "0x1::aptos_account::transfer"
+    );
+    assert!(entry_function.ty_args().is_empty());
+    assert_eq!(
+        entry_function.args(),
+        [AccountAddress::TWO.to_vec(), 1u64.to_le_bytes().to_vec()]
+    );
+}
+
+#[test]
+fn test_transfer_coins() {
+    let coin_type = TypeTag::from_str("0x1::aptos_coin::AptosCoin").unwrap();
+    let payload = transfer_coins(coin_type.clone(), AccountAddress::TWO, 500);
+    let entry_function = entry_function(&payload);
+    assert_eq!(
+        function_name(entry_function),
+        "0x1::aptos_account::transfer_coins"
+    );
+    assert_eq!(entry_function.ty_args(), [coin_type]);
+    assert_eq!(
+        entry_function.args(),
+        [AccountAddress::TWO.to_vec(), 500u64.to_le_bytes().to_vec()]
+    );
+}
+
+#[test]
+fn test_batch_transfer() {
+    let payload = batch_transfer(&[(AccountAddress::ONE, 1), (AccountAddress::TWO, 2)]);
+    let entry_function = entry_function(&payload);
+    assert_eq!(
+        function_name(entry_function),
+        "0x1::aptos_account::batch_transfer"
+    );
+
+    // Each argument is a length prefixed vector
+    let mut recipients = vec![2];
+    recipients.extend(AccountAddress::ONE.to_vec());
+    recipients.extend(AccountAddress::TWO.to_vec());
+    let mut amounts = vec![2];
+    amounts.extend(1u64.to_le_bytes());
+    amounts.extend(2u64.to_le_bytes());
+    assert_eq!(entry_function.args(), [recipients, amounts]);
+
+    let empty = batch_transfer(&[]);
+    assert_eq!(empty.entry_function().unwrap().args(), [vec![0], vec![0]]);
+}
+
+#[test]
+fn test_transfer_fungible_asset() {
+    let metadata = AccountAddress::from_str("0xa").unwrap();
+    let payload = transfer_fungible_asset(metadata, AccountAddress::TWO, 7);
+    let entry_function = entry_function(&payload);
+    assert_eq!(
+        function_name(entry_function),
+        "0x1::primary_fungible_store::transfer"
+    );
+    assert_eq!(
+        entry_function.ty_args()[0].to_canonical_string(),
+        "0x1::fungible_asset::Metadata"
+    );
+    assert_eq!(
+        entry_function.args(),
+        [
+            metadata.to_vec(),
+            AccountAddress::TWO.to_vec(),
+            7u64.to_le_bytes().to_vec()
+        ]
+    );
+}
+
+#[test]
+fn test_transfer_object() {
+    let object = AccountAddress::from_str("0xb").unwrap();
+    let payload = transfer_object(object_core(), object, AccountAddress::TWO);
+    let entry_function = entry_function(&payload);
+    assert_eq!(function_name(entry_function), "0x1::object::transfer");
+    assert_eq!(
+        entry_function.ty_args()[0].to_canonical_string(),
+        "0x1::object::ObjectCore"
+    );
+    assert_eq!(
+        entry_function.args(),
+        [object.to_vec(), AccountAddress::TWO.to_vec()]
+    );
+
+    let payload = transfer_object_call(object, AccountAddress::TWO);
+    let entry_function = payload.entry_function().unwrap();
+    assert_eq!(function_name(entry_function), "0x1::object::transfer_call");
+    assert!(entry_function.ty_args().is_empty());
+    assert_eq!(
+        entry_function.args(),
+        [object.to_vec(), AccountAddress::TWO.to_vec()]
+    );
+}
@@ -0,0 +1,124 @@
+use super::{random_account, transfer_transaction, unreachable_client};
+use crate::account::account_key::AccountKey;
+use crate::account::signer::Signer;
+use crate::transaction::pipeline::{
+    ItemResult, PipelineStats, SubmissionOutcome, SubmissionPipeline,
+};
+use aptos_crypto::compat::Sha3_256;
+use aptos_rust_sdk_types::api_types::chain_id::ChainId;
+use aptos_rust_sdk_types::error::RestError;
+use ed25519_dalek::Digest;
+use futures_util::{stream, StreamExt};
+use std::time::Duration;
+
+fn result(index: usize, attempts: u32, outcome: SubmissionOutcome) -> ItemResult {
+    ItemResult {
+        index,
+        sender: random_account().address(),
+        attempts,
+        outcome,
+    }
+}
+
+#[test]
+fn test_committed_hash() {
+    let sender = random_account();
+    let signed_txn = sender
+        .sign_transaction(transfer_transaction(sender.address(), 0))
+        .unwrap();
+
+    let mut hasher = Sha3_256::new();
+    hasher.update(Sha3_256::digest(b"APTOS::Transaction"));
+    hasher.update([0]);
+    hasher.update(signed_txn.to_vec());
+    let expected = format!("0x{}", hex::encode(hasher.finalize()));
+    assert_eq!(signed_txn.committed_hash(), expected);
+}
+
+#[test]
+fn test_pipeline_needs_signers() {
+    let signers: Vec<AccountKey> = vec![];
+    assert!(SubmissionPipeline::new(unreachable_client(), ChainId::Testnet, signers).is_err());
+}
+
+#[test]
+fn test_stats() {
+    let results = vec![
+        result(
+            0,
+            1,
+            SubmissionOutcome::Succeeded {
+                hash: "0x1".to_string(),
+                version: 10,
+                gas_used: 5,
+            },
+        ),
+        result(
+            1,
+            3,
+            SubmissionOutcome::ExecutionFailed {
+                hash: "0x2".to_string(),
+                version: 11,
+                gas_used: 7,
+                vm_status: "Move abort".to_string(),
+            },
+        ),
+        result(
+            2,
+            3,
+            SubmissionOutcome::Expired {
+                hash: "0x3".to_string(),
+            },
+        ),
+        result(
+            3,
+            1,
+            SubmissionOutcome::Error {
+                hash: None,
+                error: RestError::Timeout("test"),
+            },
+        ),
+    ];
+    let stats = PipelineStats::from_results(&results, Duration::from_secs(2));
+    assert_eq!(stats.total, 4);
+    assert_eq!(stats.succeeded, 1);
+    assert_eq!(stats.execution_failed, 1);
+    assert_eq!(stats.expired, 1);
+    assert_eq!(stats.errored, 1);
+    assert_eq!(stats.retries, 4);
+    assert_eq!(stats.gas_used, 12);
+    assert_eq!(stats.committed(), 2);
+    assert_eq!(stats.committed_per_second(), 1.0);
+    assert_eq!(
+        stats.to_string(),
+        "4 transactions in 2.0s (1.0 committed/s): 1 succeeded, 1 failed in execution, 1 expired, 1 errors, 4 retries"
+    );
+}
+
+#[tokio::test]
+async fn test_run_reports_each_item() {
+    let signers = vec![random_account(), random_account()];
+    let pipeline = SubmissionPipeline::new(unreachable_client(), ChainId::Testnet, signers)
+        .unwrap()
+        .with_max_concurrency(2);
+    let senders = pipeline.senders();
+
+    let payloads =
+        stream::iter(0..5).map(|i| transfer_transaction(senders[0], i).payload().clone());
+    let report = pipeline.run(payloads).await;
+
+    // Nothing reaches the node, so every item fails on its first attempt, before submission
+    assert_eq!(report.results.len(), 5);
+    for (i, result) in report.results.iter().enumerate() {
+        assert_eq!(result.index, i);
+        assert_eq!(result.sender, senders[i % 2]);
+        assert_eq!(result.attempts, 1);
+        assert!(matches!(
+            result.outcome,
+            SubmissionOutcome::Error { hash: None, .. }
+        ));
+    }
+    assert_eq!(report.failures().count(), 5);
+    assert_eq!(report.stats.errored, 5);
+    assert_eq!(report.stats.committed(), 0);
+}
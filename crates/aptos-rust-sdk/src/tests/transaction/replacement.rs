@@ -0,0 +1,61 @@
+use super::{random_account, transfer_transaction, unreachable_client};
+use crate::account::signer::Signer;
+use crate::transaction::replacement::{with_gas_unit_price, PendingTransaction};
+
+#[test]
+fn test_with_gas_unit_price() {
+    let sender = random_account();
+    let raw_txn = transfer_transaction(sender.address(), 7);
+    let replacement = with_gas_unit_price(&raw_txn, 150);
+    assert_eq!(replacement.gas_unit_price(), 150);
+    assert_eq!(replacement.sequence_number(), 7);
+    assert_eq!(replacement.payload(), raw_txn.payload());
+    assert_eq!(
+        replacement.expiration_timestamp_secs(),
+        raw_txn.expiration_timestamp_secs()
+    );
+    assert_eq!(
+        with_gas_unit_price(&replacement, raw_txn.gas_unit_price()),
+        raw_txn
+    );
+}
+
+#[test]
+fn test_tracks_submitted_hash() {
+    let sender = random_account();
+    let signed_txn = sender
+        .sign_transaction(transfer_transaction(sender.address(), 0))
+        .unwrap();
+    let pending = PendingTransaction::from_submitted(&signed_txn);
+    assert_eq!(pending.hashes(), [signed_txn.committed_hash()]);
+    assert_eq!(pending.latest_hash(), signed_txn.committed_hash());
+    assert_eq!(pending.raw_txn(), signed_txn.raw_txn());
+    assert_eq!(pending.committed_hash(), None);
+}
+
+#[tokio::test]
+async fn test_replacement_must_raise_price() {
+    let client = unreachable_client();
+    let sender = random_account();
+    let signed_txn = sender
+        .sign_transaction(transfer_transaction(sender.address(), 0))
+        .unwrap();
+    let mut pending = PendingTransaction::from_submitted(&signed_txn);
+
+    assert!(pending
+        .replace_transaction(&client, &sender, 100)
+        .await
+        .is_err());
+    assert!(pending
+        .replace_transaction(&client, &random_account(), 200)
+        .await
+        .is_err());
+
+    // A failed submission leaves the pending transaction untouched
+    assert!(pending
+        .bump_gas_unit_price(&client, &sender, 10)
+        .await
+        .is_err());
+    assert_eq!(pending.raw_txn(), signed_txn.raw_txn());
+    assert_eq!(pending.hashes().len(), 1);
+}
@@ -0,0 +1,96 @@
+use crate::transaction::sequence_number::AccountSequenceManager;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+const EXPIRATION: u64 = 1_000;
+
+#[tokio::test]
+async fn test_hands_out_sequence_numbers_up_to_cap() {
+    let manager = AccountSequenceManager::new(AccountAddress::ONE).with_max_in_flight(3);
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, None);
+
+    manager.update_from_chain(10, 0).await;
+    for expected in 10..13 {
+        assert_eq!(
+            manager.try_next_sequence_number(EXPIRATION).await,
+            Some(expected)
+        );
+    }
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, None);
+
+    // Two transactions committed, making room for two more
+    manager.update_from_chain(12, 0).await;
+    assert_eq!(manager.in_flight().await, 1);
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, Some(13));
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, Some(14));
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, None);
+}
+
+#[tokio::test]
+async fn test_fills_gap_left_by_expired_transaction() {
+    let manager = AccountSequenceManager::new(AccountAddress::ONE);
+    manager.update_from_chain(0, 0).await;
+    assert_eq!(manager.try_next_sequence_number(100).await, Some(0));
+    assert_eq!(manager.try_next_sequence_number(200).await, Some(1));
+    assert_eq!(manager.try_next_sequence_number(200).await, Some(2));
+
+    // 0 expired and blocks 1 and 2, so it's handed out again before anything new
+    manager.update_from_chain(0, 150).await;
+    assert_eq!(manager.try_next_sequence_number(300).await, Some(0));
+    assert_eq!(manager.try_next_sequence_number(300).await, Some(3));
+}
+
+#[tokio::test]
+async fn test_resets_when_everything_expired() {
+    let manager = AccountSequenceManager::new(AccountAddress::ONE);
+    manager.update_from_chain(5, 0).await;
+    for _ in 0..3 {
+        manager.try_next_sequence_number(100).await.unwrap();
+    }
+
+    // 5 committed, and 6 and 7 expired with nothing after them
+    manager.update_from_chain(6, 150).await;
+    assert_eq!(manager.in_flight().await, 0);
+    assert_eq!(manager.try_next_sequence_number(300).await, Some(6));
+    assert_eq!(manager.try_next_sequence_number(300).await, Some(7));
+}
+
+#[tokio::test]
+async fn test_release_and_external_use() {
+    let manager = AccountSequenceManager::new(AccountAddress::ONE);
+    manager.update_from_chain(0, 0).await;
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, Some(0));
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, Some(1));
+
+    // 0 never reached mempool while 1 did, so 0 is a gap
+    manager.release(0).await;
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, Some(0));
+    // The last one can just be reused in order
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, Some(2));
+    manager.release(2).await;
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, Some(2));
+
+    // Another client used the account, so the chain is ahead of us
+    manager.update_from_chain(20, 0).await;
+    assert_eq!(manager.in_flight().await, 0);
+    assert_eq!(manager.try_next_sequence_number(EXPIRATION).await, Some(20));
+}
+
+#[tokio::test]
+async fn test_shared_across_tasks() {
+    let manager = Arc::new(AccountSequenceManager::new(AccountAddress::ONE));
+    manager.update_from_chain(0, 0).await;
+
+    let tasks: Vec<_> = (0..50)
+        .map(|_| {
+            let manager = manager.clone();
+            tokio::spawn(async move { manager.try_next_sequence_number(EXPIRATION).await })
+        })
+        .collect();
+    let mut sequence_numbers = BTreeSet::new();
+    for task in tasks {
+        sequence_numbers.insert(task.await.unwrap().unwrap());
+    }
+    assert_eq!(sequence_numbers, (0..50).collect());
+}
@@ -0,0 +1,88 @@
+use super::{random_account, transfer_transaction};
+use crate::account::signer::Signer;
+use crate::transaction::simulation::{SimulationSigner, SimulationSigners};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction_authenticator::{
+    AccountAuthenticator, TransactionAuthenticator,
+};
+
+#[test]
+fn test_simulate_single_sender() {
+    let sender = random_account();
+    let raw_txn = transfer_transaction(sender.address(), 0);
+
+    // A known public key gets a zeroed signature
+    let signed = SimulationSigners::new(sender.public_key().clone())
+        .simulation_transaction(raw_txn.clone())
+        .unwrap();
+    match signed.authenticator() {
+        TransactionAuthenticator::SingleSender {
+            sender:
+                AccountAuthenticator::Ed25519 {
+                    public_key,
+                    signature,
+                },
+        } => {
+            assert_eq!(public_key, sender.public_key());
+            assert_eq!(signature.to_bytes(), [0u8; 64]);
+        }
+        other => panic!("Expected single sender authenticator, got {:?}", other),
+    }
+
+    // Only an address gets no authenticator
+    let signed = SimulationSigners::new(sender.address())
+        .simulation_transaction(raw_txn)
+        .unwrap();
+    assert_eq!(
+        signed.authenticator(),
+        &TransactionAuthenticator::single_sender(AccountAuthenticator::no_authenticator())
+    );
+}
+
+#[test]
+fn test_simulate_multi_agent_and_fee_payer() {
+    let sender = random_account();
+    let secondary = random_account();
+    let fee_payer = AccountAddress::TWO;
+    let raw_txn = transfer_transaction(sender.address(), 0);
+
+    let signers = SimulationSigners::new(sender.address()).with_secondary_signers([
+        SimulationSigner::from_public_key(secondary.address(), secondary.public_key().clone()),
+    ]);
+    match signers
+        .simulation_transaction(raw_txn.clone())
+        .unwrap()
+        .authenticator()
+    {
+        TransactionAuthenticator::MultiAgent {
+            secondary_signer_addresses,
+            secondary_signers,
+            ..
+        } => {
+            assert_eq!(secondary_signer_addresses, &vec![secondary.address()]);
+            assert!(matches!(
+                secondary_signers[0],
+                AccountAuthenticator::Ed25519 { .. }
+            ));
+        }
+        other => panic!("Expected multi-agent authenticator, got {:?}", other),
+    }
+
+    let signed = signers
+        .with_fee_payer(fee_payer)
+        .simulation_transaction(raw_txn)
+        .unwrap();
+    assert_eq!(signed.authenticator().fee_payer_address(), Some(fee_payer));
+    assert_eq!(
+        signed.authenticator().fee_payer_signer(),
+        Some(AccountAuthenticator::no_authenticator())
+    );
+}
+
+#[test]
+fn test_simulate_rejects_wrong_sender() {
+    let sender = random_account();
+    SimulationSigners::new(AccountAddress::ONE)
+        .simulation_transaction(transfer_transaction(sender.address(), 0))
+        .expect_err("Sender does not match");
+}
@@ -0,0 +1,89 @@
+use super::{random_account, transfer_transaction};
+use crate::account::signer::Signer;
+use crate::transaction::sponsored::SponsoredTransaction;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction_authenticator::TransactionAuthenticator;
+
+#[test]
+fn test_sponsored_flow() {
+    let sender = random_account();
+    let sponsor = random_account();
+    let raw_txn = transfer_transaction(sender.address(), 7);
+
+    // The sender signs and hands the bytes over to the sponsor
+    let sender_side = SponsoredTransaction::new(raw_txn.clone(), &sender).unwrap();
+    assert!(!sender_side.is_sponsored());
+    let bytes = sender_side.to_bytes().unwrap();
+
+    let sponsor_side = SponsoredTransaction::from_bytes(&bytes).unwrap();
+    assert_eq!(sender_side, sponsor_side);
+    let sponsored = sponsor_side.sign_as_fee_payer(&sponsor).unwrap();
+    assert_eq!(sponsored.fee_payer_address(), Some(sponsor.address()));
+
+    let signed = sponsored.into_signed_transaction().unwrap();
+    assert_eq!(signed.raw_txn(), &raw_txn);
+    match signed.authenticator() {
+        TransactionAuthenticator::FeePayer {
+            sender: sender_authenticator,
+            secondary_signer_addresses,
+            fee_payer_address,
+            fee_payer_signer,
+            ..
+        } => {
+            assert!(secondary_signer_addresses.is_empty());
+            assert_eq!(*fee_payer_address, sponsor.address());
+            // The sender signs over 0x0, and the fee payer over its own address
+            let sender_message =
+                SponsoredTransaction::signing_message(&raw_txn, AccountAddress::ZERO).unwrap();
+            sender_authenticator
+                .verify_arbitrary_msg(&sender_message)
+                .unwrap();
+            let fee_payer_message =
+                SponsoredTransaction::signing_message(&raw_txn, sponsor.address()).unwrap();
+            fee_payer_signer
+                .verify_arbitrary_msg(&fee_payer_message)
+                .unwrap();
+        }
+        other => panic!("Expected fee payer authenticator, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_sponsored_requires_fee_payer() {
+    let sender = random_account();
+    let sponsored =
+        SponsoredTransaction::new(transfer_transaction(sender.address(), 7), &sender).unwrap();
+    sponsored
+        .into_signed_transaction()
+        .expect_err("Fee payer has not signed");
+}
+
+#[test]
+fn test_sponsored_rejects_wrong_sender() {
+    let sender = random_account();
+    let other = random_account();
+    SponsoredTransaction::new(transfer_transaction(sender.address(), 7), &other)
+        .expect_err("Signer is not the sender");
+}
+
+#[test]
+fn test_sponsored_rejects_mismatched_signature() {
+    let sender = random_account();
+    let sponsor = random_account();
+    let raw_txn = transfer_transaction(sender.address(), 7);
+    let other_txn = transfer_transaction(sender.address(), 8);
+
+    // Pair the sender's signature for one transaction with a different transaction
+    let signed_other = SponsoredTransaction::new(other_txn.clone(), &sender)
+        .unwrap()
+        .to_bytes()
+        .unwrap();
+    let mut tampered = raw_txn.to_vec();
+    tampered.extend_from_slice(&signed_other[other_txn.to_vec().len()..]);
+
+    let tampered = SponsoredTransaction::from_bytes(&tampered).unwrap();
+    assert_eq!(tampered.raw_txn(), &raw_txn);
+    tampered
+        .sign_as_fee_payer(&sponsor)
+        .expect_err("Sender signed a different transaction");
+}
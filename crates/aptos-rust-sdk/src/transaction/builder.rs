@@ -0,0 +1,125 @@
+use anyhow::anyhow;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::chain_id::ChainId;
+use aptos_rust_sdk_types::api_types::transaction::{RawTransaction, TransactionPayload};
+use rand::Rng;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub(crate) const DEFAULT_MAX_GAS_AMOUNT: u64 = 200_000;
+pub(crate) const DEFAULT_GAS_UNIT_PRICE: u64 = 100;
+const DEFAULT_EXPIRATION_SECS: u64 = 20;
+
+/// How a transaction is protected from being replayed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReplayProtection {
+    /// The sender's account sequence number, executing transactions in order
+    SequenceNumber(u64),
+    /// A unique nonce, letting transactions execute in any order.  The expiration of an
+    /// orderless transaction has to be within a minute of the current time.
+    Nonce(u64),
+}
+
+/// Builds a [`RawTransaction`], filling in defaults for gas and expiration.  Payloads for common
+/// transfers are in [`crate::transaction::payloads`].
+#[derive(Clone, Debug)]
+pub struct TransactionBuilder {
+    sender: AccountAddress,
+    payload: TransactionPayload,
+    chain_id: ChainId,
+    replay_protection: Option<ReplayProtection>,
+    max_gas_amount: u64,
+    gas_unit_price: u64,
+    expiration_timestamp_secs: Option<u64>,
+}
+
+impl TransactionBuilder {
+    pub fn new(sender: AccountAddress, payload: TransactionPayload, chain_id: ChainId) -> Self {
+        Self {
+            sender,
+            payload,
+            chain_id,
+            replay_protection: None,
+            max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+            gas_unit_price: DEFAULT_GAS_UNIT_PRICE,
+            expiration_timestamp_secs: None,
+        }
+    }
+
+    pub fn sequence_number(mut self, sequence_number: u64) -> Self {
+        self.replay_protection = Some(ReplayProtection::SequenceNumber(sequence_number));
+        self
+    }
+
+    /// Make the transaction orderless, with the given replay protection nonce
+    pub fn orderless(mut self, nonce: u64) -> Self {
+        self.replay_protection = Some(ReplayProtection::Nonce(nonce));
+        self
+    }
+
+    /// Make the transaction orderless, with a random replay protection nonce
+    pub fn orderless_with_random_nonce(self) -> Self {
+        self.orderless(rand::thread_rng().gen())
+    }
+
+    pub fn replay_protection(mut self, replay_protection: ReplayProtection) -> Self {
+        self.replay_protection = Some(replay_protection);
+        self
+    }
+
+    pub fn max_gas_amount(mut self, max_gas_amount: u64) -> Self {
+        self.max_gas_amount = max_gas_amount;
+        self
+    }
+
+    pub fn gas_unit_price(mut self, gas_unit_price: u64) -> Self {
+        self.gas_unit_price = gas_unit_price;
+        self
+    }
+
+    pub fn expiration_timestamp_secs(mut self, expiration_timestamp_secs: u64) -> Self {
+        self.expiration_timestamp_secs = Some(expiration_timestamp_secs);
+        self
+    }
+
+    /// Expire the transaction `secs` seconds after it's built
+    pub fn expiration_from_now(self, secs: u64) -> Self {
+        self.expiration_timestamp_secs(now_secs() + secs)
+    }
+
+    /// Build the transaction.  Either a sequence number or orderless mode must have been chosen.
+    pub fn build(self) -> anyhow::Result<RawTransaction> {
+        let expiration_timestamp_secs = self
+            .expiration_timestamp_secs
+            .unwrap_or_else(|| now_secs() + DEFAULT_EXPIRATION_SECS);
+        match self
+            .replay_protection
+            .ok_or_else(|| anyhow!("Transaction needs a sequence number or to be orderless"))?
+        {
+            ReplayProtection::SequenceNumber(sequence_number) => Ok(RawTransaction::new(
+                self.sender,
+                sequence_number,
+                self.payload,
+                self.max_gas_amount,
+                self.gas_unit_price,
+                expiration_timestamp_secs,
+                self.chain_id,
+            )),
+            ReplayProtection::Nonce(nonce) => RawTransaction::new_orderless(
+                self.sender,
+                nonce,
+                self.payload,
+                self.max_gas_amount,
+                self.gas_unit_price,
+                expiration_timestamp_secs,
+                self.chain_id,
+            ),
+        }
+    }
+}
+
+pub(crate) fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("System time is before the unix epoch")
+        .as_secs()
+}
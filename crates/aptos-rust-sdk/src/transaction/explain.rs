@@ -0,0 +1,442 @@
+use crate::client::rest_api::AptosFullnodeClient;
+use anyhow::ensure;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::chain_id::ChainId;
+use aptos_rust_sdk_types::api_types::move_module::MoveType;
+use aptos_rust_sdk_types::api_types::move_value::MoveValue;
+use aptos_rust_sdk_types::api_types::transaction::{
+    EntryFunction, RawTransaction, SignedTransaction, TransactionExecutable,
+    TransactionExtraConfig, TransactionPayload, TransactionPayloadInner,
+};
+use aptos_rust_sdk_types::api_types::transaction_authenticator::AccountAuthenticator;
+use aptos_rust_sdk_types::api_types::type_tag::TypeTag;
+use std::fmt;
+use std::str::FromStr;
+
+const OCTAS_PER_APT: u128 = 100_000_000;
+const APT_DECIMALS: usize = 8;
+
+/// A human-readable description of a transaction, for reviewing it before signing or approving.
+///
+/// The [`fmt::Display`] implementation renders it as a multi-line report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionExplanation {
+    pub sender: AccountAddress,
+    /// Unused for orderless transactions, see `replay_protection_nonce`
+    pub sequence_number: u64,
+    pub replay_protection_nonce: Option<u64>,
+    /// The chain name, with its id, e.g. `testnet (2)`
+    pub chain: String,
+    /// The expiration time, e.g. `2023-11-14 22:13:20 UTC`
+    pub expiration: String,
+    pub max_gas_amount: u64,
+    pub gas_unit_price: u64,
+    /// The most the transaction can cost, `max_gas_amount * gas_unit_price`, in APT
+    pub max_fee: String,
+    pub payload: PayloadExplanation,
+    /// Empty for an unsigned transaction
+    pub signers: Vec<SignerExplanation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PayloadExplanation {
+    EntryFunction(EntryFunctionExplanation),
+    Script {
+        type_arguments: Vec<String>,
+        num_arguments: usize,
+    },
+    Multisig {
+        multisig_address: AccountAddress,
+        /// The entry function, if it isn't already stored on chain
+        entry_function: Option<EntryFunctionExplanation>,
+    },
+    /// Nothing to execute
+    Empty,
+    ModuleBundle,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryFunctionExplanation {
+    /// The function id, e.g. `0x1::aptos_account::transfer`
+    pub function: String,
+    pub type_arguments: Vec<String>,
+    pub arguments: Vec<ArgumentExplanation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArgumentExplanation {
+    /// The Move type of the argument, if the module ABI was available
+    pub type_: Option<String>,
+    /// The decoded value, or the hex of the BCS bytes if it couldn't be decoded
+    pub value: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignerRole {
+    Sender,
+    SecondarySigner,
+    FeePayer,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignerExplanation {
+    pub role: SignerRole,
+    pub address: AccountAddress,
+    pub scheme: String,
+    /// Hex of the public key, if the authenticator has one
+    pub public_key: Option<String>,
+}
+
+impl TransactionExplanation {
+    /// Explain an unsigned transaction.
+    ///
+    /// `module_abi` is the `abi` of the module the entry function is in, as returned by
+    /// [`AptosFullnodeClient::get_account_module`].  Without it, arguments are shown as hex.
+    pub fn new(raw_txn: &RawTransaction, module_abi: Option<&serde_json::Value>) -> Self {
+        let payload = raw_txn.payload();
+        let explain_multisig = |multisig_address| PayloadExplanation::Multisig {
+            multisig_address,
+            entry_function: payload
+                .entry_function()
+                .map(|entry_function| EntryFunctionExplanation::new(entry_function, module_abi)),
+        };
+        let payload = match payload {
+            TransactionPayload::Multisig(multisig) => explain_multisig(multisig.multisig_address),
+            TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                extra_config:
+                    TransactionExtraConfig::V1 {
+                        multisig_address: Some(multisig_address),
+                        ..
+                    },
+                ..
+            }) => explain_multisig(*multisig_address),
+            TransactionPayload::EntryFunction(entry_function)
+            | TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::EntryFunction(entry_function),
+                ..
+            }) => PayloadExplanation::EntryFunction(EntryFunctionExplanation::new(
+                entry_function,
+                module_abi,
+            )),
+            TransactionPayload::Script(script)
+            | TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::Script(script),
+                ..
+            }) => PayloadExplanation::Script {
+                type_arguments: script
+                    .ty_args()
+                    .iter()
+                    .map(TypeTag::to_canonical_string)
+                    .collect(),
+                num_arguments: script.args().len(),
+            },
+            TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::Empty,
+                ..
+            }) => PayloadExplanation::Empty,
+            TransactionPayload::ModuleBundle => PayloadExplanation::ModuleBundle,
+        };
+
+        let max_fee = raw_txn.max_gas_amount() as u128 * raw_txn.gas_unit_price() as u128;
+        Self {
+            sender: raw_txn.sender(),
+            sequence_number: raw_txn.sequence_number(),
+            replay_protection_nonce: raw_txn.replay_protection_nonce(),
+            chain: chain_name(raw_txn.chain_id()),
+            expiration: format_utc(raw_txn.expiration_timestamp_secs()),
+            max_gas_amount: raw_txn.max_gas_amount(),
+            gas_unit_price: raw_txn.gas_unit_price(),
+            max_fee: format_apt(max_fee),
+            payload,
+            signers: vec![],
+        }
+    }
+
+    /// Explain a signed transaction, including who signed it
+    pub fn from_signed_txn(
+        signed_txn: &SignedTransaction,
+        module_abi: Option<&serde_json::Value>,
+    ) -> Self {
+        let mut explanation = Self::new(signed_txn.raw_txn(), module_abi);
+        let authenticator = signed_txn.authenticator();
+        explanation.signers.push(SignerExplanation::new(
+            SignerRole::Sender,
+            signed_txn.raw_txn().sender(),
+            &authenticator.sender(),
+        ));
+        for (address, signer) in authenticator
+            .secondary_signer_addresses()
+            .into_iter()
+            .zip(authenticator.secondary_signers())
+        {
+            explanation.signers.push(SignerExplanation::new(
+                SignerRole::SecondarySigner,
+                address,
+                &signer,
+            ));
+        }
+        if let (Some(address), Some(signer)) = (
+            authenticator.fee_payer_address(),
+            authenticator.fee_payer_signer(),
+        ) {
+            explanation.signers.push(SignerExplanation::new(
+                SignerRole::FeePayer,
+                address,
+                &signer,
+            ));
+        }
+        explanation
+    }
+
+    /// Explain an unsigned transaction, fetching the module ABI from the network when possible
+    pub async fn fetch(client: &AptosFullnodeClient, raw_txn: &RawTransaction) -> Self {
+        let module_abi = fetch_module_abi(client, raw_txn.payload()).await;
+        Self::new(raw_txn, module_abi.as_ref())
+    }
+
+    /// Explain a signed transaction, fetching the module ABI from the network when possible
+    pub async fn fetch_signed(
+        client: &AptosFullnodeClient,
+        signed_txn: &SignedTransaction,
+    ) -> Self {
+        let module_abi = fetch_module_abi(client, signed_txn.raw_txn().payload()).await;
+        Self::from_signed_txn(signed_txn, module_abi.as_ref())
+    }
+}
+
+/// The ABI is only used to improve the explanation, so a missing module is not an error
+async fn fetch_module_abi(
+    client: &AptosFullnodeClient,
+    payload: &TransactionPayload,
+) -> Option<serde_json::Value> {
+    let module = payload.entry_function()?.module();
+    let response = client
+        .get_account_module(module.address().to_string(), module.name().to_string())
+        .await
+        .ok()?;
+    response.into_inner().get("abi").cloned()
+}
+
+impl EntryFunctionExplanation {
+    pub fn new(entry_function: &EntryFunction, module_abi: Option<&serde_json::Value>) -> Self {
+        let module = entry_function.module();
+        let params = module_abi
+            .and_then(|abi| function_params(abi, entry_function.function()))
+            .filter(|params| params.len() == entry_function.args().len());
+
+        let arguments = entry_function
+            .args()
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                let type_ = params
+                    .as_ref()
+                    .map(|params| instantiate_param(&params[i], entry_function.ty_args()));
+                let value = type_
+                    .as_deref()
+                    .and_then(|type_| decode_argument(type_, arg).ok())
+                    .unwrap_or_else(|| format!("0x{}", hex::encode(arg)));
+                ArgumentExplanation { type_, value }
+            })
+            .collect();
+
+        Self {
+            function: format!(
+                "0x{}::{}::{}",
+                module.address().short_str_lossless(),
+                module.name(),
+                entry_function.function()
+            ),
+            type_arguments: entry_function
+                .ty_args()
+                .iter()
+                .map(TypeTag::to_canonical_string)
+                .collect(),
+            arguments,
+        }
+    }
+}
+
+impl SignerExplanation {
+    fn new(
+        role: SignerRole,
+        address: AccountAddress,
+        authenticator: &AccountAuthenticator,
+    ) -> Self {
+        let public_key = match authenticator {
+            AccountAuthenticator::MultiEd25519 { .. }
+            | AccountAuthenticator::NoAuthenticator {} => None,
+            _ => Some(format!(
+                "0x{}",
+                hex::encode(authenticator.public_key_bytes())
+            )),
+        };
+        Self {
+            role,
+            address,
+            scheme: authenticator.scheme().to_string(),
+            public_key,
+        }
+    }
+}
+
+/// The non-signer parameter types of `function` in a module ABI
+fn function_params(module_abi: &serde_json::Value, function: &str) -> Option<Vec<String>> {
+    let function = module_abi
+        .get("exposed_functions")?
+        .as_array()?
+        .iter()
+        .find(|exposed| exposed.get("name").and_then(|name| name.as_str()) == Some(function))?;
+    function
+        .get("params")?
+        .as_array()?
+        .iter()
+        .map(|param| param.as_str().map(str::to_string))
+        .filter(|param| !matches!(param.as_deref(), Some("signer") | Some("&signer")))
+        .collect()
+}
+
+/// A parameter type from a module ABI with its generic type params replaced by the type
+/// arguments, or as written if it can't be instantiated
+fn instantiate_param(param: &str, ty_args: &[TypeTag]) -> String {
+    MoveType::from_str(param)
+        .and_then(|move_type| move_type.instantiate(ty_args))
+        .map(|type_tag| type_tag.to_canonical_string())
+        .unwrap_or_else(|_| param.to_string())
+}
+
+/// Decode a BCS encoded argument of the given Move type into a readable value
+fn decode_argument(type_: &str, bytes: &[u8]) -> anyhow::Result<String> {
+    let type_tag = TypeTag::from_str(type_)?;
+    ensure!(
+        type_tag != TypeTag::Signer,
+        "Signers can't be passed as arguments"
+    );
+    Ok(MoveValue::from_bcs(&type_tag, bytes)?.to_string())
+}
+
+fn chain_name(chain_id: ChainId) -> String {
+    match chain_id {
+        ChainId::Other(id) => format!("chain {}", id),
+        named => format!("{} ({})", named, named.id()),
+    }
+}
+
+/// Format an amount of octas as APT, without trailing zeros
+fn format_apt(octas: u128) -> String {
+    let whole = octas / OCTAS_PER_APT;
+    let fraction = octas % OCTAS_PER_APT;
+    if fraction == 0 {
+        format!("{} APT", whole)
+    } else {
+        let fraction = format!("{:0width$}", fraction, width = APT_DECIMALS);
+        format!("{}.{} APT", whole, fraction.trim_end_matches('0'))
+    }
+}
+
+/// Format unix seconds as a UTC date and time
+fn format_utc(timestamp_secs: u64) -> String {
+    let days = (timestamp_secs / 86_400) as i64;
+    let secs_of_day = timestamp_secs % 86_400;
+
+    // Days since the epoch to a civil date, from Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
+
+impl fmt::Display for SignerRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let role = match self {
+            SignerRole::Sender => "sender",
+            SignerRole::SecondarySigner => "secondary signer",
+            SignerRole::FeePayer => "fee payer",
+        };
+        write!(f, "{}", role)
+    }
+}
+
+impl fmt::Display for EntryFunctionExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Function:         {}", self.function)?;
+        if !self.type_arguments.is_empty() {
+            writeln!(f, "Type arguments:   {}", self.type_arguments.join(", "))?;
+        }
+        writeln!(f, "Arguments:")?;
+        for (i, argument) in self.arguments.iter().enumerate() {
+            match &argument.type_ {
+                Some(type_) => writeln!(f, "  [{}] {}: {}", i, type_, argument.value)?,
+                None => writeln!(f, "  [{}] {}", i, argument.value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TransactionExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Sender:           {}", self.sender)?;
+        match self.replay_protection_nonce {
+            Some(nonce) => writeln!(f, "Orderless nonce:  {}", nonce)?,
+            None => writeln!(f, "Sequence number:  {}", self.sequence_number)?,
+        }
+        writeln!(f, "Chain:            {}", self.chain)?;
+        writeln!(f, "Expiration:       {}", self.expiration)?;
+        writeln!(f, "Max gas amount:   {}", self.max_gas_amount)?;
+        writeln!(f, "Gas unit price:   {} octas", self.gas_unit_price)?;
+        writeln!(f, "Max fee:          {}", self.max_fee)?;
+        match &self.payload {
+            PayloadExplanation::EntryFunction(entry_function) => write!(f, "{}", entry_function)?,
+            PayloadExplanation::Script {
+                type_arguments,
+                num_arguments,
+            } => {
+                writeln!(f, "Script")?;
+                if !type_arguments.is_empty() {
+                    writeln!(f, "Type arguments:   {}", type_arguments.join(", "))?;
+                }
+                writeln!(f, "Arguments:        {}", num_arguments)?;
+            }
+            PayloadExplanation::Multisig {
+                multisig_address,
+                entry_function,
+            } => {
+                writeln!(f, "Multisig account: {}", multisig_address)?;
+                match entry_function {
+                    Some(entry_function) => write!(f, "{}", entry_function)?,
+                    None => writeln!(f, "Payload stored on chain")?,
+                }
+            }
+            PayloadExplanation::Empty => writeln!(f, "Empty payload")?,
+            PayloadExplanation::ModuleBundle => writeln!(f, "Module bundle (deprecated)")?,
+        }
+        if !self.signers.is_empty() {
+            writeln!(f, "Signers:")?;
+            for signer in &self.signers {
+                write!(f, "  {} {} ({}", signer.role, signer.address, signer.scheme)?;
+                if let Some(public_key) = &signer.public_key {
+                    write!(f, ", public key {}", public_key)?;
+                }
+                writeln!(f, ")")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+pub mod builder;
+pub mod explain;
+pub mod offline;
+pub mod partially_signed;
+pub mod payloads;
+pub mod pipeline;
+pub mod replacement;
+pub mod sequence_number;
+pub mod simulation;
+pub mod sponsored;
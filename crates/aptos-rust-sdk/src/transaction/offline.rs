@@ -0,0 +1,252 @@
+use crate::account::signer::Signer;
+use crate::client::response::FullnodeResponse;
+use crate::client::rest_api::AptosFullnodeClient;
+use anyhow::{anyhow, bail, ensure, Context};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::{
+    GenerateSigningMessage, RawTransaction, SignedTransaction, TransactionExecutable,
+    TransactionPayload, TransactionPayloadInner,
+};
+use aptos_rust_sdk_types::api_types::transaction_authenticator::TransactionAuthenticator;
+use aptos_rust_sdk_types::AptosResult;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A human-readable description of a [`RawTransaction`], written next to its BCS bytes so the
+/// person signing can check what they're signing
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionSummary {
+    pub sender: AccountAddress,
+    /// Unused for orderless transactions, see `replay_protection_nonce`
+    pub sequence_number: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replay_protection_nonce: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multisig_address: Option<AccountAddress>,
+    /// The fully qualified entry function, e.g. `0x...01::aptos_account::transfer`, or `script`
+    pub function: String,
+    pub type_arguments: Vec<String>,
+    /// Hex of the BCS encoded arguments
+    pub arguments: Vec<String>,
+    pub max_gas_amount: u64,
+    pub gas_unit_price: u64,
+    pub expiration_timestamp_secs: u64,
+    pub chain_id: u8,
+}
+
+impl From<&RawTransaction> for TransactionSummary {
+    fn from(raw_txn: &RawTransaction) -> Self {
+        let payload = raw_txn.payload();
+        let (function, type_arguments, arguments) = match payload.entry_function() {
+            Some(entry_function) => (
+                format!(
+                    "{}::{}::{}",
+                    entry_function.module().address(),
+                    entry_function.module().name(),
+                    entry_function.function()
+                ),
+                entry_function
+                    .ty_args()
+                    .iter()
+                    .map(|ty_arg| ty_arg.to_canonical_string())
+                    .collect(),
+                entry_function
+                    .args()
+                    .iter()
+                    .map(|arg| format!("0x{}", hex::encode(arg)))
+                    .collect(),
+            ),
+            None => {
+                let function = match payload {
+                    TransactionPayload::Script(_)
+                    | TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                        executable: TransactionExecutable::Script(_),
+                        ..
+                    }) => "script",
+                    TransactionPayload::ModuleBundle => "module bundle",
+                    // A multisig transaction with its payload stored on chain
+                    _ => "none",
+                };
+                (function.to_string(), vec![], vec![])
+            }
+        };
+        Self {
+            sender: raw_txn.sender(),
+            sequence_number: raw_txn.sequence_number(),
+            replay_protection_nonce: raw_txn.replay_protection_nonce(),
+            multisig_address: payload.multisig_address(),
+            function,
+            type_arguments,
+            arguments,
+            max_gas_amount: raw_txn.max_gas_amount(),
+            gas_unit_price: raw_txn.gas_unit_price(),
+            expiration_timestamp_secs: raw_txn.expiration_timestamp_secs(),
+            chain_id: raw_txn.chain_id().id(),
+        }
+    }
+}
+
+/// An unsigned transaction exported for signing on an air-gapped machine.
+///
+/// Every field of the [`RawTransaction`] (sequence number, gas, expiration, and chain id) is
+/// fixed when it's exported, so the signing side needs no network access.  The file is JSON
+/// holding a [`TransactionSummary`] and the hex BCS of the transaction; when loaded, the summary
+/// is checked against the bytes so it can't be edited to misrepresent the transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsignedTransactionFile {
+    summary: TransactionSummary,
+    raw_transaction: String,
+}
+
+impl UnsignedTransactionFile {
+    pub fn new(raw_txn: &RawTransaction) -> Self {
+        Self {
+            summary: TransactionSummary::from(raw_txn),
+            raw_transaction: format!("0x{}", hex::encode(raw_txn.to_vec())),
+        }
+    }
+
+    pub fn summary(&self) -> &TransactionSummary {
+        &self.summary
+    }
+
+    /// Decode the transaction, checking it matches the summary
+    pub fn raw_txn(&self) -> anyhow::Result<RawTransaction> {
+        let raw_txn: RawTransaction = aptos_bcs::from_bytes(&decode_hex(&self.raw_transaction)?)
+            .context("Invalid raw transaction")?;
+        ensure!(
+            TransactionSummary::from(&raw_txn) == self.summary,
+            "Transaction summary does not match the raw transaction"
+        );
+        Ok(raw_txn)
+    }
+
+    /// Sign the transaction, without any network access
+    pub fn sign(&self, signer: &impl Signer) -> anyhow::Result<SignedTransactionFile> {
+        let signed_txn = signer.sign_transaction(self.raw_txn()?)?;
+        Ok(SignedTransactionFile::new(&signed_txn))
+    }
+
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let file: Self = serde_json::from_str(json)?;
+        file.raw_txn()?;
+        Ok(file)
+    }
+
+    pub fn write_to(&self, path: &Path) -> anyhow::Result<()> {
+        write_file(path, &self.to_json()?)
+    }
+
+    pub fn read_from(path: &Path) -> anyhow::Result<Self> {
+        Self::from_json(&read_file(path)?)
+    }
+}
+
+/// A transaction signed offline, to be carried back to an online machine for submission.
+///
+/// Like [`UnsignedTransactionFile`], it's JSON holding a [`TransactionSummary`] next to the hex
+/// BCS of the [`SignedTransaction`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedTransactionFile {
+    summary: TransactionSummary,
+    signed_transaction: String,
+}
+
+impl SignedTransactionFile {
+    pub fn new(signed_txn: &SignedTransaction) -> Self {
+        Self {
+            summary: TransactionSummary::from(signed_txn.raw_txn()),
+            signed_transaction: format!("0x{}", hex::encode(signed_txn.to_vec())),
+        }
+    }
+
+    pub fn summary(&self) -> &TransactionSummary {
+        &self.summary
+    }
+
+    /// Decode the transaction, checking it matches the summary and is signed by the sender
+    pub fn signed_txn(&self) -> anyhow::Result<SignedTransaction> {
+        let signed_txn: SignedTransaction =
+            aptos_bcs::from_bytes(&decode_hex(&self.signed_transaction)?)
+                .context("Invalid signed transaction")?;
+        ensure!(
+            TransactionSummary::from(signed_txn.raw_txn()) == self.summary,
+            "Transaction summary does not match the signed transaction"
+        );
+
+        let sender = match signed_txn.authenticator() {
+            TransactionAuthenticator::Ed25519 { .. }
+            | TransactionAuthenticator::SingleSender { .. } => signed_txn.authenticator().sender(),
+            other => bail!("Expected a single signer transaction, got {}", other),
+        };
+        let message = signed_txn.raw_txn().generate_signing_message()?;
+        sender
+            .verify_arbitrary_msg(&message)
+            .context("Signature does not match the transaction")?;
+        Ok(signed_txn)
+    }
+
+    /// Check the transaction can still be executed on the chain at the given ledger timestamp
+    pub fn validate(&self, chain_id: u8, ledger_timestamp_secs: u64) -> anyhow::Result<()> {
+        let signed_txn = self.signed_txn()?;
+        let raw_txn = signed_txn.raw_txn();
+        ensure!(
+            raw_txn.chain_id().id() == chain_id,
+            "Transaction is for chain {}, but the network is chain {}",
+            raw_txn.chain_id().id(),
+            chain_id
+        );
+        ensure!(
+            raw_txn.expiration_timestamp_secs() > ledger_timestamp_secs,
+            "Transaction expired at {}, ledger time is {}",
+            raw_txn.expiration_timestamp_secs(),
+            ledger_timestamp_secs
+        );
+        Ok(())
+    }
+
+    /// Validate the transaction against the network's current state, then broadcast it
+    pub async fn submit(
+        &self,
+        client: &AptosFullnodeClient,
+    ) -> AptosResult<FullnodeResponse<serde_json::Value>> {
+        let state = client.get_state().await?;
+        self.validate(state.chain_id, state.timestamp_usecs / 1_000_000)?;
+        client.submit_transaction(self.signed_txn()?).await
+    }
+
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let file: Self = serde_json::from_str(json)?;
+        file.signed_txn()?;
+        Ok(file)
+    }
+
+    pub fn write_to(&self, path: &Path) -> anyhow::Result<()> {
+        write_file(path, &self.to_json()?)
+    }
+
+    pub fn read_from(path: &Path) -> anyhow::Result<Self> {
+        Self::from_json(&read_file(path)?)
+    }
+}
+
+fn decode_hex(hex_str: &str) -> anyhow::Result<Vec<u8>> {
+    let hex_str = hex_str.trim();
+    hex::decode(hex_str.strip_prefix("0x").unwrap_or(hex_str)).map_err(|err| anyhow!(err))
+}
+
+fn write_file(path: &Path, contents: &str) -> anyhow::Result<()> {
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn read_file(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
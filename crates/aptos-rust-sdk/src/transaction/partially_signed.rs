@@ -0,0 +1,243 @@
+use crate::account::signer::Signer;
+use anyhow::{anyhow, bail, ensure, Context};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::{
+    GenerateSigningMessage, RawTransactionWithData, SignedTransaction,
+};
+use aptos_rust_sdk_types::api_types::transaction_authenticator::{
+    AccountAuthenticator, TransactionAuthenticator,
+};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+/// A multi-agent transaction collecting signatures from signers that don't share a process.
+///
+/// Each signer receives the transaction in its BCS, hex, or base64 form, adds its signature with
+/// [`PartiallySignedTransaction::sign`] or [`PartiallySignedTransaction::add_signature`], and
+/// passes it on.  Once [`PartiallySignedTransaction::is_complete`], it can be turned into a
+/// [`SignedTransaction`] with [`PartiallySignedTransaction::finalize`].
+///
+/// The required signers are, in order, the sender, the secondary signers, and the fee payer if
+/// there is one.  For a fee payer transaction where the sponsor isn't known up front, use
+/// [`crate::transaction::sponsored::SponsoredTransaction`] instead.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartiallySignedTransaction {
+    raw_txn: RawTransactionWithData,
+    /// One slot per required signer, in the order of [`PartiallySignedTransaction::required_signers`]
+    signatures: Vec<Option<AccountAuthenticator>>,
+}
+
+impl PartiallySignedTransaction {
+    /// Start collecting signatures for a multi-agent or fee payer transaction
+    pub fn new(raw_txn: RawTransactionWithData) -> anyhow::Result<Self> {
+        if raw_txn.fee_payer_address() == Some(AccountAddress::ZERO) {
+            bail!(
+                "Fee payer address must be set to collect signatures for a fee payer transaction"
+            );
+        }
+        let num_signers = Self::signers_of(&raw_txn).len();
+        Ok(Self {
+            raw_txn,
+            signatures: vec![None; num_signers],
+        })
+    }
+
+    pub fn raw_txn(&self) -> &RawTransactionWithData {
+        &self.raw_txn
+    }
+
+    /// The message every signer has to sign
+    pub fn signing_message(&self) -> anyhow::Result<Vec<u8>> {
+        self.raw_txn.generate_signing_message()
+    }
+
+    /// All addresses that have to sign: the sender, the secondary signers, then the fee payer
+    pub fn required_signers(&self) -> Vec<AccountAddress> {
+        Self::signers_of(&self.raw_txn)
+    }
+
+    /// The addresses that still have to sign
+    pub fn missing_signers(&self) -> Vec<AccountAddress> {
+        self.required_signers()
+            .into_iter()
+            .zip(&self.signatures)
+            .filter(|(_, signature)| signature.is_none())
+            .map(|(address, _)| address)
+            .collect()
+    }
+
+    /// Whether every required signer has signed
+    pub fn is_complete(&self) -> bool {
+        self.signatures.iter().all(Option::is_some)
+    }
+
+    /// Sign the transaction with a local signer
+    pub fn sign(&mut self, signer: &impl Signer) -> anyhow::Result<()> {
+        let message = self.signing_message()?;
+        self.add_signature(signer.address(), signer.sign_message(&message))
+    }
+
+    /// Add a signature produced elsewhere for `signer`.
+    ///
+    /// The signature is checked against the transaction before it's accepted.  Signing again
+    /// for the same address replaces the previous signature.
+    pub fn add_signature(
+        &mut self,
+        signer: AccountAddress,
+        authenticator: AccountAuthenticator,
+    ) -> anyhow::Result<()> {
+        let required_signers = self.required_signers();
+        ensure!(
+            required_signers.contains(&signer),
+            "{} is not a signer of this transaction",
+            signer
+        );
+        self.verify_signature(signer, &authenticator)
+            .with_context(|| format!("Signature from {} does not match the transaction", signer))?;
+
+        for (address, slot) in required_signers.iter().zip(self.signatures.iter_mut()) {
+            if *address == signer {
+                *slot = Some(authenticator.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Fee payer transactions allow everyone but the fee payer to sign over `0x0` as the fee
+    /// payer address, so either message is accepted for them
+    fn verify_signature(
+        &self,
+        signer: AccountAddress,
+        authenticator: &AccountAuthenticator,
+    ) -> anyhow::Result<()> {
+        let message = self.signing_message()?;
+        if authenticator.verify_arbitrary_msg(&message).is_ok() {
+            return Ok(());
+        }
+        match &self.raw_txn {
+            RawTransactionWithData::MultiAgentWithFeePayer {
+                raw_txn,
+                secondary_signer_addresses,
+                fee_payer_address,
+            } if *fee_payer_address != signer => {
+                let message = RawTransactionWithData::new_multi_agent_with_fee_payer(
+                    raw_txn.clone(),
+                    secondary_signer_addresses.clone(),
+                    AccountAddress::ZERO,
+                )
+                .generate_signing_message()?;
+                authenticator.verify_arbitrary_msg(&message)
+            }
+            _ => authenticator.verify_arbitrary_msg(&message),
+        }
+    }
+
+    /// Build the final [`SignedTransaction`] once every required signer has signed.
+    ///
+    /// Every signature is checked against the transaction again, as those loaded with
+    /// [`PartiallySignedTransaction::from_bytes`] haven't been.
+    pub fn finalize(self) -> anyhow::Result<SignedTransaction> {
+        let missing_signers = self.missing_signers();
+        ensure!(
+            missing_signers.is_empty(),
+            "Transaction is missing signatures from {:?}",
+            missing_signers
+        );
+        for (signer, authenticator) in self.required_signers().into_iter().zip(&self.signatures) {
+            if let Some(authenticator) = authenticator {
+                self.verify_signature(signer, authenticator)
+                    .with_context(|| {
+                        format!("Signature from {} does not match the transaction", signer)
+                    })?;
+            }
+        }
+        let mut signatures = self.signatures.into_iter().flatten();
+        let sender = signatures
+            .next()
+            .ok_or_else(|| anyhow!("Transaction has no sender signature"))?;
+
+        Ok(match self.raw_txn {
+            RawTransactionWithData::MultiAgent {
+                raw_txn,
+                secondary_signer_addresses,
+            } => {
+                let secondary_signers = signatures.collect();
+                SignedTransaction::new(
+                    raw_txn,
+                    TransactionAuthenticator::multi_agent(
+                        sender,
+                        secondary_signer_addresses,
+                        secondary_signers,
+                    ),
+                )
+            }
+            RawTransactionWithData::MultiAgentWithFeePayer {
+                raw_txn,
+                secondary_signer_addresses,
+                fee_payer_address,
+            } => {
+                let secondary_signers = signatures
+                    .by_ref()
+                    .take(secondary_signer_addresses.len())
+                    .collect();
+                let fee_payer_signer = signatures
+                    .next()
+                    .ok_or_else(|| anyhow!("Transaction has no fee payer signature"))?;
+                SignedTransaction::new(
+                    raw_txn,
+                    TransactionAuthenticator::fee_payer(
+                        sender,
+                        secondary_signer_addresses,
+                        secondary_signers,
+                        fee_payer_address,
+                        fee_payer_signer,
+                    ),
+                )
+            }
+        })
+    }
+
+    /// Serialize to BCS for handing to the next signer
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(aptos_bcs::to_bytes(self)?)
+    }
+
+    /// Deserialize from the output of [`PartiallySignedTransaction::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        let txn: Self = aptos_bcs::from_bytes(bytes)?;
+        ensure!(
+            txn.signatures.len() == Self::signers_of(&txn.raw_txn).len(),
+            "Number of signatures does not match the number of signers"
+        );
+        Ok(txn)
+    }
+
+    /// `0x` prefixed hex of the BCS bytes
+    pub fn to_hex(&self) -> anyhow::Result<String> {
+        Ok(format!("0x{}", hex::encode(self.to_bytes()?)))
+    }
+
+    /// Parse the output of [`PartiallySignedTransaction::to_hex`], with or without `0x`
+    pub fn from_hex(hex_str: &str) -> anyhow::Result<Self> {
+        let hex_str = hex_str.trim();
+        Self::from_bytes(&hex::decode(hex_str.strip_prefix("0x").unwrap_or(hex_str))?)
+    }
+
+    /// Standard base64 of the BCS bytes
+    pub fn to_base64(&self) -> anyhow::Result<String> {
+        Ok(BASE64.encode(self.to_bytes()?))
+    }
+
+    /// Parse the output of [`PartiallySignedTransaction::to_base64`]
+    pub fn from_base64(base64_str: &str) -> anyhow::Result<Self> {
+        Self::from_bytes(&BASE64.decode(base64_str.trim())?)
+    }
+
+    fn signers_of(raw_txn: &RawTransactionWithData) -> Vec<AccountAddress> {
+        std::iter::once(raw_txn.raw_txn().sender())
+            .chain(raw_txn.secondary_signer_addresses().iter().copied())
+            .chain(raw_txn.fee_payer_address())
+            .collect()
+    }
+}
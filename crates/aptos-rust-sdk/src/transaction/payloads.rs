@@ -0,0 +1,111 @@
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::module_id::ModuleId;
+use aptos_rust_sdk_types::api_types::move_value::MoveValue;
+use aptos_rust_sdk_types::api_types::transaction::{EntryFunction, TransactionPayload};
+use aptos_rust_sdk_types::api_types::type_tag::{StructTag, TypeTag};
+
+/// `0x1::aptos_account::transfer`, sending APT and creating the recipient account if needed
+pub fn transfer(to: AccountAddress, amount: u64) -> TransactionPayload {
+    framework_call(
+        "aptos_account",
+        "transfer",
+        vec![],
+        vec![to.into(), amount.into()],
+    )
+}
+
+/// `0x1::aptos_account::transfer_coins<T>`, sending any coin type and creating the recipient
+/// account if needed
+pub fn transfer_coins(coin_type: TypeTag, to: AccountAddress, amount: u64) -> TransactionPayload {
+    framework_call(
+        "aptos_account",
+        "transfer_coins",
+        vec![coin_type],
+        vec![to.into(), amount.into()],
+    )
+}
+
+/// `0x1::aptos_account::batch_transfer`, sending APT to each recipient in one transaction
+pub fn batch_transfer(transfers: &[(AccountAddress, u64)]) -> TransactionPayload {
+    let (recipients, amounts): (Vec<AccountAddress>, Vec<u64>) = transfers.iter().copied().unzip();
+    framework_call(
+        "aptos_account",
+        "batch_transfer",
+        vec![],
+        vec![recipients.into(), amounts.into()],
+    )
+}
+
+/// `0x1::primary_fungible_store::transfer`, sending a fungible asset given the address of its
+/// metadata object
+pub fn transfer_fungible_asset(
+    metadata: AccountAddress,
+    to: AccountAddress,
+    amount: u64,
+) -> TransactionPayload {
+    framework_call(
+        "primary_fungible_store",
+        "transfer",
+        vec![fungible_asset_metadata()],
+        // An `Object<T>` is encoded as its address
+        vec![metadata.into(), to.into(), amount.into()],
+    )
+}
+
+/// `0x1::object::transfer<T>`, transferring an object of resource type `object_type`, e.g.
+/// [`object_core`] for any object
+pub fn transfer_object(
+    object_type: TypeTag,
+    object: AccountAddress,
+    to: AccountAddress,
+) -> TransactionPayload {
+    framework_call(
+        "object",
+        "transfer",
+        vec![object_type],
+        vec![object.into(), to.into()],
+    )
+}
+
+/// `0x1::object::transfer_call`, transferring an object by its address
+pub fn transfer_object_call(object: AccountAddress, to: AccountAddress) -> TransactionPayload {
+    framework_call(
+        "object",
+        "transfer_call",
+        vec![],
+        vec![object.into(), to.into()],
+    )
+}
+
+/// The `0x1::object::ObjectCore` type
+pub fn object_core() -> TypeTag {
+    framework_struct("object", "ObjectCore")
+}
+
+/// The `0x1::fungible_asset::Metadata` type
+pub fn fungible_asset_metadata() -> TypeTag {
+    framework_struct("fungible_asset", "Metadata")
+}
+
+fn framework_struct(module: &str, name: &str) -> TypeTag {
+    TypeTag::Struct(Box::new(StructTag {
+        address: AccountAddress::ONE,
+        module: module.to_string(),
+        name: name.to_string(),
+        type_args: vec![],
+    }))
+}
+
+fn framework_call(
+    module: &str,
+    function: &str,
+    ty_args: Vec<TypeTag>,
+    args: Vec<MoveValue>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::from_values(
+        ModuleId::new(AccountAddress::ONE, module.to_string()),
+        function.to_string(),
+        ty_args,
+        args,
+    ))
+}
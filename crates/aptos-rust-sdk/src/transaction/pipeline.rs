@@ -0,0 +1,490 @@
+use crate::account::signer::Signer;
+use crate::client::rest_api::{is_pending, AptosFullnodeClient};
+use crate::transaction::builder::{
+    now_secs, TransactionBuilder, DEFAULT_GAS_UNIT_PRICE, DEFAULT_MAX_GAS_AMOUNT,
+};
+use crate::transaction::sequence_number::AccountSequenceManager;
+use anyhow::ensure;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::chain_id::ChainId;
+use aptos_rust_sdk_types::api_types::transaction::TransactionPayload;
+use aptos_rust_sdk_types::error::{AptosErrorCode, RestError};
+use futures_util::{Stream, StreamExt};
+use std::fmt::{Display, Formatter};
+use std::pin::pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+const DEFAULT_MAX_CONCURRENCY: usize = 32;
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_EXPIRATION_SECS: u64 = 30;
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// How long past its expiration a transaction is still waited on, in case the node falls behind
+const EXPIRATION_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
+/// Submits a batch of transactions, e.g. payouts or mints, from a pool of signer accounts.
+///
+/// Payloads are assigned to the accounts round-robin, and each account hands out its sequence
+/// numbers with an [`AccountSequenceManager`].  Every transaction is signed, submitted, and then
+/// waited on until it's committed or expires, with at most `max_concurrency` transactions being
+/// worked on at a time.
+///
+/// Transactions rejected because mempool is full, or because the sequence number was already
+/// used, are retried after a backoff.  Transactions that expire are retried with a fresh
+/// expiration, up to `max_attempts` in total.  Transactions that are committed but fail in
+/// execution are never retried.
+///
+/// The sequence managers keep their state between runs, so one pipeline can be reused for many
+/// batches.
+#[derive(Debug)]
+pub struct SubmissionPipeline<S> {
+    client: AptosFullnodeClient,
+    accounts: Vec<Arc<PipelineAccount<S>>>,
+    config: PipelineConfig,
+}
+
+#[derive(Debug)]
+struct PipelineAccount<S> {
+    signer: S,
+    sequence_numbers: AccountSequenceManager,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PipelineConfig {
+    chain_id: ChainId,
+    max_concurrency: usize,
+    max_attempts: u32,
+    max_gas_amount: u64,
+    gas_unit_price: u64,
+    expiration_secs: u64,
+    poll_interval: Duration,
+}
+
+impl<S: Signer + Send + Sync + 'static> SubmissionPipeline<S> {
+    /// Create a pipeline submitting from the given accounts, which must not be empty
+    pub fn new(
+        client: AptosFullnodeClient,
+        chain_id: ChainId,
+        signers: impl IntoIterator<Item = S>,
+    ) -> anyhow::Result<Self> {
+        let accounts: Vec<_> = signers
+            .into_iter()
+            .map(|signer| {
+                Arc::new(PipelineAccount {
+                    sequence_numbers: AccountSequenceManager::new(signer.address()),
+                    signer,
+                })
+            })
+            .collect();
+        ensure!(
+            !accounts.is_empty(),
+            "A submission pipeline needs at least one signer"
+        );
+
+        Ok(Self {
+            client,
+            accounts,
+            config: PipelineConfig {
+                chain_id,
+                max_concurrency: DEFAULT_MAX_CONCURRENCY,
+                max_attempts: DEFAULT_MAX_ATTEMPTS,
+                max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+                gas_unit_price: DEFAULT_GAS_UNIT_PRICE,
+                expiration_secs: DEFAULT_EXPIRATION_SECS,
+                poll_interval: DEFAULT_POLL_INTERVAL,
+            },
+        })
+    }
+
+    /// Set the maximum number of transactions being submitted or waited on at a time
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.config.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Set the maximum number of times a transaction is submitted, including the first
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.config.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_max_gas_amount(mut self, max_gas_amount: u64) -> Self {
+        self.config.max_gas_amount = max_gas_amount;
+        self
+    }
+
+    pub fn with_gas_unit_price(mut self, gas_unit_price: u64) -> Self {
+        self.config.gas_unit_price = gas_unit_price;
+        self
+    }
+
+    /// Set how many seconds after submission each transaction expires
+    pub fn with_expiration_secs(mut self, expiration_secs: u64) -> Self {
+        self.config.expiration_secs = expiration_secs;
+        self
+    }
+
+    /// Set how often to check on submitted transactions, and the base of the retry backoff
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.config.poll_interval = poll_interval;
+        self
+    }
+
+    /// The addresses of the accounts submitting, in the order payloads are assigned to them
+    pub fn senders(&self) -> Vec<AccountAddress> {
+        self.accounts
+            .iter()
+            .map(|account| account.signer.address())
+            .collect()
+    }
+
+    /// Submit a transaction for each payload, and wait for all of them to finish.
+    ///
+    /// Payloads are pulled from the stream only as capacity frees up, so it can generate them
+    /// lazily, e.g. from a queue.  The results are in the same order as the payloads.
+    pub async fn run(&self, payloads: impl Stream<Item = TransactionPayload>) -> PipelineReport {
+        let start = Instant::now();
+        let semaphore = Arc::new(Semaphore::new(self.config.max_concurrency));
+        let mut tasks = JoinSet::new();
+
+        let mut payloads = pin!(payloads.enumerate());
+        while let Some((index, payload)) = payloads.next().await {
+            let permit = semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("The semaphore is never closed");
+            let account = self.accounts[index % self.accounts.len()].clone();
+            let client = self.client.clone();
+            let config = self.config;
+            tasks.spawn(async move {
+                let (attempts, outcome) = account.submit(&client, &config, payload).await;
+                drop(permit);
+                ItemResult {
+                    index,
+                    sender: account.signer.address(),
+                    attempts,
+                    outcome,
+                }
+            });
+        }
+
+        let mut results = Vec::new();
+        while let Some(result) = tasks.join_next().await {
+            results.push(result.expect("Submission task panicked"));
+        }
+        results.sort_by_key(|result| result.index);
+        PipelineReport::new(results, start.elapsed())
+    }
+}
+
+impl<S: Signer> PipelineAccount<S> {
+    /// Submit one payload until it's committed, returning the number of attempts made
+    async fn submit(
+        &self,
+        client: &AptosFullnodeClient,
+        config: &PipelineConfig,
+        payload: TransactionPayload,
+    ) -> (u32, SubmissionOutcome) {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let can_retry = attempt < config.max_attempts;
+            let backoff = config.poll_interval * attempt;
+
+            let expiration_timestamp_secs = now_secs() + config.expiration_secs;
+            let sequence_number = match self
+                .sequence_numbers
+                .next_sequence_number(client, expiration_timestamp_secs)
+                .await
+            {
+                Ok(sequence_number) => sequence_number,
+                Err(error) => return (attempt, SubmissionOutcome::error(None, error)),
+            };
+
+            let signed_txn = match TransactionBuilder::new(
+                self.signer.address(),
+                payload.clone(),
+                config.chain_id,
+            )
+            .sequence_number(sequence_number)
+            .max_gas_amount(config.max_gas_amount)
+            .gas_unit_price(config.gas_unit_price)
+            .expiration_timestamp_secs(expiration_timestamp_secs)
+            .build()
+            .and_then(|raw_txn| self.signer.sign_transaction(raw_txn))
+            {
+                Ok(signed_txn) => signed_txn,
+                Err(error) => {
+                    self.sequence_numbers.release(sequence_number).await;
+                    return (attempt, SubmissionOutcome::error(None, error.into()));
+                }
+            };
+            let hash = signed_txn.committed_hash();
+
+            if let Err(error) = client.submit_transaction(signed_txn).await {
+                let resynced = match self
+                    .sequence_numbers
+                    .handle_submission_error(client, sequence_number, &error)
+                    .await
+                {
+                    Ok(resynced) => resynced,
+                    Err(error) => return (attempt, SubmissionOutcome::error(None, error)),
+                };
+                if can_retry && (resynced || is_mempool_full(&error)) {
+                    tokio::time::sleep(backoff).await;
+                    continue;
+                }
+                // Without an answer from the node, the transaction may still have reached mempool
+                let hash = (!matches!(error, RestError::Api(_))).then_some(hash);
+                return (attempt, SubmissionOutcome::error(hash, error));
+            }
+
+            match wait_for_commit(client, &hash, expiration_timestamp_secs, config).await {
+                Ok(Some(transaction)) => {
+                    return (attempt, SubmissionOutcome::committed(hash, &transaction))
+                }
+                Ok(None) => {
+                    // Pick up the expired sequence number, so it's handed out again
+                    if let Err(error) = self.sequence_numbers.sync(client).await {
+                        return (attempt, SubmissionOutcome::error(None, error));
+                    }
+                    if !can_retry {
+                        return (attempt, SubmissionOutcome::Expired { hash });
+                    }
+                }
+                Err(error) => return (attempt, SubmissionOutcome::error(Some(hash), error)),
+            }
+        }
+    }
+}
+
+/// Poll a submitted transaction until it's committed, or `None` once the ledger passes its
+/// expiration without it.  Gives up with a timeout if the node doesn't report the ledger passing
+/// the expiration within a grace period of it.
+async fn wait_for_commit(
+    client: &AptosFullnodeClient,
+    hash: &str,
+    expiration_timestamp_secs: u64,
+    config: &PipelineConfig,
+) -> Result<Option<serde_json::Value>, RestError> {
+    let deadline = Instant::now()
+        + Duration::from_secs(expiration_timestamp_secs.saturating_sub(now_secs()))
+        + EXPIRATION_GRACE_PERIOD;
+    loop {
+        let ledger_timestamp_usecs = match client.get_transaction_by_hash_json(hash).await {
+            Ok(response) if !is_pending(response.inner()) => {
+                return Ok(Some(response.into_inner()))
+            }
+            Ok(response) => Some(response.state().timestamp_usecs),
+            Err(RestError::Api(response))
+                if matches!(
+                    response.error.error_code,
+                    AptosErrorCode::TransactionNotFound
+                ) =>
+            {
+                response.state.as_ref().map(|state| state.timestamp_usecs)
+            }
+            Err(error) => return Err(error),
+        };
+
+        if ledger_timestamp_usecs
+            .is_some_and(|timestamp_usecs| timestamp_usecs / 1_000_000 >= expiration_timestamp_secs)
+        {
+            return Ok(None);
+        }
+        if Instant::now() >= deadline {
+            return Err(RestError::Timeout("to be committed"));
+        }
+        tokio::time::sleep(config.poll_interval).await;
+    }
+}
+
+fn is_mempool_full(error: &RestError) -> bool {
+    matches!(
+        error,
+        RestError::Api(response)
+            if matches!(response.error.error_code, AptosErrorCode::MempoolIsFull)
+    )
+}
+
+/// What happened to one payload submitted through a [`SubmissionPipeline`]
+#[derive(Debug)]
+pub struct ItemResult {
+    /// The position of the payload in the batch
+    pub index: usize,
+    /// The account that submitted it
+    pub sender: AccountAddress,
+    /// How many times it was submitted, including retries
+    pub attempts: u32,
+    pub outcome: SubmissionOutcome,
+}
+
+/// The final state of a transaction submitted through a [`SubmissionPipeline`]
+#[derive(Debug)]
+pub enum SubmissionOutcome {
+    /// Committed, and executed successfully
+    Succeeded {
+        hash: String,
+        version: u64,
+        gas_used: u64,
+    },
+    /// Committed, but failed in execution, e.g. the Move code aborted.  The gas is still charged.
+    ExecutionFailed {
+        hash: String,
+        version: u64,
+        gas_used: u64,
+        vm_status: String,
+    },
+    /// Expired without being committed, on the last attempt
+    Expired { hash: String },
+    /// The transaction couldn't be submitted, or its status couldn't be checked.  The hash is
+    /// set if it may still have reached mempool.
+    Error {
+        hash: Option<String>,
+        error: RestError,
+    },
+}
+
+impl SubmissionOutcome {
+    fn committed(hash: String, transaction: &serde_json::Value) -> Self {
+        let number = |field: &str| {
+            transaction[field]
+                .as_str()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default()
+        };
+        let version = number("version");
+        let gas_used = number("gas_used");
+        if transaction["success"].as_bool().unwrap_or_default() {
+            Self::Succeeded {
+                hash,
+                version,
+                gas_used,
+            }
+        } else {
+            Self::ExecutionFailed {
+                hash,
+                version,
+                gas_used,
+                vm_status: transaction["vm_status"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+            }
+        }
+    }
+
+    fn error(hash: Option<String>, error: RestError) -> Self {
+        Self::Error { hash, error }
+    }
+
+    /// The hash of the transaction, if it may be on chain
+    pub fn hash(&self) -> Option<&str> {
+        match self {
+            Self::Succeeded { hash, .. }
+            | Self::ExecutionFailed { hash, .. }
+            | Self::Expired { hash } => Some(hash),
+            Self::Error { hash, .. } => hash.as_deref(),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Succeeded { .. })
+    }
+}
+
+/// The results of a [`SubmissionPipeline::run`]
+#[derive(Debug)]
+pub struct PipelineReport {
+    /// One result per payload, in the order they were given
+    pub results: Vec<ItemResult>,
+    pub stats: PipelineStats,
+}
+
+impl PipelineReport {
+    pub fn new(results: Vec<ItemResult>, elapsed: Duration) -> Self {
+        let stats = PipelineStats::from_results(&results, elapsed);
+        Self { results, stats }
+    }
+
+    /// The results of every payload that didn't execute successfully
+    pub fn failures(&self) -> impl Iterator<Item = &ItemResult> {
+        self.results
+            .iter()
+            .filter(|result| !result.outcome.is_success())
+    }
+}
+
+/// Counts and throughput for a [`SubmissionPipeline::run`]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PipelineStats {
+    pub total: usize,
+    pub succeeded: usize,
+    pub execution_failed: usize,
+    pub expired: usize,
+    pub errored: usize,
+    /// Submissions beyond the first for each payload
+    pub retries: u64,
+    pub gas_used: u64,
+    pub elapsed: Duration,
+}
+
+impl PipelineStats {
+    pub fn from_results(results: &[ItemResult], elapsed: Duration) -> Self {
+        let mut stats = Self {
+            total: results.len(),
+            elapsed,
+            ..Self::default()
+        };
+        for result in results {
+            stats.retries += u64::from(result.attempts.saturating_sub(1));
+            match &result.outcome {
+                SubmissionOutcome::Succeeded { gas_used, .. } => {
+                    stats.succeeded += 1;
+                    stats.gas_used += gas_used;
+                }
+                SubmissionOutcome::ExecutionFailed { gas_used, .. } => {
+                    stats.execution_failed += 1;
+                    stats.gas_used += gas_used;
+                }
+                SubmissionOutcome::Expired { .. } => stats.expired += 1,
+                SubmissionOutcome::Error { .. } => stats.errored += 1,
+            }
+        }
+        stats
+    }
+
+    /// The number of transactions committed, whether or not they executed successfully
+    pub fn committed(&self) -> usize {
+        self.succeeded + self.execution_failed
+    }
+
+    /// Committed transactions per second over the whole run
+    pub fn committed_per_second(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs == 0.0 {
+            return 0.0;
+        }
+        self.committed() as f64 / secs
+    }
+}
+
+impl Display for PipelineStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} transactions in {:.1}s ({:.1} committed/s): {} succeeded, {} failed in execution, {} expired, {} errors, {} retries",
+            self.total,
+            self.elapsed.as_secs_f64(),
+            self.committed_per_second(),
+            self.succeeded,
+            self.execution_failed,
+            self.expired,
+            self.errored,
+            self.retries
+        )
+    }
+}
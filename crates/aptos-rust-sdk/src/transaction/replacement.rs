@@ -0,0 +1,193 @@
+use crate::account::signer::Signer;
+use crate::client::rest_api::{is_pending, AptosFullnodeClient};
+use anyhow::anyhow;
+use aptos_rust_sdk_types::api_types::transaction::{RawTransaction, SignedTransaction};
+use aptos_rust_sdk_types::error::{AptosErrorCode, AptosErrorResponse, RestError};
+use aptos_rust_sdk_types::AptosResult;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A submitted transaction that can be replaced with a higher gas price while it's pending.
+///
+/// Every version submitted shares the sequence number, so at most one of them commits.
+/// [`PendingTransaction::wait`] checks all of them, and records which one it was.
+#[derive(Clone, Debug)]
+pub struct PendingTransaction {
+    raw_txn: RawTransaction,
+    /// The hash of every version submitted, oldest first
+    hashes: Vec<String>,
+    committed_hash: Option<String>,
+}
+
+/// The result of trying to replace a pending transaction
+#[derive(Debug)]
+pub enum Replacement {
+    /// The replacement was accepted into mempool, with the given hash
+    Submitted(String),
+    /// Mempool refused to update the pending transaction, with
+    /// [`AptosErrorCode::InvalidTransactionUpdate`].  The previous version is still pending.
+    UpdateRejected(AptosErrorResponse),
+    /// The sequence number was already used, so one of the versions already committed
+    AlreadyCommitted,
+}
+
+impl PendingTransaction {
+    /// Sign and submit a transaction, keeping track of it for replacement
+    pub async fn submit(
+        client: &AptosFullnodeClient,
+        signer: &impl Signer,
+        raw_txn: RawTransaction,
+    ) -> AptosResult<Self> {
+        let signed_txn = signer.sign_transaction(raw_txn)?;
+        let hash = signed_txn.committed_hash();
+        let raw_txn = signed_txn.raw_txn().clone();
+        client.submit_transaction(signed_txn).await?;
+        Ok(Self {
+            raw_txn,
+            hashes: vec![hash],
+            committed_hash: None,
+        })
+    }
+
+    /// Keep track of a transaction that was already submitted
+    pub fn from_submitted(signed_txn: &SignedTransaction) -> Self {
+        Self {
+            raw_txn: signed_txn.raw_txn().clone(),
+            hashes: vec![signed_txn.committed_hash()],
+            committed_hash: None,
+        }
+    }
+
+    /// The latest version of the transaction
+    pub fn raw_txn(&self) -> &RawTransaction {
+        &self.raw_txn
+    }
+
+    /// The hashes of every version submitted, oldest first
+    pub fn hashes(&self) -> &[String] {
+        &self.hashes
+    }
+
+    /// The hash of the latest version submitted
+    pub fn latest_hash(&self) -> &str {
+        self.hashes
+            .last()
+            .expect("At least one version is submitted")
+    }
+
+    /// The hash of the version that committed, once [`PendingTransaction::wait`] has found it
+    pub fn committed_hash(&self) -> Option<&str> {
+        self.committed_hash.as_deref()
+    }
+
+    /// Resubmit the transaction with the same sequence number, and a higher gas unit price.
+    ///
+    /// The replacement is signed by `signer`, which has to be the original sender.  It only
+    /// becomes the latest version if mempool accepts it.
+    pub async fn replace_transaction(
+        &mut self,
+        client: &AptosFullnodeClient,
+        signer: &impl Signer,
+        gas_unit_price: u64,
+    ) -> AptosResult<Replacement> {
+        if gas_unit_price <= self.raw_txn.gas_unit_price() {
+            return Err(anyhow!(
+                "Replacement gas unit price {} must be higher than the current {}",
+                gas_unit_price,
+                self.raw_txn.gas_unit_price()
+            )
+            .into());
+        }
+
+        let raw_txn = with_gas_unit_price(&self.raw_txn, gas_unit_price);
+        let signed_txn = signer.sign_transaction(raw_txn)?;
+        let hash = signed_txn.committed_hash();
+        let raw_txn = signed_txn.raw_txn().clone();
+        match client.submit_transaction(signed_txn).await {
+            Ok(_) => {
+                self.raw_txn = raw_txn;
+                self.hashes.push(hash.clone());
+                Ok(Replacement::Submitted(hash))
+            }
+            Err(RestError::Api(response)) => match response.error.error_code {
+                AptosErrorCode::InvalidTransactionUpdate => {
+                    Ok(Replacement::UpdateRejected(response))
+                }
+                AptosErrorCode::SequenceNumberTooOld => Ok(Replacement::AlreadyCommitted),
+                _ => Err(RestError::Api(response)),
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Resubmit the transaction with its gas unit price raised by `percent`, at least by one
+    pub async fn bump_gas_unit_price(
+        &mut self,
+        client: &AptosFullnodeClient,
+        signer: &impl Signer,
+        percent: u64,
+    ) -> AptosResult<Replacement> {
+        let current = self.raw_txn.gas_unit_price();
+        let bumped = current.saturating_add((current.saturating_mul(percent) / 100).max(1));
+        self.replace_transaction(client, signer, bumped).await
+    }
+
+    /// Wait for any version of the transaction to commit, returning it.  Returns `None` if the
+    /// ledger passes the expiration without any of them committing.
+    pub async fn wait(
+        &mut self,
+        client: &AptosFullnodeClient,
+        timeout: Duration,
+    ) -> AptosResult<Option<serde_json::Value>> {
+        let start = Instant::now();
+        loop {
+            let mut ledger_timestamp_usecs = 0;
+            // The latest version is the most likely to commit
+            for hash in self.hashes.iter().rev() {
+                match client.get_transaction_by_hash_json(hash).await {
+                    Ok(response) if !is_pending(response.inner()) => {
+                        self.committed_hash = Some(hash.clone());
+                        return Ok(Some(response.into_inner()));
+                    }
+                    Ok(response) => {
+                        ledger_timestamp_usecs = response.state().timestamp_usecs;
+                    }
+                    // Replaced versions are dropped from mempool
+                    Err(RestError::Api(response))
+                        if matches!(
+                            response.error.error_code,
+                            AptosErrorCode::TransactionNotFound
+                        ) =>
+                    {
+                        if let Some(state) = &response.state {
+                            ledger_timestamp_usecs = state.timestamp_usecs;
+                        }
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+
+            if ledger_timestamp_usecs / 1_000_000 >= self.raw_txn.expiration_timestamp_secs() {
+                return Ok(None);
+            }
+            if start.elapsed() >= timeout {
+                return Err(RestError::Timeout("to be committed"));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// A copy of the transaction with a different gas unit price, and everything else unchanged
+pub fn with_gas_unit_price(raw_txn: &RawTransaction, gas_unit_price: u64) -> RawTransaction {
+    RawTransaction::new(
+        raw_txn.sender(),
+        raw_txn.sequence_number(),
+        raw_txn.payload().clone(),
+        raw_txn.max_gas_amount(),
+        gas_unit_price,
+        raw_txn.expiration_timestamp_secs(),
+        raw_txn.chain_id(),
+    )
+}
@@ -0,0 +1,257 @@
+use crate::client::rest_api::AptosFullnodeClient;
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::error::{AptosErrorCode, RestError};
+use aptos_rust_sdk_types::AptosResult;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const DEFAULT_MAX_IN_FLIGHT: u64 = 100;
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Hands out sequence numbers for one account locally, so every submission doesn't need a
+/// round trip to the chain.
+///
+/// At most `max_in_flight` sequence numbers are handed out beyond the last one committed on
+/// chain; past that, [`AccountSequenceManager::next_sequence_number`] waits for the chain to
+/// catch up.  The manager resyncs from chain when a submission fails with
+/// [`AptosErrorCode::SequenceNumberTooOld`] or [`AptosErrorCode::InvalidTransactionUpdate`].
+///
+/// A transaction that expires before it's committed leaves a gap, which blocks every later
+/// sequence number in mempool.  Gaps are found by comparing each transaction's expiration with
+/// the ledger timestamp, and are handed out again first so the blocked transactions can go
+/// through.  If nothing is in flight after a gap, the manager goes back to the gap instead.
+///
+/// All state is behind a [`tokio::sync::Mutex`], so the manager can be shared across tasks in an
+/// [`std::sync::Arc`].
+#[derive(Debug)]
+pub struct AccountSequenceManager {
+    address: AccountAddress,
+    max_in_flight: u64,
+    poll_interval: Duration,
+    wait_timeout: Duration,
+    state: Mutex<SequenceState>,
+}
+
+#[derive(Debug, Default)]
+struct SequenceState {
+    synced: bool,
+    /// The next sequence number the chain expects
+    on_chain: u64,
+    /// The next sequence number that has never been handed out
+    next: u64,
+    /// Sequence numbers handed out and not yet committed, with their expiration
+    in_flight: BTreeMap<u64, u64>,
+    /// Sequence numbers that were handed out, but will never be committed
+    gaps: BTreeSet<u64>,
+}
+
+impl AccountSequenceManager {
+    pub fn new(address: AccountAddress) -> Self {
+        Self {
+            address,
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            wait_timeout: DEFAULT_WAIT_TIMEOUT,
+            state: Mutex::new(SequenceState::default()),
+        }
+    }
+
+    /// Set the maximum number of uncommitted transactions at a time
+    pub fn with_max_in_flight(mut self, max_in_flight: u64) -> Self {
+        self.max_in_flight = max_in_flight.max(1);
+        self
+    }
+
+    /// Set how often to check the chain while waiting for in-flight transactions to commit
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set how long to wait for room before giving up with [`RestError::Timeout`]
+    pub fn with_wait_timeout(mut self, wait_timeout: Duration) -> Self {
+        self.wait_timeout = wait_timeout;
+        self
+    }
+
+    pub fn address(&self) -> AccountAddress {
+        self.address
+    }
+
+    /// The number of sequence numbers handed out and not yet committed
+    pub async fn in_flight(&self) -> usize {
+        self.state.lock().await.in_flight.len()
+    }
+
+    /// Reserve a sequence number for a transaction expiring at `expiration_timestamp_secs`,
+    /// waiting if too many transactions are in flight
+    pub async fn next_sequence_number(
+        &self,
+        client: &AptosFullnodeClient,
+        expiration_timestamp_secs: u64,
+    ) -> AptosResult<u64> {
+        let start = Instant::now();
+        let mut polled = false;
+        loop {
+            {
+                let mut state = self.state.lock().await;
+                if state.synced {
+                    if let Some(sequence_number) =
+                        state.take(self.max_in_flight, expiration_timestamp_secs)
+                    {
+                        return Ok(sequence_number);
+                    }
+                }
+            }
+
+            // Either this is the first use, or the window is full and the chain has to catch up
+            if polled {
+                if start.elapsed() >= self.wait_timeout {
+                    return Err(RestError::Timeout(
+                        "in-flight transactions to commit before handing out a sequence number",
+                    ));
+                }
+                tokio::time::sleep(self.poll_interval).await;
+            }
+            self.sync(client).await?;
+            polled = true;
+        }
+    }
+
+    /// Reserve a sequence number without consulting the chain, or `None` if the manager hasn't
+    /// been synced or too many transactions are in flight
+    pub async fn try_next_sequence_number(&self, expiration_timestamp_secs: u64) -> Option<u64> {
+        let mut state = self.state.lock().await;
+        if !state.synced {
+            return None;
+        }
+        state.take(self.max_in_flight, expiration_timestamp_secs)
+    }
+
+    /// Fetch the account's sequence number and the ledger time from chain, and update the
+    /// in-flight transactions with them
+    pub async fn sync(&self, client: &AptosFullnodeClient) -> AptosResult<()> {
+        let (sequence_number, ledger_timestamp_secs) =
+            match client.get_account(self.address.to_string()).await {
+                Ok(response) => (
+                    response.inner().sequence_number.inner(),
+                    response.state().timestamp_usecs / 1_000_000,
+                ),
+                // Accounts without an on-chain resource start at 0
+                Err(RestError::Api(response))
+                    if matches!(response.error.error_code, AptosErrorCode::AccountNotFound) =>
+                {
+                    let ledger_timestamp_secs = response
+                        .state
+                        .as_ref()
+                        .map(|state| state.timestamp_usecs / 1_000_000)
+                        .unwrap_or_default();
+                    (0, ledger_timestamp_secs)
+                }
+                Err(err) => return Err(err),
+            };
+        self.update_from_chain(sequence_number, ledger_timestamp_secs)
+            .await;
+        Ok(())
+    }
+
+    /// Update the in-flight transactions with the account's sequence number on chain, and the
+    /// ledger time it was read at
+    pub async fn update_from_chain(&self, sequence_number: u64, ledger_timestamp_secs: u64) {
+        self.state
+            .lock()
+            .await
+            .update_from_chain(sequence_number, ledger_timestamp_secs);
+    }
+
+    /// Give back a sequence number whose transaction never reached mempool, so it's handed out
+    /// again
+    pub async fn release(&self, sequence_number: u64) {
+        self.state.lock().await.release(sequence_number);
+    }
+
+    /// Update the manager after submitting the transaction with `sequence_number` failed.
+    ///
+    /// Returns `true` if the manager resynced from chain, and the transaction should be retried
+    /// with a new sequence number.  Transactions rejected outright have their sequence number
+    /// released; for any other error it's unknown whether the transaction reached mempool, so it
+    /// stays in flight until it's committed or expires.
+    pub async fn handle_submission_error(
+        &self,
+        client: &AptosFullnodeClient,
+        sequence_number: u64,
+        error: &RestError,
+    ) -> AptosResult<bool> {
+        let RestError::Api(response) = error else {
+            return Ok(false);
+        };
+        match response.error.error_code {
+            AptosErrorCode::SequenceNumberTooOld | AptosErrorCode::InvalidTransactionUpdate => {
+                // The sequence number is already used, so it can't be handed out again
+                self.state.lock().await.in_flight.remove(&sequence_number);
+                self.sync(client).await?;
+                Ok(true)
+            }
+            AptosErrorCode::VmError
+            | AptosErrorCode::MempoolIsFull
+            | AptosErrorCode::InvalidInput => {
+                self.release(sequence_number).await;
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+impl SequenceState {
+    fn take(&mut self, max_in_flight: u64, expiration_timestamp_secs: u64) -> Option<u64> {
+        let sequence_number = match self.gaps.pop_first() {
+            Some(gap) => gap,
+            None if self.next - self.on_chain < max_in_flight => {
+                self.next += 1;
+                self.next - 1
+            }
+            None => return None,
+        };
+        self.in_flight
+            .insert(sequence_number, expiration_timestamp_secs);
+        Some(sequence_number)
+    }
+
+    fn update_from_chain(&mut self, on_chain: u64, ledger_timestamp_secs: u64) {
+        self.synced = true;
+        self.on_chain = on_chain;
+        // Anything below the chain's sequence number is committed, by us or by someone else
+        self.in_flight = self.in_flight.split_off(&on_chain);
+        self.gaps = self.gaps.split_off(&on_chain);
+        self.next = self.next.max(on_chain);
+
+        // Mempool drops transactions once the ledger passes their expiration
+        let expired: Vec<u64> = self
+            .in_flight
+            .iter()
+            .filter(|(_, expiration)| **expiration <= ledger_timestamp_secs)
+            .map(|(sequence_number, _)| *sequence_number)
+            .collect();
+        for sequence_number in expired {
+            self.release(sequence_number);
+        }
+    }
+
+    fn release(&mut self, sequence_number: u64) {
+        if self.in_flight.remove(&sequence_number).is_some() {
+            self.gaps.insert(sequence_number);
+        }
+        // Gaps with nothing in flight after them can simply be handed out again in order
+        let last_in_flight = self.in_flight.keys().next_back().copied();
+        while let Some(&gap) = self.gaps.last() {
+            if last_in_flight.is_some_and(|last| last > gap) {
+                break;
+            }
+            self.gaps.pop_last();
+            self.next = gap;
+        }
+    }
+}
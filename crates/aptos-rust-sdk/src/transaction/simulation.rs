@@ -0,0 +1,147 @@
+use anyhow::ensure;
+use aptos_crypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::{RawTransaction, SignedTransaction};
+use aptos_rust_sdk_types::api_types::transaction_authenticator::{
+    AccountAuthenticator, AuthenticationKey, TransactionAuthenticator,
+};
+
+/// A signer of a transaction being simulated, for whom no private key is available.
+///
+/// The node only accepts simulations with invalid signatures, so the authenticator never holds a
+/// real one.  If the public key is known, it gets an all-zero signature, which lets the node
+/// check the key against the account's authentication key and estimate gas for verifying it.
+/// Otherwise, a [`AccountAuthenticator::NoAuthenticator`] skips the check entirely.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulationSigner {
+    address: AccountAddress,
+    public_key: Option<Ed25519PublicKey>,
+}
+
+impl SimulationSigner {
+    /// A signer known only by its address
+    pub fn from_address(address: AccountAddress) -> Self {
+        Self {
+            address,
+            public_key: None,
+        }
+    }
+
+    /// A signer with a known public key, at the address given, e.g. for a rotated key
+    pub fn from_public_key(address: AccountAddress, public_key: Ed25519PublicKey) -> Self {
+        Self {
+            address,
+            public_key: Some(public_key),
+        }
+    }
+
+    pub fn address(&self) -> AccountAddress {
+        self.address
+    }
+
+    pub fn public_key(&self) -> Option<&Ed25519PublicKey> {
+        self.public_key.as_ref()
+    }
+
+    /// The simulation-only authenticator for the signer
+    pub fn authenticator(&self) -> AccountAuthenticator {
+        match &self.public_key {
+            Some(public_key) => AccountAuthenticator::ed25519(public_key.clone(), zero_signature()),
+            None => AccountAuthenticator::no_authenticator(),
+        }
+    }
+}
+
+impl From<AccountAddress> for SimulationSigner {
+    fn from(address: AccountAddress) -> Self {
+        Self::from_address(address)
+    }
+}
+
+/// A signer with a known public key at the address derived from it
+impl From<Ed25519PublicKey> for SimulationSigner {
+    fn from(public_key: Ed25519PublicKey) -> Self {
+        let address = AuthenticationKey::ed25519(&public_key).account_address();
+        Self::from_public_key(address, public_key)
+    }
+}
+
+/// Everyone who has to sign a transaction being simulated
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulationSigners {
+    sender: SimulationSigner,
+    secondary_signers: Vec<SimulationSigner>,
+    fee_payer: Option<SimulationSigner>,
+}
+
+impl SimulationSigners {
+    pub fn new(sender: impl Into<SimulationSigner>) -> Self {
+        Self {
+            sender: sender.into(),
+            secondary_signers: vec![],
+            fee_payer: None,
+        }
+    }
+
+    /// Make it a multi-agent transaction, with the secondary signers in order
+    pub fn with_secondary_signers(
+        mut self,
+        secondary_signers: impl IntoIterator<Item = impl Into<SimulationSigner>>,
+    ) -> Self {
+        self.secondary_signers = secondary_signers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Make it a fee payer transaction
+    pub fn with_fee_payer(mut self, fee_payer: impl Into<SimulationSigner>) -> Self {
+        self.fee_payer = Some(fee_payer.into());
+        self
+    }
+
+    /// Build a [`SignedTransaction`] that can only be simulated, never submitted
+    pub fn simulation_transaction(
+        &self,
+        raw_txn: RawTransaction,
+    ) -> anyhow::Result<SignedTransaction> {
+        ensure!(
+            raw_txn.sender() == self.sender.address(),
+            "Transaction sender {} does not match simulation signer {}",
+            raw_txn.sender(),
+            self.sender.address()
+        );
+
+        let sender = self.sender.authenticator();
+        let secondary_signer_addresses = self
+            .secondary_signers
+            .iter()
+            .map(SimulationSigner::address)
+            .collect();
+        let secondary_signers = self
+            .secondary_signers
+            .iter()
+            .map(SimulationSigner::authenticator)
+            .collect();
+
+        let authenticator = match &self.fee_payer {
+            Some(fee_payer) => TransactionAuthenticator::fee_payer(
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer.address(),
+                fee_payer.authenticator(),
+            ),
+            None if !self.secondary_signers.is_empty() => TransactionAuthenticator::multi_agent(
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            ),
+            None => TransactionAuthenticator::single_sender(sender),
+        };
+        Ok(SignedTransaction::new(raw_txn, authenticator))
+    }
+}
+
+fn zero_signature() -> Ed25519Signature {
+    Ed25519Signature::try_from(&[0u8; Ed25519Signature::LENGTH][..])
+        .expect("An all-zero signature is well-formed")
+}
@@ -0,0 +1,152 @@
+use crate::account::signer::Signer;
+use crate::client::response::FullnodeResponse;
+use crate::client::rest_api::AptosFullnodeClient;
+use anyhow::{anyhow, ensure, Context};
+use aptos_rust_sdk_types::api_types::address::AccountAddress;
+use aptos_rust_sdk_types::api_types::transaction::{
+    GenerateSigningMessage, RawTransaction, RawTransactionWithData, SignedTransaction,
+};
+use aptos_rust_sdk_types::api_types::transaction_authenticator::{
+    AccountAuthenticator, TransactionAuthenticator,
+};
+use aptos_rust_sdk_types::AptosResult;
+use serde::{Deserialize, Serialize};
+
+/// A fee payer transaction on its way from the sender to the sponsor paying for its gas.
+///
+/// The flow is:
+/// 1. The sender builds the [`RawTransaction`] and signs it with [`SponsoredTransaction::new`]
+/// 2. The result is serialized with [`SponsoredTransaction::to_bytes`] and handed to the sponsor
+/// 3. The sponsor loads it with [`SponsoredTransaction::from_bytes`] and co-signs it with
+///    [`SponsoredTransaction::sign_as_fee_payer`]
+/// 4. Either side submits it with [`SponsoredTransaction::submit`]
+///
+/// The sender signs with `0x0` in place of the fee payer address, so the sender doesn't need to
+/// know who is sponsoring the transaction.  The fee payer always signs over its own address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SponsoredTransaction {
+    raw_txn: RawTransaction,
+    sender: AccountAuthenticator,
+    fee_payer: Option<(AccountAddress, AccountAuthenticator)>,
+}
+
+impl SponsoredTransaction {
+    /// Sign the transaction as the sender, leaving the fee payer to be filled in by the sponsor
+    pub fn new(raw_txn: RawTransaction, sender: &impl Signer) -> anyhow::Result<Self> {
+        ensure!(
+            raw_txn.sender() == sender.address(),
+            "Transaction sender {} does not match signer {}",
+            raw_txn.sender(),
+            sender.address()
+        );
+        let message = Self::signing_message(&raw_txn, AccountAddress::ZERO)?;
+        let sender = sender.sign_message(&message);
+        Ok(Self {
+            raw_txn,
+            sender,
+            fee_payer: None,
+        })
+    }
+
+    /// The message a signer has to sign, given the fee payer address it signs over
+    pub fn signing_message(
+        raw_txn: &RawTransaction,
+        fee_payer_address: AccountAddress,
+    ) -> anyhow::Result<Vec<u8>> {
+        RawTransactionWithData::new_multi_agent_with_fee_payer(
+            raw_txn.clone(),
+            vec![],
+            fee_payer_address,
+        )
+        .generate_signing_message()
+    }
+
+    pub fn raw_txn(&self) -> &RawTransaction {
+        &self.raw_txn
+    }
+
+    pub fn fee_payer_address(&self) -> Option<AccountAddress> {
+        self.fee_payer.as_ref().map(|(address, _)| *address)
+    }
+
+    /// Whether the sponsor has signed the transaction
+    pub fn is_sponsored(&self) -> bool {
+        self.fee_payer.is_some()
+    }
+
+    /// Co-sign the transaction as the fee payer, after checking the sender's signature
+    pub fn sign_as_fee_payer(mut self, fee_payer: &impl Signer) -> anyhow::Result<Self> {
+        ensure!(
+            self.fee_payer.is_none(),
+            "Transaction is already signed by fee payer {}",
+            self.fee_payer_address().unwrap()
+        );
+        let fee_payer_address = fee_payer.address();
+        self.verify_sender(fee_payer_address)?;
+
+        let message = Self::signing_message(&self.raw_txn, fee_payer_address)?;
+        self.fee_payer = Some((fee_payer_address, fee_payer.sign_message(&message)));
+        Ok(self)
+    }
+
+    /// Check that every signature was made over the same transaction
+    pub fn verify(&self) -> anyhow::Result<()> {
+        let (fee_payer_address, fee_payer_signer) = self
+            .fee_payer
+            .as_ref()
+            .ok_or_else(|| anyhow!("Transaction has not been signed by a fee payer"))?;
+        self.verify_sender(*fee_payer_address)?;
+        let message = Self::signing_message(&self.raw_txn, *fee_payer_address)?;
+        fee_payer_signer
+            .verify_arbitrary_msg(&message)
+            .context("Fee payer signature does not match the transaction")
+    }
+
+    /// The sender may have signed over either the zero address, or the actual fee payer address
+    fn verify_sender(&self, fee_payer_address: AccountAddress) -> anyhow::Result<()> {
+        let message = Self::signing_message(&self.raw_txn, AccountAddress::ZERO)?;
+        if self.sender.verify_arbitrary_msg(&message).is_ok() {
+            return Ok(());
+        }
+        let message = Self::signing_message(&self.raw_txn, fee_payer_address)?;
+        self.sender
+            .verify_arbitrary_msg(&message)
+            .context("Sender signature does not match the transaction")
+    }
+
+    /// Serialize to BCS for handing between the sender and the sponsor
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(aptos_bcs::to_bytes(self)?)
+    }
+
+    /// Deserialize from the output of [`SponsoredTransaction::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(aptos_bcs::from_bytes(bytes)?)
+    }
+
+    /// Build the final [`SignedTransaction`], after verifying all signatures
+    pub fn into_signed_transaction(self) -> anyhow::Result<SignedTransaction> {
+        self.verify()?;
+        let (fee_payer_address, fee_payer_signer) = self.fee_payer.unwrap();
+        Ok(SignedTransaction::new(
+            self.raw_txn,
+            TransactionAuthenticator::fee_payer(
+                self.sender,
+                vec![],
+                vec![],
+                fee_payer_address,
+                fee_payer_signer,
+            ),
+        ))
+    }
+
+    /// Verify and submit the transaction to the network
+    pub async fn submit(
+        self,
+        client: &AptosFullnodeClient,
+    ) -> AptosResult<FullnodeResponse<serde_json::Value>> {
+        client
+            .submit_transaction(self.into_signed_transaction()?)
+            .await
+    }
+}
@@ -4,6 +4,9 @@ mod tests {
     use aptos_crypto::Uniform;
     use aptos_rust_sdk::client::builder::AptosClientBuilder;
     use aptos_rust_sdk::client::config::AptosNetwork;
+    use aptos_rust_sdk::transaction::builder::TransactionBuilder;
+    use aptos_rust_sdk::transaction::payloads;
+    use aptos_rust_sdk_types::api_types::account::{Account, AccountResources};
     use aptos_rust_sdk_types::api_types::address::AccountAddress;
     use aptos_rust_sdk_types::api_types::chain_id::ChainId;
     use aptos_rust_sdk_types::api_types::module_id::ModuleId;
@@ -39,37 +42,17 @@ mod tests {
             .await
             .unwrap()
             .into_inner();
-        let sequence_number = resource
-            .iter()
-            .find(|r| r.type_ == "0x1::account::Account")
-            .unwrap()
-            .data
-            .get("sequence_number")
-            .unwrap()
-            .as_str()
-            .unwrap()
-            .parse::<u64>()
-            .unwrap();
-        let payload = TransactionPayload::EntryFunction(EntryFunction::new(
-            ModuleId::new(AccountAddress::ONE, "aptos_account".to_string()),
-            "transfer".to_string(),
-            vec![],
-            vec![AccountAddress::ONE.to_vec(), 1u64.to_le_bytes().to_vec()],
-        ));
-        let max_gas_amount = 11;
-        let gas_unit_price = 100;
+        let sequence_number = resource.find::<Account>().unwrap().unwrap().sequence_number;
+        let payload = payloads::transfer(AccountAddress::ONE, 1);
         let expiration_timestamp_secs = state.timestamp_usecs / 1000 / 1000 + 60 * 10;
-        let chain_id = ChainId::Testnet;
 
-        let raw_txn = RawTransaction::new(
-            sender,
-            sequence_number,
-            payload,
-            max_gas_amount,
-            gas_unit_price,
-            expiration_timestamp_secs,
-            chain_id,
-        );
+        let raw_txn = TransactionBuilder::new(sender, payload, ChainId::Testnet)
+            .sequence_number(sequence_number)
+            .max_gas_amount(11)
+            .gas_unit_price(100)
+            .expiration_timestamp_secs(expiration_timestamp_secs)
+            .build()
+            .unwrap();
 
         let message = raw_txn.generate_signing_message().unwrap();
 
@@ -219,17 +202,7 @@ mod tests {
             .unwrap()
             .into_inner();
 
-        let sequence_number = resource
-            .iter()
-            .find(|r| r.type_ == "0x1::account::Account")
-            .unwrap()
-            .data
-            .get("sequence_number")
-            .unwrap()
-            .as_str()
-            .unwrap()
-            .parse::<u64>()
-            .unwrap();
+        let sequence_number = resource.find::<Account>().unwrap().unwrap().sequence_number;
 
         let payload = TransactionPayload::EntryFunction(EntryFunction::new(
             ModuleId::new(